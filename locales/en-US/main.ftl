@@ -0,0 +1,50 @@
+## Generic errors (by error code)
+
+error-bad-request = The request is invalid
+error-validation-failed = Request validation failed
+error-rule-violation = The request violates a business rule
+error-conflict = The request conflicts with the current state of the resource
+error-not-found = The requested resource does not exist
+error-unauthorized = Unauthorized
+error-internal-error = Internal server error
+database-error = Database operation failed
+database-unavailable = The database is unavailable
+resource-exists = The resource already exists
+
+## Field validation (violation-field-code)
+
+violation-title-required = The article title must not be empty
+violation-title-too-long = The article title is too long (at most { $max } characters)
+violation-slug-required = The article slug must not be empty
+violation-summary-too-long = The summary is too long (at most { $max } characters)
+violation-author-required = The author name must not be empty
+violation-author-too-long = The author name is too long (at most { $max } characters)
+violation-tags-required = Tags must not be empty
+violation-tags-too-long = A tag is too long (at most { $max } characters)
+violation-url-invalid-scheme = The webhook URL must start with http:// or https://
+violation-event-types-required = Subscribe to at least one event type
+violation-secret-too-short = The webhook secret must be at least { $min } characters long
+
+## Request parameters
+
+invalid-cursor = Invalid pagination cursor
+cursor-sort-mismatch = The pagination cursor does not match the current sort order
+unsupported-field = Unsupported field: { $name }
+empty-field-list = The field list must not be empty
+unsupported-include = Unsupported include: { $name }
+unknown-event-type = Unknown event type: { $name }
+invalid-article-status = Invalid article status
+
+## Articles
+
+article-empty-content = An article without content cannot be published
+article-already-published = The article is already published
+article-not-published = Only published articles can be reverted to draft
+article-published-slug-immutable = The slug of a published article cannot be changed
+article-exists = The article already exists: { $id }
+slug-taken = The slug is already in use: { $slug }
+concurrent-modification = The article was modified concurrently, please retry
+event-stream-empty = The article event stream is empty
+event-stream-not-created = The article event stream must start with a created event
+event-not-applicable = The event cannot be applied to an article: { $event_type }
+article-field-required = The article is missing a required field: { $name }
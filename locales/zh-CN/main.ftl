@@ -0,0 +1,50 @@
+## 通用错误（按错误码）
+
+error-bad-request = 请求参数无效
+error-validation-failed = 请求参数验证失败
+error-rule-violation = 业务规则验证失败
+error-conflict = 请求与资源当前状态冲突
+error-not-found = 请求的资源不存在
+error-unauthorized = 未授权的访问
+error-internal-error = 服务器内部错误
+database-error = 数据库操作失败
+database-unavailable = 数据库不可用
+resource-exists = 资源已存在
+
+## 字段校验（violation-字段-违规类型）
+
+violation-title-required = 文章标题不能为空
+violation-title-too-long = 文章标题过长（最多{ $max }个字符）
+violation-slug-required = 文章Slug不能为空
+violation-summary-too-long = 摘要过长（最多{ $max }个字符）
+violation-author-required = 作者名不能为空
+violation-author-too-long = 作者名过长（最多{ $max }个字符）
+violation-tags-required = 标签不能为空
+violation-tags-too-long = 标签过长（最多{ $max }个字符）
+violation-url-invalid-scheme = Webhook地址必须以http://或https://开头
+violation-event-types-required = 至少需要订阅一种事件类型
+violation-secret-too-short = Webhook密钥长度不能少于{ $min }个字符
+
+## 请求参数
+
+invalid-cursor = 无效的分页游标
+cursor-sort-mismatch = 分页游标与当前排序条件不匹配
+unsupported-field = 不支持的字段: { $name }
+empty-field-list = 字段列表不能为空
+unsupported-include = 不支持的关联数据: { $name }
+unknown-event-type = 未知的事件类型: { $name }
+invalid-article-status = 无效的文章状态
+
+## 文章
+
+article-empty-content = 无法发布空内容的文章
+article-already-published = 文章已经是发布状态
+article-not-published = 只有已发布的文章可以设为草稿
+article-published-slug-immutable = 已发布文章不能修改slug
+article-exists = 文章已存在: { $id }
+slug-taken = slug已被使用: { $slug }
+concurrent-modification = 聚合已被并发修改，请重试
+event-stream-empty = 文章事件流为空
+event-stream-not-created = 文章事件流必须以创建事件开始
+event-not-applicable = 无法应用到文章的事件: { $event_type }
+article-field-required = 文章缺少必填字段: { $name }
@@ -0,0 +1,31 @@
+pub use sea_orm_migration::prelude::*;
+
+mod m20261019_000001_create_articles;
+mod m20261019_000002_create_outbox;
+mod m20261019_000003_create_domain_events;
+mod m20261019_000004_create_webhook_subscriptions;
+mod m20261019_000005_create_webhook_deliveries;
+mod m20261019_000006_create_article_snapshots;
+mod m20261019_000007_create_article_read_view;
+mod m20261019_000008_create_projection_checkpoints;
+mod m20261019_000009_add_article_author_and_tags;
+
+/// 全部数据库迁移，按版本顺序执行
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20261019_000001_create_articles::Migration),
+            Box::new(m20261019_000002_create_outbox::Migration),
+            Box::new(m20261019_000003_create_domain_events::Migration),
+            Box::new(m20261019_000004_create_webhook_subscriptions::Migration),
+            Box::new(m20261019_000005_create_webhook_deliveries::Migration),
+            Box::new(m20261019_000006_create_article_snapshots::Migration),
+            Box::new(m20261019_000007_create_article_read_view::Migration),
+            Box::new(m20261019_000008_create_projection_checkpoints::Migration),
+            Box::new(m20261019_000009_add_article_author_and_tags::Migration),
+        ]
+    }
+}
@@ -0,0 +1,48 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 已手工建表的环境中跳过，迁移记录照常写入
+        manager
+            .create_table(
+                Table::create()
+                    .table(Articles::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Articles::Id))
+                    .col(text(Articles::Summary))
+                    .col(string(Articles::Title))
+                    .col(string_uniq(Articles::Slug))
+                    .col(text(Articles::Content))
+                    .col(string(Articles::Status))
+                    .col(timestamp_with_time_zone(Articles::CreatedAt))
+                    .col(timestamp_with_time_zone(Articles::UpdatedAt))
+                    .col(timestamp_with_time_zone_null(Articles::PublishedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Articles::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Articles {
+    Table,
+    Id,
+    Summary,
+    Title,
+    Slug,
+    Content,
+    Status,
+    CreatedAt,
+    UpdatedAt,
+    PublishedAt,
+}
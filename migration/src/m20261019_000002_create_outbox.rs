@@ -0,0 +1,60 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Outbox::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Outbox::Id))
+                    .col(string(Outbox::AggregateId))
+                    .col(string(Outbox::EventType))
+                    .col(json_binary(Outbox::Envelope))
+                    .col(timestamp_with_time_zone(Outbox::CreatedAt))
+                    .col(integer(Outbox::Attempts).default(0))
+                    .col(text_null(Outbox::LastError))
+                    .col(timestamp_with_time_zone(Outbox::NextAttemptAt))
+                    .col(timestamp_with_time_zone_null(Outbox::DispatchedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 中继按到期时间轮询未投递的事件
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_pending")
+                    .table(Outbox::Table)
+                    .col(Outbox::DispatchedAt)
+                    .col(Outbox::NextAttemptAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Outbox::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Outbox {
+    Table,
+    Id,
+    AggregateId,
+    EventType,
+    Envelope,
+    CreatedAt,
+    Attempts,
+    LastError,
+    NextAttemptAt,
+    DispatchedAt,
+}
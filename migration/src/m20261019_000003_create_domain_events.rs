@@ -0,0 +1,72 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DomainEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        big_integer(DomainEvents::Position)
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(uuid_uniq(DomainEvents::EventId))
+                    .col(string(DomainEvents::EventType))
+                    .col(string(DomainEvents::AggregateType))
+                    .col(string(DomainEvents::AggregateId))
+                    .col(big_integer(DomainEvents::Sequence))
+                    .col(integer(DomainEvents::SchemaVersion))
+                    .col(uuid_null(DomainEvents::CausationId))
+                    .col(uuid_null(DomainEvents::CorrelationId))
+                    .col(timestamp_with_time_zone(DomainEvents::OccurredOn))
+                    .col(json_binary(DomainEvents::Payload))
+                    .col(timestamp_with_time_zone(DomainEvents::RecordedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 同一聚合的序号唯一，用于乐观并发控制
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_domain_events_stream")
+                    .table(DomainEvents::Table)
+                    .col(DomainEvents::AggregateType)
+                    .col(DomainEvents::AggregateId)
+                    .col(DomainEvents::Sequence)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DomainEvents::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum DomainEvents {
+    Table,
+    Position,
+    EventId,
+    EventType,
+    AggregateType,
+    AggregateId,
+    Sequence,
+    SchemaVersion,
+    CausationId,
+    CorrelationId,
+    OccurredOn,
+    Payload,
+    RecordedAt,
+}
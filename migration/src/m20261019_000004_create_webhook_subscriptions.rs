@@ -0,0 +1,43 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookSubscriptions::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(WebhookSubscriptions::Id))
+                    .col(string(WebhookSubscriptions::Url))
+                    .col(json_binary(WebhookSubscriptions::EventTypes))
+                    .col(string(WebhookSubscriptions::Secret))
+                    .col(boolean(WebhookSubscriptions::Active).default(true))
+                    .col(timestamp_with_time_zone(WebhookSubscriptions::CreatedAt))
+                    .col(timestamp_with_time_zone(WebhookSubscriptions::UpdatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookSubscriptions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookSubscriptions {
+    Table,
+    Id,
+    Url,
+    EventTypes,
+    Secret,
+    Active,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,81 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDeliveries::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(WebhookDeliveries::Id))
+                    .col(uuid(WebhookDeliveries::SubscriptionId))
+                    .col(string(WebhookDeliveries::EventType))
+                    .col(string(WebhookDeliveries::AggregateId))
+                    .col(json_binary(WebhookDeliveries::Payload))
+                    .col(string(WebhookDeliveries::Status))
+                    .col(integer(WebhookDeliveries::Attempts).default(0))
+                    .col(integer_null(WebhookDeliveries::ResponseStatus))
+                    .col(text_null(WebhookDeliveries::LastError))
+                    .col(timestamp_with_time_zone(WebhookDeliveries::NextAttemptAt))
+                    .col(timestamp_with_time_zone(WebhookDeliveries::CreatedAt))
+                    .col(timestamp_with_time_zone_null(
+                        WebhookDeliveries::DeliveredAt,
+                    ))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 投递工作者按状态和到期时间领取任务
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_due")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::Status)
+                    .col(WebhookDeliveries::NextAttemptAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // 按订阅查询投递记录
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_subscription")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::SubscriptionId)
+                    .col(WebhookDeliveries::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDeliveries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookDeliveries {
+    Table,
+    Id,
+    SubscriptionId,
+    EventType,
+    AggregateId,
+    Payload,
+    Status,
+    Attempts,
+    ResponseStatus,
+    LastError,
+    NextAttemptAt,
+    CreatedAt,
+    DeliveredAt,
+}
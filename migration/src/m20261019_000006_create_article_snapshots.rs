@@ -0,0 +1,37 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleSnapshots::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(ArticleSnapshots::AggregateId))
+                    .col(big_integer(ArticleSnapshots::Version))
+                    .col(json_binary(ArticleSnapshots::State))
+                    .col(timestamp_with_time_zone(ArticleSnapshots::CreatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArticleSnapshots::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ArticleSnapshots {
+    Table,
+    AggregateId,
+    Version,
+    State,
+    CreatedAt,
+}
@@ -0,0 +1,55 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleReadView::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(ArticleReadView::Id))
+                    .col(string(ArticleReadView::Title))
+                    .col(string_uniq(ArticleReadView::Slug))
+                    .col(text(ArticleReadView::Summary))
+                    .col(text(ArticleReadView::Content))
+                    .col(string(ArticleReadView::Status))
+                    .col(string_null(ArticleReadView::AuthorName))
+                    .col(json_binary(ArticleReadView::Tags).default(Expr::cust("'[]'")))
+                    .col(integer(ArticleReadView::ReadingTime).default(1))
+                    .col(integer(ArticleReadView::CommentCount).default(0))
+                    .col(timestamp_with_time_zone(ArticleReadView::CreatedAt))
+                    .col(timestamp_with_time_zone(ArticleReadView::UpdatedAt))
+                    .col(timestamp_with_time_zone_null(ArticleReadView::PublishedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArticleReadView::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ArticleReadView {
+    Table,
+    Id,
+    Title,
+    Slug,
+    Summary,
+    Content,
+    Status,
+    AuthorName,
+    Tags,
+    ReadingTime,
+    CommentCount,
+    CreatedAt,
+    UpdatedAt,
+    PublishedAt,
+}
@@ -0,0 +1,35 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProjectionCheckpoints::Table)
+                    .if_not_exists()
+                    .col(string(ProjectionCheckpoints::Name).primary_key())
+                    .col(big_integer(ProjectionCheckpoints::Position))
+                    .col(timestamp_with_time_zone(ProjectionCheckpoints::UpdatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProjectionCheckpoints::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProjectionCheckpoints {
+    Table,
+    Name,
+    Position,
+    UpdatedAt,
+}
@@ -0,0 +1,97 @@
+use clap::{Parser, Subcommand};
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{DatabaseConnection, DbErr};
+use std::error::Error;
+use std::sync::Arc;
+
+use crate::adapters::outbound::persistence::projection::{
+    ArticleReadViewProjector, config::load_projection_config,
+};
+use crate::shared::events::EventRegistry;
+
+/// Rhyon博客服务，不带子命令时启动HTTP服务
+#[derive(Debug, Parser)]
+#[command(name = "rhyon", version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// 数据库结构迁移
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateCommand,
+    },
+    /// 读模型投影管理
+    Projections {
+        #[command(subcommand)]
+        action: ProjectionCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MigrateCommand {
+    /// 执行待执行的迁移
+    Up {
+        /// 最多执行的迁移数，默认全部执行
+        #[arg(short = 'n', long)]
+        steps: Option<u32>,
+    },
+    /// 回滚最近执行的迁移
+    Down {
+        /// 回滚的迁移数
+        #[arg(short = 'n', long, default_value_t = 1)]
+        steps: u32,
+    },
+    /// 查看各迁移的执行状态
+    Status,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProjectionCommand {
+    /// 清空文章读模型并从事件存储重放全部历史
+    Rebuild,
+}
+
+impl Command {
+    /// 执行运维子命令，完成后进程退出
+    pub async fn run(
+        self,
+        db: DatabaseConnection,
+        registry: Arc<EventRegistry>,
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            Command::Migrate { action } => action.run(&db).await?,
+            Command::Projections {
+                action: ProjectionCommand::Rebuild,
+            } => {
+                let projector =
+                    ArticleReadViewProjector::new(db, registry, load_projection_config());
+                let count = projector.rebuild().await?;
+                tracing::info!("✅ 文章读模型已重建，重放事件{}个", count);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MigrateCommand {
+    async fn run(self, db: &DatabaseConnection) -> Result<(), DbErr> {
+        match self {
+            MigrateCommand::Up { steps } => {
+                let pending = Migrator::get_pending_migrations(db).await?.len();
+                Migrator::up(db, steps).await?;
+                let applied = steps.map_or(pending, |steps| pending.min(steps as usize));
+                tracing::info!("✅ 已执行{}个数据库迁移", applied);
+            }
+            MigrateCommand::Down { steps } => {
+                Migrator::down(db, Some(steps)).await?;
+                tracing::info!("✅ 已回滚最近{}个数据库迁移", steps);
+            }
+            MigrateCommand::Status => Migrator::status(db).await?,
+        }
+        Ok(())
+    }
+}
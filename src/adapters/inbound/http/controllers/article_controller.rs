@@ -1,14 +1,22 @@
 use axum::Json;
 use axum::extract::{Path, Query, State};
+use axum::response::IntoResponse;
 use std::sync::Arc;
 
 use crate::adapters::inbound::http::dto::{
-    ArticleDetailHttpResponse, ArticleListHttpResponse, CreateArticleHttpRequest,
-    HttpPaginationRequest, HttpPaginationResponse,
+    ArticleDetailHttpResponse, ArticleHttpPayload, ArticleListHttpResponse,
+    CreateArticleHttpRequest, HttpFieldSelectionRequest, HttpPaginationRequest,
+    HttpPaginationResponse,
+};
+use crate::adapters::inbound::http::middleware::last_modified;
+use crate::adapters::inbound::http::openapi::{EmptyResponse, ErrorResponse};
+use crate::application::models::{ArticleField, ArticleFieldSet, ArticleQueryModel};
+use crate::application::queries::{
+    GetArticleBySlugQuery, GetArticlesByCursorQuery, GetArticlesQuery,
 };
-use crate::application::queries::{GetArticleBySlugQuery, GetArticlesQuery};
 use crate::application::services::ArticleApplicationService;
 use crate::core::response::R;
+use crate::domain::article::commands::PublishArticleCommand;
 use crate::domain::article::specifications::ArticleSortSpec;
 use crate::shared::errors::RhyonError;
 
@@ -22,53 +30,262 @@ impl ArticleController {
             application_service,
         }
     }
+}
+
+/// 创建文章
+///
+/// 新文章为草稿状态，返回文章ID
+#[utoipa::path(
+    post,
+    path = "/articles",
+    tag = "articles",
+    security(("admin_token" = [])),
+    request_body = CreateArticleHttpRequest,
+    responses(
+        (status = 200, description = "文章ID", body = R<String>),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+        (status = 409, description = "slug已被使用", body = ErrorResponse),
+        (status = 422, description = "字段校验失败", body = ErrorResponse),
+    )
+)]
+pub async fn create_article(
+    State(controller): State<Arc<ArticleController>>,
+    Json(request): Json<CreateArticleHttpRequest>,
+) -> Result<R<String>, RhyonError> {
+    let command = request.into();
+    let article_id = controller
+        .application_service
+        .create_article(command)
+        .await?;
+    Ok(R::success(article_id.to_string()))
+}
+
+/// 发布文章
+#[utoipa::path(
+    post,
+    path = "/articles/{slug}/publish",
+    tag = "articles",
+    security(("admin_token" = [])),
+    params(("slug" = String, Path, description = "文章slug")),
+    responses(
+        (status = 200, description = "文章已发布", body = EmptyResponse),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+        (status = 404, description = "文章不存在", body = ErrorResponse),
+        (status = 409, description = "文章已经发布", body = ErrorResponse),
+    )
+)]
+pub async fn publish_article(
+    State(controller): State<Arc<ArticleController>>,
+    Path(slug): Path<String>,
+) -> Result<R<()>, RhyonError> {
+    controller
+        .application_service
+        .publish_article(PublishArticleCommand::new(slug))
+        .await?;
+    Ok(R::success_empty())
+}
 
-    /// POST /articles - 创建文章
-    pub async fn create_article(
-        State(controller): State<Arc<ArticleController>>,
-        Json(request): Json<CreateArticleHttpRequest>,
-    ) -> Result<R<String>, RhyonError> {
-        let command = request.into();
-        let article_id = controller
+/// 获取文章列表
+///
+/// 只返回已发布文章。携带`cursor`参数时使用游标分页，
+/// 支持 `fields=title,slug,published_at` 只返回指定字段，`include=author,tags` 嵌入关联数据；
+/// 响应的`Last-Modified`为本页文章中最近的更新时间
+#[utoipa::path(
+    get,
+    path = "/articles",
+    tag = "articles",
+    params(HttpPaginationRequest, HttpFieldSelectionRequest),
+    responses(
+        (status = 200, description = "文章列表",
+            body = R<HttpPaginationResponse<ArticleHttpPayload<ArticleListHttpResponse>>>,
+            headers(
+                ("ETag" = String, description = "响应内容的强校验值"),
+                ("Last-Modified" = String, description = "本页文章中最近的更新时间，空页时没有")
+            )),
+        (status = 304, description = "If-None-Match或If-Modified-Since匹配，客户端缓存仍然有效"),
+        (status = 400, description = "分页、排序或字段参数无效", body = ErrorResponse),
+    )
+)]
+pub async fn get_articles(
+    State(controller): State<Arc<ArticleController>>,
+    Query(params): Query<HttpPaginationRequest>,
+    Query(selection): Query<HttpFieldSelectionRequest>,
+) -> Result<impl IntoResponse, RhyonError> {
+    // 从HTTP参数构建查询对象
+    let sort = params
+        .sort_string()
+        .map(ArticleSortSpec::from)
+        .unwrap_or_default();
+    let selected = selection.field_set()?;
+    let fields = selection
+        .resolve(selected.clone(), ArticleFieldSet::list_default)?
+        .with(ArticleField::UpdatedAt);
+    let shape =
+        |model| ArticleHttpPayload::<ArticleListHttpResponse>::from_model(model, selected.as_ref());
+    let modified = |items: &[ArticleQueryModel]| items.iter().map(|model| model.updated_at).max();
+
+    let (modified, response): (_, HttpPaginationResponse<_>) = if params.is_cursor_mode() {
+        let query = GetArticlesByCursorQuery::new(params.into_cursor_pagination()?)
+            .with_sort(sort)
+            .with_fields(fields);
+        let result = controller
             .application_service
-            .create_article(command)
+            .get_articles_by_cursor(query)
             .await?;
-        Ok(R::success(article_id.to_string()))
+        (modified(&result.items), result.map(shape).into())
+    } else {
+        let query = GetArticlesQuery::new(params.into_pagination())
+            .with_sort(sort)
+            .with_fields(fields);
+        let result = controller.application_service.get_articles(query).await?;
+        (modified(&result.items), result.map(shape).into())
+    };
+
+    Ok((modified.map(last_modified), R::success(response)))
+}
+
+/// 根据slug获取文章详情
+///
+/// 支持 `fields` 参数；响应带有`Last-Modified`，未选择`updated_at`字段时也会读取它
+#[utoipa::path(
+    get,
+    path = "/articles/{slug}",
+    tag = "articles",
+    params(("slug" = String, Path, description = "文章slug"), HttpFieldSelectionRequest),
+    responses(
+        (status = 200, description = "文章详情",
+            body = R<ArticleHttpPayload<ArticleDetailHttpResponse>>,
+            headers(
+                ("ETag" = String, description = "响应内容的强校验值"),
+                ("Last-Modified" = String, description = "文章的最后修改时间")
+            )),
+        (status = 304, description = "If-None-Match或If-Modified-Since匹配，客户端缓存仍然有效"),
+        (status = 400, description = "字段参数无效", body = ErrorResponse),
+        (status = 404, description = "文章不存在", body = ErrorResponse),
+    )
+)]
+pub async fn get_article_by_slug(
+    State(controller): State<Arc<ArticleController>>,
+    Path(slug): Path<String>,
+    Query(selection): Query<HttpFieldSelectionRequest>,
+) -> Result<impl IntoResponse, RhyonError> {
+    let selected = selection.field_set()?;
+    let fields = selection
+        .resolve(selected.clone(), ArticleFieldSet::all)?
+        .with(ArticleField::UpdatedAt);
+
+    let query = GetArticleBySlugQuery::new(slug).with_fields(fields);
+    let article = controller
+        .application_service
+        .get_article_by_slug(query)
+        .await?
+        .ok_or(RhyonError::NotFound)?;
+
+    let modified = last_modified(article.updated_at);
+    Ok((
+        modified,
+        R::success(ArticleHttpPayload::<ArticleDetailHttpResponse>::from_model(
+            article,
+            selected.as_ref(),
+        )),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::http::middleware::{AdminConfig, load_http_cache_config};
+    use crate::adapters::inbound::http::routes::article_routes;
+    use crate::adapters::outbound::persistence::database::testing::{
+        article_service, publish_article, sqlite_test_db,
+    };
+    use crate::domain::article::commands::CreateArticleCommand;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::header::{IF_MODIFIED_SINCE, LAST_MODIFIED};
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    fn app(service: Arc<dyn ArticleApplicationService>) -> Router {
+        let controller = Arc::new(ArticleController::new(service));
+        let admin = Arc::new(AdminConfig {
+            token: "secret".to_string(),
+        });
+        article_routes(&load_http_cache_config(), admin).with_state(controller)
     }
 
-    /// GET /articles - 获取文章列表
-    pub async fn get_articles(
-        State(controller): State<Arc<ArticleController>>,
-        Query(params): Query<HttpPaginationRequest>,
-    ) -> Result<R<HttpPaginationResponse<ArticleListHttpResponse>>, RhyonError> {
-        // 从HTTP参数构建查询对象
-        let sort = params
-            .sort_string()
-            .map(ArticleSortSpec::from)
-            .unwrap_or_default();
-        let pagination = params.into_pagination();
-
-        let query = GetArticlesQuery::new(pagination).with_sort(sort);
-        let result = controller.application_service.get_articles(query).await?;
+    async fn send(app: &Router, uri: &str, token: Option<&str>, body: &str) -> StatusCode {
+        let mut request = Request::post(uri).header("content-type", "application/json");
+        if let Some(token) = token {
+            request = request.header("authorization", format!("Bearer {token}"));
+        }
+        let request = request.body(Body::from(body.to_string())).unwrap();
+        app.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn creating_and_publishing_require_the_admin_token() {
+        let db = sqlite_test_db().await;
+        let app = app(article_service(&db));
+        let article = r#"{"title": "Hello", "content": "body"}"#;
 
-        let response: HttpPaginationResponse<ArticleListHttpResponse> =
-            result.map(|model| model.into()).into();
+        assert_eq!(
+            send(&app, "/", None, article).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            send(&app, "/", Some("wrong"), article).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            send(&app, "/hello/publish", None, "").await,
+            StatusCode::UNAUTHORIZED
+        );
 
-        Ok(R::success(response))
+        assert_eq!(
+            send(&app, "/", Some("secret"), article).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            send(&app, "/hello/publish", Some("secret"), "").await,
+            StatusCode::OK
+        );
     }
 
-    /// GET /articles/{slug} - 根据slug获取文章详情
-    pub async fn get_article_by_slug(
-        State(controller): State<Arc<ArticleController>>,
-        Path(slug): Path<String>,
-    ) -> Result<R<ArticleDetailHttpResponse>, RhyonError> {
-        let query = GetArticleBySlugQuery::new(slug);
-        let article = controller
-            .application_service
-            .get_article_by_slug(query)
-            .await?
-            .ok_or(RhyonError::NotFound)?;
+    #[tokio::test]
+    async fn list_is_last_modified_at_its_newest_article() {
+        let db = sqlite_test_db().await;
+        let service = article_service(&db);
+        for title in ["First", "Second"] {
+            let command = CreateArticleCommand::new(title.to_string(), "body".to_string());
+            publish_article(&db, &*service, command).await;
+        }
+        let newest = service
+            .get_article_by_slug(GetArticleBySlugQuery::new("second".to_string()))
+            .await
+            .unwrap()
+            .unwrap()
+            .updated_at;
+        let expected = last_modified(newest)[0].1.clone();
+        let app = app(service);
+
+        // 未选择updated_at字段时也按它生成Last-Modified
+        for uri in ["/", "/?fields=title", "/?cursor="] {
+            let request = Request::get(uri).body(Body::empty()).unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{uri}");
+            assert_eq!(
+                response.headers()[LAST_MODIFIED],
+                expected.as_str(),
+                "{uri}"
+            );
 
-        Ok(R::success(article.into()))
+            let request = Request::get(uri)
+                .header(IF_MODIFIED_SINCE, &expected)
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_MODIFIED, "{uri}");
+        }
     }
 }
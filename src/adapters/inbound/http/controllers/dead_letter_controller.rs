@@ -0,0 +1,35 @@
+use axum::extract::State;
+use std::sync::Arc;
+
+use crate::adapters::inbound::http::openapi::ErrorResponse;
+use crate::adapters::outbound::events::{DeadLetter, DeadLetterList};
+use crate::core::response::R;
+
+pub struct DeadLetterController {
+    dead_letters: DeadLetterList,
+}
+
+impl DeadLetterController {
+    pub fn new(dead_letters: DeadLetterList) -> Self {
+        Self { dead_letters }
+    }
+}
+
+/// 死信列表
+///
+/// 事件处理器重试耗尽后记录的死信
+#[utoipa::path(
+    get,
+    path = "/admin/dead-letters",
+    tag = "admin",
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, description = "死信列表", body = R<Vec<DeadLetter>>),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+    )
+)]
+pub async fn list_dead_letters(
+    State(controller): State<Arc<DeadLetterController>>,
+) -> R<Vec<DeadLetter>> {
+    R::success(controller.dead_letters.entries())
+}
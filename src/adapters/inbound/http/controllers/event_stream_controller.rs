@@ -0,0 +1,242 @@
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+
+use crate::adapters::inbound::http::dto::EventStreamHttpRequest;
+use crate::adapters::inbound::http::sse::{EventStreamHub, StreamedEvent};
+
+/// 断线重连时客户端携带的最后事件ID
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+pub struct EventStreamController {
+    hub: Arc<EventStreamHub>,
+}
+
+impl EventStreamController {
+    pub fn new(hub: Arc<EventStreamHub>) -> Self {
+        Self { hub }
+    }
+}
+
+/// 以SSE推送文章相关的领域事件
+///
+/// 支持 `types` 参数过滤事件类型；事件ID为事件的全局位置，
+/// 携带 `Last-Event-ID` 重连到任意实例都可以从重放缓冲区续传
+#[utoipa::path(
+    get,
+    path = "/events/stream",
+    tag = "events",
+    params(
+        EventStreamHttpRequest,
+        ("Last-Event-ID" = Option<String>, Header, description = "最后收到的事件ID")
+    ),
+    responses(
+        (status = 200, description = "事件流", content_type = "text/event-stream", body = String),
+    )
+)]
+pub async fn stream_events(
+    State(controller): State<Arc<EventStreamController>>,
+    headers: HeaderMap,
+    Query(params): Query<EventStreamHttpRequest>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let hub = controller.hub.clone();
+    let last_event_id = headers
+        .get(LAST_EVENT_ID_HEADER)
+        .and_then(|value| value.to_str().ok());
+    let (replay, receiver) = hub.subscribe(last_event_id);
+
+    let event_types = params.event_types();
+    let wanted = move |event: &StreamedEvent| {
+        event_types
+            .as_ref()
+            .is_none_or(|types| types.contains(&event.event_type))
+    };
+
+    // 客户端消费过慢导致积压溢出时结束流，由客户端携带Last-Event-ID重连续传
+    let live = BroadcastStream::new(receiver)
+        .take_while(Result::is_ok)
+        .filter_map(Result::ok);
+
+    let keep_alive = Duration::from_secs(hub.config().keep_alive_secs);
+    let stream = tokio_stream::iter(replay)
+        .chain(live)
+        .filter(wanted)
+        .map(move |event| {
+            Ok(Event::default()
+                .id(event.id())
+                .event(&event.event_type)
+                .data(event.data.to_string()))
+        });
+
+    Sse::new(stream).keep_alive(KeepAlive::new().interval(keep_alive))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::http::routes::event_stream_routes;
+    use crate::adapters::inbound::http::sse::EventStreamFeed;
+    use crate::adapters::inbound::http::sse::config::load_event_stream_config;
+    use crate::adapters::outbound::persistence::database::testing::{
+        article_service, publish_article, sqlite_test_db,
+    };
+    use crate::adapters::outbound::persistence::event_store::SeaOrmEventStore;
+    use crate::adapters::outbound::persistence::projection::ArticleReadViewProgress;
+    use crate::domain::article::commands::CreateArticleCommand;
+    use crate::shared::events::EventStore;
+    use axum::Router;
+    use axum::body::{Body, BodyDataStream};
+    use axum::http::Request;
+    use sea_orm::DatabaseConnection;
+    use tower::ServiceExt;
+
+    /// 一个应用实例：各自的事件流中心与事件存储数据源
+    struct Instance {
+        app: Router,
+        feed: EventStreamFeed,
+    }
+
+    impl Instance {
+        async fn start(db: &DatabaseConnection) -> Self {
+            let hub = Arc::new(EventStreamHub::new(load_event_stream_config()));
+            let feed = EventStreamFeed::new(
+                hub.clone(),
+                Arc::new(SeaOrmEventStore::new(db.clone())),
+                Arc::new(ArticleReadViewProgress::new(db.clone())),
+            );
+            feed.backfill().await.unwrap();
+            let controller = Arc::new(EventStreamController::new(hub));
+
+            Self {
+                app: event_stream_routes().with_state(controller),
+                feed,
+            }
+        }
+
+        /// 推送投影检查点之前的全部事件
+        async fn catch_up(&self) {
+            while self.feed.feed_batch().await.unwrap() > 0 {}
+        }
+
+        async fn connect(&self, uri: &str, last_event_id: Option<&str>) -> SseClient {
+            let mut request = Request::get(uri);
+            if let Some(id) = last_event_id {
+                request = request.header(LAST_EVENT_ID_HEADER, id);
+            }
+            let response = self
+                .app
+                .clone()
+                .oneshot(request.body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+
+            SseClient {
+                body: response.into_body().into_data_stream(),
+                buffer: String::new(),
+            }
+        }
+    }
+
+    /// 逐个解析SSE响应中的事件
+    struct SseClient {
+        body: BodyDataStream,
+        buffer: String,
+    }
+
+    impl SseClient {
+        /// 下一个事件的ID与类型
+        async fn next(&mut self) -> (String, String) {
+            loop {
+                if let Some(end) = self.buffer.find("\n\n") {
+                    let frame: String = self.buffer.drain(..end + 2).collect();
+                    let field = |name: &str| {
+                        frame
+                            .lines()
+                            .find_map(|line| line.strip_prefix(name))
+                            .map(|value| value.trim().to_string())
+                    };
+                    if let (Some(id), Some(event)) = (field("id:"), field("event:")) {
+                        return (id, event);
+                    }
+                    continue;
+                }
+
+                let chunk = tokio::time::timeout(Duration::from_secs(5), self.body.next())
+                    .await
+                    .expect("an event should arrive")
+                    .expect("the stream should stay open")
+                    .unwrap();
+                self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+            }
+        }
+    }
+
+    /// 事件存储中全部事件的位置与类型
+    async fn stored_events(db: &DatabaseConnection) -> Vec<(String, String)> {
+        SeaOrmEventStore::new(db.clone())
+            .read_all(0, 100)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|stored| (stored.position.to_string(), stored.envelope.event_type))
+            .collect()
+    }
+
+    fn command(title: &str) -> CreateArticleCommand {
+        CreateArticleCommand::new(title.to_string(), "body".to_string())
+    }
+
+    #[tokio::test]
+    async fn events_are_filtered_by_type() {
+        let db = sqlite_test_db().await;
+        let service = article_service(&db);
+        let instance = Instance::start(&db).await;
+        let mut client = instance
+            .connect("/stream?types=article.published", None)
+            .await;
+
+        publish_article(&db, &*service, command("First")).await;
+        publish_article(&db, &*service, command("Second")).await;
+        instance.catch_up().await;
+
+        let published: Vec<_> = stored_events(&db)
+            .await
+            .into_iter()
+            .filter(|(_, event_type)| event_type == "article.published")
+            .collect();
+        assert_eq!(published.len(), 2);
+        for expected in published {
+            assert_eq!(client.next().await, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn clients_resume_on_another_instance_from_the_global_position() {
+        let db = sqlite_test_db().await;
+        let service = article_service(&db);
+        let first = Instance::start(&db).await;
+        let mut client = first.connect("/stream", None).await;
+
+        publish_article(&db, &*service, command("First")).await;
+        first.catch_up().await;
+        let events = stored_events(&db).await;
+        assert_eq!(events.len(), 2);
+        assert_eq!(client.next().await, events[0]);
+
+        // 断线后重连到另一个实例：从重放缓冲区补上错过的事件，再接收实时事件
+        let second = Instance::start(&db).await;
+        let mut client = second.connect("/stream", Some(&events[0].0)).await;
+        assert_eq!(client.next().await, events[1]);
+
+        publish_article(&db, &*service, command("Second")).await;
+        second.catch_up().await;
+        let events = stored_events(&db).await;
+        assert_eq!(client.next().await, events[2]);
+        assert_eq!(client.next().await, events[3]);
+    }
+}
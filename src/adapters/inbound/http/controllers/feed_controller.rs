@@ -0,0 +1,228 @@
+use axum::extract::{OriginalUri, Path, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+use crate::adapters::inbound::http::dto::FeedScopeHttpRequest;
+use crate::adapters::inbound::http::middleware::last_modified;
+use crate::adapters::inbound::http::site::config::{FeedConfig, FeedContent, SiteConfig};
+use crate::adapters::inbound::http::site::{
+    ATOM_CONTENT_TYPE, Feed, FeedEntry, JSON_FEED_CONTENT_TYPE, RSS_CONTENT_TYPE,
+};
+use crate::application::models::{ArticleField, ArticleFieldSet};
+use crate::application::queries::GetArticlesQuery;
+use crate::application::services::ArticleApplicationService;
+use crate::domain::article::specifications::ArticleSortSpec;
+use crate::shared::errors::RhyonError;
+use crate::shared::pagination::QueryPagination;
+
+pub struct FeedController {
+    application_service: Arc<dyn ArticleApplicationService>,
+    site: SiteConfig,
+    config: FeedConfig,
+}
+
+impl FeedController {
+    pub fn new(
+        application_service: Arc<dyn ArticleApplicationService>,
+        site: SiteConfig,
+        config: FeedConfig,
+    ) -> Self {
+        Self {
+            application_service,
+            site,
+            config,
+        }
+    }
+
+    /// 按发布时间倒序读取最新的已发布文章
+    async fn feed(
+        &self,
+        path: &str,
+        scope: Option<Path<FeedScopeHttpRequest>>,
+    ) -> Result<Feed, RhyonError> {
+        let scope = scope.map(|Path(scope)| scope).unwrap_or_default();
+
+        let fields = ArticleFieldSet::list_default()
+            .with(ArticleField::AuthorName)
+            .with(ArticleField::Tags);
+        let fields = match self.config.content {
+            FeedContent::Full => fields.with(ArticleField::Content),
+            FeedContent::Summary => fields,
+        };
+
+        let query = GetArticlesQuery::new(QueryPagination::new(1, self.config.limit))
+            .with_specification(scope.specification())
+            .with_sort(ArticleSortSpec::published_at_desc())
+            .with_fields(fields);
+        let articles = self.application_service.get_articles(query).await?;
+
+        Ok(Feed {
+            title: scope.title(&self.site.title),
+            description: self.site.description.clone(),
+            language: self.site.language.clone(),
+            home_url: self.site.url_for("/"),
+            feed_url: self.site.url_for(path),
+            entries: articles
+                .items
+                .into_iter()
+                .map(|article| FeedEntry::from_model(article, &self.site, self.config.content))
+                .collect(),
+        })
+    }
+
+    /// 带有`Last-Modified`（最新条目的更新时间）的订阅源响应
+    fn respond(feed: &Feed, content_type: &'static str, body: String) -> Response {
+        (
+            feed.updated().map(last_modified),
+            [(CONTENT_TYPE, content_type)],
+            body,
+        )
+            .into_response()
+    }
+}
+
+/// RSS 2.0订阅源
+///
+/// 另有 `/tags/{tag}/feed.xml`、`/authors/{author}/feed.xml`
+#[utoipa::path(
+    get,
+    path = "/feed.xml",
+    tag = "feeds",
+    responses(
+        (status = 200, description = "RSS 2.0文档", content_type = "application/rss+xml", body = String,
+            headers(("Last-Modified" = String, description = "最新条目的更新时间"))),
+        (status = 304, description = "客户端缓存仍然有效"),
+    )
+)]
+pub async fn rss(
+    State(controller): State<Arc<FeedController>>,
+    OriginalUri(uri): OriginalUri,
+    scope: Option<Path<FeedScopeHttpRequest>>,
+) -> Result<Response, RhyonError> {
+    let feed = controller.feed(uri.path(), scope).await?;
+    Ok(FeedController::respond(
+        &feed,
+        RSS_CONTENT_TYPE,
+        feed.to_rss(),
+    ))
+}
+
+/// Atom订阅源
+#[utoipa::path(
+    get,
+    path = "/atom.xml",
+    tag = "feeds",
+    responses(
+        (status = 200, description = "Atom文档", content_type = "application/atom+xml", body = String,
+            headers(("Last-Modified" = String, description = "最新条目的更新时间"))),
+        (status = 304, description = "客户端缓存仍然有效"),
+    )
+)]
+pub async fn atom(
+    State(controller): State<Arc<FeedController>>,
+    OriginalUri(uri): OriginalUri,
+    scope: Option<Path<FeedScopeHttpRequest>>,
+) -> Result<Response, RhyonError> {
+    let feed = controller.feed(uri.path(), scope).await?;
+    Ok(FeedController::respond(
+        &feed,
+        ATOM_CONTENT_TYPE,
+        feed.to_atom(),
+    ))
+}
+
+/// JSON Feed订阅源
+#[utoipa::path(
+    get,
+    path = "/feed.json",
+    tag = "feeds",
+    responses(
+        (status = 200, description = "JSON Feed文档", content_type = "application/feed+json", body = String,
+            headers(("Last-Modified" = String, description = "最新条目的更新时间"))),
+        (status = 304, description = "客户端缓存仍然有效"),
+    )
+)]
+pub async fn json(
+    State(controller): State<Arc<FeedController>>,
+    OriginalUri(uri): OriginalUri,
+    scope: Option<Path<FeedScopeHttpRequest>>,
+) -> Result<Response, RhyonError> {
+    let feed = controller.feed(uri.path(), scope).await?;
+    Ok(FeedController::respond(
+        &feed,
+        JSON_FEED_CONTENT_TYPE,
+        feed.to_json(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::http::middleware::load_http_cache_config;
+    use crate::adapters::inbound::http::routes::feed_routes;
+    use crate::adapters::inbound::http::site::config::{load_feed_config, load_site_config};
+    use crate::adapters::outbound::persistence::database::testing::{
+        article_service, project, publish_article, sqlite_test_db,
+    };
+    use crate::domain::article::commands::CreateArticleCommand;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    fn command(title: &str, author: &str, tags: &[&str]) -> CreateArticleCommand {
+        CreateArticleCommand {
+            author: Some(author.to_string()),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..CreateArticleCommand::new(title.to_string(), "body".to_string())
+        }
+    }
+
+    /// 订阅源中按顺序出现的文章标题
+    async fn titles(app: &Router, uri: &str) -> Vec<String> {
+        let response = app
+            .clone()
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "{uri}");
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let feed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        feed["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["title"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn tag_and_author_feeds_list_matching_published_articles() {
+        let db = sqlite_test_db().await;
+        let service = article_service(&db);
+        publish_article(&db, &*service, command("Rust tips", "Ada", &["rust"])).await;
+        publish_article(&db, &*service, command("Cooking", "Bob", &["food", "rust"])).await;
+        // 草稿不出现在订阅源中
+        service
+            .create_article(command("Draft", "Ada", &["rust"]))
+            .await
+            .unwrap();
+        project(&db).await;
+
+        let controller = Arc::new(FeedController::new(
+            service,
+            load_site_config(),
+            load_feed_config(),
+        ));
+        let app = feed_routes(&load_http_cache_config()).with_state(controller);
+
+        let mut rust = titles(&app, "/tags/rust/feed.json").await;
+        rust.sort();
+        assert_eq!(rust, ["Cooking", "Rust tips"]);
+        assert_eq!(titles(&app, "/tags/FOOD/feed.json").await, ["Cooking"]);
+        assert_eq!(titles(&app, "/authors/Ada/feed.json").await, ["Rust tips"]);
+        assert!(titles(&app, "/tags/go/feed.json").await.is_empty());
+    }
+}
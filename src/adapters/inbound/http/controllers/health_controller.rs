@@ -0,0 +1,117 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use sea_orm::{ConnAcquireErr, ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr};
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::adapters::inbound::http::dto::{
+    ConnectionPoolHttpResponse, DatabaseHealthError, DatabaseHealthHttpResponse,
+};
+use crate::core::response::R;
+use crate::shared::i18n::{Locale, Message};
+
+pub struct HealthController {
+    db: DatabaseConnection,
+}
+
+impl HealthController {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 将数据库错误归类为可公开的失败原因，避免向未认证调用方暴露主机或驱动信息
+    fn classify(err: &DbErr) -> DatabaseHealthError {
+        match err {
+            DbErr::ConnectionAcquire(ConnAcquireErr::Timeout) => DatabaseHealthError::PoolTimeout,
+            DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed) => {
+                DatabaseHealthError::PoolClosed
+            }
+            DbErr::Conn(_) => DatabaseHealthError::ConnectionFailed,
+            _ => DatabaseHealthError::QueryFailed,
+        }
+    }
+
+    fn pool(&self) -> ConnectionPoolHttpResponse {
+        let (size, idle) = match self.db.get_database_backend() {
+            DatabaseBackend::Postgres => {
+                let pool = self.db.get_postgres_connection_pool();
+                (pool.size(), pool.num_idle())
+            }
+            DatabaseBackend::Sqlite => {
+                let pool = self.db.get_sqlite_connection_pool();
+                (pool.size(), pool.num_idle())
+            }
+            DatabaseBackend::MySql => (0, 0),
+        };
+
+        ConnectionPoolHttpResponse { size, idle }
+    }
+}
+
+/// 数据库探针
+///
+/// 数据库不可用时返回503，`data.error`给出失败原因
+#[utoipa::path(
+    get,
+    path = "/health/db",
+    tag = "health",
+    responses(
+        (status = 200, description = "数据库可用", body = R<DatabaseHealthHttpResponse>),
+        (status = 503, description = "数据库不可用", body = R<DatabaseHealthHttpResponse>),
+    )
+)]
+pub async fn database(
+    State(controller): State<Arc<HealthController>>,
+) -> (StatusCode, R<DatabaseHealthHttpResponse>) {
+    let started = Instant::now();
+    let result = controller.db.ping().await;
+
+    let mut health = DatabaseHealthHttpResponse {
+        status: "up",
+        backend: format!("{:?}", controller.db.get_database_backend()).to_lowercase(),
+        latency_ms: started.elapsed().as_millis() as u64,
+        pool: controller.pool(),
+        error: None,
+    };
+
+    match result {
+        Ok(()) => (StatusCode::OK, R::success(health)),
+        Err(err) => {
+            tracing::warn!("⚠️ 数据库健康检查失败: {}", err);
+            health.status = "down";
+            health.error = Some(HealthController::classify(&err));
+            let status = StatusCode::SERVICE_UNAVAILABLE;
+            let message = Message::new("database-unavailable").localize(Locale::current());
+            let body = R::error(status.as_u16(), &message).with_data(health);
+            (status, body)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::http::routes::health_routes;
+    use crate::adapters::outbound::persistence::database::testing::sqlite_test_db;
+    use axum::body::{Body, to_bytes};
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn unavailable_database_reports_only_the_failure_reason() {
+        let db = sqlite_test_db().await;
+        let app = health_routes().with_state(Arc::new(HealthController::new(db.clone())));
+        db.close().await.unwrap();
+
+        let response = app
+            .oneshot(Request::get("/db").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["data"]["status"], "down");
+        assert_eq!(body["data"]["error"], "pool_closed");
+    }
+}
@@ -0,0 +1,26 @@
+use axum::extract::State;
+use std::sync::Arc;
+
+use crate::adapters::outbound::cache::{ArticleCache, CacheMetricsSnapshot};
+use crate::core::response::R;
+
+pub struct MetricsController {
+    article_cache: Arc<ArticleCache>,
+}
+
+impl MetricsController {
+    pub fn new(article_cache: Arc<ArticleCache>) -> Self {
+        Self { article_cache }
+    }
+}
+
+/// 文章查询缓存的命中统计
+#[utoipa::path(
+    get,
+    path = "/metrics/cache",
+    tag = "metrics",
+    responses((status = 200, description = "缓存命中统计", body = R<CacheMetricsSnapshot>))
+)]
+pub async fn cache(State(controller): State<Arc<MetricsController>>) -> R<CacheMetricsSnapshot> {
+    R::success(controller.article_cache.metrics())
+}
@@ -1,3 +1,19 @@
 pub mod article_controller;
+pub mod dead_letter_controller;
+pub mod event_stream_controller;
+pub mod feed_controller;
+pub mod health_controller;
+pub mod metrics_controller;
+pub mod site_controller;
+pub mod theme_controller;
+pub mod webhook_controller;
 
 pub use article_controller::*;
+pub use dead_letter_controller::*;
+pub use event_stream_controller::*;
+pub use feed_controller::*;
+pub use health_controller::*;
+pub use metrics_controller::*;
+pub use site_controller::*;
+pub use theme_controller::*;
+pub use webhook_controller::*;
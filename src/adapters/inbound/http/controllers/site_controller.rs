@@ -0,0 +1,384 @@
+use axum::extract::{Path, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+use crate::adapters::inbound::http::middleware::last_modified;
+use crate::adapters::inbound::http::openapi::ErrorResponse;
+use crate::adapters::inbound::http::site::config::{RobotsConfig, SiteConfig};
+use crate::adapters::inbound::http::site::{
+    MAX_SITEMAP_URLS, ROBOTS_CONTENT_TYPE, SITEMAP_CONTENT_TYPE, SitemapUrl, robots_txt,
+    sitemap_index, urlset,
+};
+use crate::application::models::{ArticleField, ArticleFieldSet, ArticleQueryModel, TagQueryModel};
+use crate::application::queries::{CountArticlesQuery, GetArticlesQuery, GetTagsQuery};
+use crate::application::services::ArticleApplicationService;
+use crate::domain::article::specifications::{ArticleSortSpec, ArticleSpec};
+use crate::shared::errors::RhyonError;
+use crate::shared::pagination::{QueryPage, QueryPagination};
+use crate::shared::query::SortCriteria;
+
+/// 站点级资源：站点地图与robots.txt
+///
+/// 站点地图包含已发布文章的页面与标签页
+pub struct SiteController {
+    application_service: Arc<dyn ArticleApplicationService>,
+    site: SiteConfig,
+    robots: RobotsConfig,
+}
+
+impl SiteController {
+    pub fn new(
+        application_service: Arc<dyn ArticleApplicationService>,
+        site: SiteConfig,
+        robots: RobotsConfig,
+    ) -> Self {
+        Self {
+            application_service,
+            site,
+            robots,
+        }
+    }
+
+    /// 按发布时间升序读取一页已发布文章，新文章总是追加在末尾，已有分页的内容保持稳定
+    async fn articles(&self, page: u64) -> Result<QueryPage<ArticleQueryModel>, RhyonError> {
+        let sort = SortCriteria::new().asc("published_at").asc("id");
+        let query = GetArticlesQuery::new(QueryPagination::batch(page, MAX_SITEMAP_URLS))
+            .with_specification(ArticleSpec::published())
+            .with_sort(ArticleSortSpec::Custom(sort))
+            .with_fields(ArticleFieldSet::only([
+                ArticleField::Slug,
+                ArticleField::UpdatedAt,
+            ]));
+        self.application_service.get_articles(query).await
+    }
+
+    /// 已发布文章使用的全部标签
+    async fn tags(&self) -> Result<Vec<TagQueryModel>, RhyonError> {
+        self.application_service.get_tags(GetTagsQuery::new()).await
+    }
+
+    fn article_urls(&self, articles: QueryPage<ArticleQueryModel>) -> Vec<SitemapUrl> {
+        articles
+            .items
+            .into_iter()
+            .map(|article| SitemapUrl {
+                loc: self.site.article_url(&article.slug),
+                lastmod: Some(article.updated_at),
+            })
+            .collect()
+    }
+
+    /// 标签页的更新时间为带有该标签的文章中最近的更新时间
+    fn tag_urls(&self, tags: Vec<TagQueryModel>) -> Vec<SitemapUrl> {
+        tags.into_iter()
+            .map(|tag| SitemapUrl {
+                loc: self.site.tag_url(&tag.tag),
+                lastmod: Some(tag.updated_at),
+            })
+            .collect()
+    }
+
+    /// 带有`Last-Modified`（最近更新的地址）的站点地图响应
+    fn urlset(&self, urls: Vec<SitemapUrl>) -> Response {
+        let modified = urls.iter().filter_map(|url| url.lastmod).max();
+
+        (
+            modified.map(last_modified),
+            [(CONTENT_TYPE, SITEMAP_CONTENT_TYPE)],
+            urlset(&urls),
+        )
+            .into_response()
+    }
+}
+
+/// 站点地图
+///
+/// 全部已发布文章及标签页的站点地图，超过单个站点地图的上限时返回站点地图索引
+#[utoipa::path(
+    get,
+    path = "/sitemap.xml",
+    tag = "site",
+    responses(
+        (status = 200, description = "站点地图或站点地图索引", content_type = "application/xml", body = String),
+        (status = 304, description = "客户端缓存仍然有效"),
+    )
+)]
+pub async fn sitemap(
+    State(controller): State<Arc<SiteController>>,
+) -> Result<Response, RhyonError> {
+    // 索引只需要页数，用计数查询代替读取整页文章
+    let total = controller
+        .application_service
+        .count_articles(CountArticlesQuery::new())
+        .await?;
+    let tags = controller.tags().await?;
+    if total + tags.len() as u64 <= MAX_SITEMAP_URLS {
+        let mut urls = controller.article_urls(controller.articles(1).await?);
+        urls.extend(controller.tag_urls(tags));
+        return Ok(controller.urlset(urls));
+    }
+
+    let mut sitemaps: Vec<_> = (1..=total.div_ceil(MAX_SITEMAP_URLS))
+        .map(|page| SitemapUrl {
+            loc: controller
+                .site
+                .url_for(&format!("sitemaps/articles/{}.xml", page)),
+            lastmod: None,
+        })
+        .collect();
+    if !tags.is_empty() {
+        sitemaps.push(SitemapUrl {
+            loc: controller.site.url_for("sitemaps/tags.xml"),
+            lastmod: tags.iter().map(|tag| tag.updated_at).max(),
+        });
+    }
+    Ok((
+        [(CONTENT_TYPE, SITEMAP_CONTENT_TYPE)],
+        sitemap_index(&sitemaps),
+    )
+        .into_response())
+}
+
+/// 站点地图索引中的第page个文章站点地图
+#[utoipa::path(
+    get,
+    path = "/sitemaps/articles/{file}",
+    tag = "site",
+    params(("file" = String, Path, description = "`{page}.xml`，page从1开始")),
+    responses(
+        (status = 200, description = "文章站点地图", content_type = "application/xml", body = String),
+        (status = 304, description = "客户端缓存仍然有效"),
+        (status = 404, description = "页码不存在", body = ErrorResponse),
+    )
+)]
+pub async fn article_sitemap(
+    State(controller): State<Arc<SiteController>>,
+    Path(file): Path<String>,
+) -> Result<Response, RhyonError> {
+    let page = file
+        .strip_suffix(".xml")
+        .and_then(|page| page.parse::<u64>().ok())
+        .filter(|page| *page >= 1)
+        .ok_or(RhyonError::NotFound)?;
+
+    let articles = controller.articles(page).await?;
+    if page > articles.total_pages.max(1) {
+        return Err(RhyonError::NotFound);
+    }
+    Ok(controller.urlset(controller.article_urls(articles)))
+}
+
+/// 站点地图索引中的标签页站点地图
+///
+/// 标签数量远少于单个站点地图的上限，不再拆分
+#[utoipa::path(
+    get,
+    path = "/sitemaps/tags.xml",
+    tag = "site",
+    responses(
+        (status = 200, description = "标签页站点地图", content_type = "application/xml", body = String),
+        (status = 304, description = "客户端缓存仍然有效"),
+    )
+)]
+pub async fn tag_sitemap(
+    State(controller): State<Arc<SiteController>>,
+) -> Result<Response, RhyonError> {
+    let tags = controller.tags().await?;
+    Ok(controller.urlset(controller.tag_urls(tags)))
+}
+
+/// robots.txt
+#[utoipa::path(
+    get,
+    path = "/robots.txt",
+    tag = "site",
+    responses((status = 200, description = "爬虫规则", content_type = "text/plain", body = String))
+)]
+pub async fn robots(State(controller): State<Arc<SiteController>>) -> Response {
+    let sitemap_url = controller.site.url_for("sitemap.xml");
+    (
+        [(CONTENT_TYPE, ROBOTS_CONTENT_TYPE)],
+        robots_txt(&controller.robots, &sitemap_url),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::http::middleware::load_http_cache_config;
+    use crate::adapters::inbound::http::routes::site_routes;
+    use crate::adapters::inbound::http::site::config::{load_robots_config, load_site_config};
+    use crate::adapters::outbound::persistence::database::testing::{
+        article_service, publish_article, sqlite_test_db,
+    };
+    use crate::application::queries::{GetArticleBySlugQuery, GetArticlesByCursorQuery};
+    use crate::domain::article::commands::{CreateArticleCommand, PublishArticleCommand};
+    use crate::shared::pagination::CursorPage;
+    use async_trait::async_trait;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::http::{Request, StatusCode};
+    use chrono::{DateTime, TimeZone, Utc};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    /// 只能计数和列出标签的文章服务，读取文章列表时测试失败
+    struct CountOnlyService {
+        total: u64,
+        tags: Vec<TagQueryModel>,
+    }
+
+    #[async_trait]
+    impl ArticleApplicationService for CountOnlyService {
+        async fn create_article(&self, _command: CreateArticleCommand) -> Result<Uuid, RhyonError> {
+            unimplemented!()
+        }
+
+        async fn publish_article(&self, _command: PublishArticleCommand) -> Result<(), RhyonError> {
+            unimplemented!()
+        }
+
+        async fn get_articles(
+            &self,
+            _query: GetArticlesQuery,
+        ) -> Result<QueryPage<ArticleQueryModel>, RhyonError> {
+            panic!("the sitemap index must not load articles")
+        }
+
+        async fn get_articles_by_cursor(
+            &self,
+            _query: GetArticlesByCursorQuery,
+        ) -> Result<CursorPage<ArticleQueryModel>, RhyonError> {
+            unimplemented!()
+        }
+
+        async fn count_articles(&self, _query: CountArticlesQuery) -> Result<u64, RhyonError> {
+            Ok(self.total)
+        }
+
+        async fn get_tags(&self, _query: GetTagsQuery) -> Result<Vec<TagQueryModel>, RhyonError> {
+            Ok(self.tags.clone())
+        }
+
+        async fn get_article_by_slug(
+            &self,
+            _query: GetArticleBySlugQuery,
+        ) -> Result<Option<ArticleQueryModel>, RhyonError> {
+            unimplemented!()
+        }
+    }
+
+    fn day(n: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, n, 0, 0, 0).unwrap()
+    }
+
+    fn app(service: Arc<dyn ArticleApplicationService>) -> Router {
+        let controller = Arc::new(SiteController::new(
+            service,
+            load_site_config(),
+            load_robots_config(),
+        ));
+        site_routes(&load_http_cache_config()).with_state(controller)
+    }
+
+    async fn get(app: Router, uri: &str) -> String {
+        let response = app
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "{uri}");
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    fn tags() -> Vec<TagQueryModel> {
+        vec![
+            TagQueryModel {
+                tag: "rust".to_string(),
+                updated_at: day(3),
+            },
+            TagQueryModel {
+                tag: "web".to_string(),
+                updated_at: day(5),
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn sitemap_index_is_built_from_the_article_count() {
+        let app = app(Arc::new(CountOnlyService {
+            total: 2 * MAX_SITEMAP_URLS + 1,
+            tags: tags(),
+        }));
+
+        let body = get(app, "/sitemap.xml").await;
+        assert!(body.contains("<sitemapindex"));
+        assert_eq!(body.matches("<sitemap>").count(), 4);
+        assert!(body.contains("sitemaps/articles/3.xml"));
+        // 标签页站点地图的更新时间为最近更新的标签
+        assert!(body.contains(
+            "<loc>http://localhost:8080/sitemaps/tags.xml</loc><lastmod>2025-01-05T00:00:00Z</lastmod>"
+        ));
+    }
+
+    #[tokio::test]
+    async fn tag_sitemap_lists_every_tag_page() {
+        let app = app(Arc::new(CountOnlyService {
+            total: 2 * MAX_SITEMAP_URLS + 1,
+            tags: tags(),
+        }));
+
+        let body = get(app, "/sitemaps/tags.xml").await;
+        assert!(body.contains("<urlset"));
+        assert!(body.contains(
+            "<loc>http://localhost:8080/tags/rust</loc><lastmod>2025-01-03T00:00:00Z</lastmod>"
+        ));
+        assert!(body.contains(
+            "<loc>http://localhost:8080/tags/web</loc><lastmod>2025-01-05T00:00:00Z</lastmod>"
+        ));
+    }
+
+    #[tokio::test]
+    async fn sitemap_lists_articles_and_tag_pages() {
+        let db = sqlite_test_db().await;
+        let service = article_service(&db);
+        let command = |title: &str, tags: &[&str]| CreateArticleCommand {
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..CreateArticleCommand::new(title.to_string(), "body".to_string())
+        };
+        publish_article(&db, &*service, command("First", &["rust", "中文"])).await;
+        publish_article(&db, &*service, command("Second", &["rust"])).await;
+
+        let body = get(app(service.clone()), "/sitemap.xml").await;
+        assert!(body.contains("<urlset"));
+        assert_eq!(body.matches("<url>").count(), 4);
+
+        // 标签页的更新时间为带有该标签的文章中最近的更新时间
+        let updated_at = |slug: &str| {
+            let service = service.clone();
+            let slug = slug.to_string();
+            async move {
+                service
+                    .get_article_by_slug(GetArticleBySlugQuery::new(slug))
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .updated_at
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            }
+        };
+        let rust = format!(
+            "<loc>http://localhost:8080/tags/rust</loc><lastmod>{}</lastmod>",
+            updated_at("second").await
+        );
+        let chinese = format!(
+            "<loc>http://localhost:8080/tags/%E4%B8%AD%E6%96%87</loc><lastmod>{}</lastmod>",
+            updated_at("first").await
+        );
+        assert!(body.contains(&rust), "{body}");
+        assert!(body.contains(&chinese), "{body}");
+    }
+}
@@ -0,0 +1,258 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use minijinja::context;
+use std::sync::Arc;
+
+use crate::adapters::inbound::http::dto::PageHttpRequest;
+use crate::adapters::inbound::http::middleware::last_modified;
+use crate::adapters::inbound::http::site::config::{SiteConfig, ThemeConfig};
+use crate::adapters::inbound::http::site::{ArchiveGroupView, ArticleView, PaginationView, Theme};
+use crate::application::models::{ArticleField, ArticleFieldSet, ArticleQueryModel};
+use crate::application::queries::{GetArticleBySlugQuery, GetArticlesQuery};
+use crate::application::services::ArticleApplicationService;
+use crate::domain::article::specifications::{ArticleSortSpec, ArticleSpec};
+use crate::shared::errors::RhyonError;
+use crate::shared::pagination::{QueryPage, QueryPagination};
+
+/// 服务端渲染的HTML页面，数据来自文章查询服务
+pub struct ThemeController {
+    application_service: Arc<dyn ArticleApplicationService>,
+    theme: Theme,
+    site: SiteConfig,
+    config: ThemeConfig,
+}
+
+impl ThemeController {
+    pub fn new(
+        application_service: Arc<dyn ArticleApplicationService>,
+        theme: Theme,
+        site: SiteConfig,
+        config: ThemeConfig,
+    ) -> Self {
+        Self {
+            application_service,
+            theme,
+            site,
+            config,
+        }
+    }
+
+    /// GET / - 首页：最新发布的文章
+    pub async fn index(
+        State(controller): State<Arc<ThemeController>>,
+        Query(request): Query<PageHttpRequest>,
+    ) -> Result<Response, RhyonError> {
+        let articles = controller
+            .articles(
+                ArticleSpec::published(),
+                QueryPagination::new(request.page, controller.config.page_size),
+            )
+            .await?;
+        if articles.items.is_empty() && request.page > 1 {
+            return controller.not_found();
+        }
+
+        let pagination = PaginationView::from(&articles);
+        controller.page(
+            "index.html",
+            context! {
+                site => &controller.site,
+                articles => controller.views(articles.items),
+                pagination,
+            },
+        )
+    }
+
+    /// GET /posts/{slug} - 文章页面，未发布的文章视为不存在
+    pub async fn article(
+        State(controller): State<Arc<ThemeController>>,
+        Path(slug): Path<String>,
+    ) -> Result<Response, RhyonError> {
+        let fields = ArticleFieldSet::all()
+            .with(ArticleField::AuthorName)
+            .with(ArticleField::Tags);
+        let query = GetArticleBySlugQuery::new(slug).with_fields(fields);
+        let article = controller
+            .application_service
+            .get_article_by_slug(query)
+            .await?
+            .filter(|article| article.status == "published");
+        let Some(article) = article else {
+            return controller.not_found();
+        };
+
+        let modified = last_modified(article.updated_at);
+        let page = controller.page(
+            "article.html",
+            context! {
+                site => &controller.site,
+                article => ArticleView::from_model(article, &controller.site),
+            },
+        )?;
+        Ok((modified, page).into_response())
+    }
+
+    /// GET /tags/{tag} - 标签页：带有该标签的文章
+    pub async fn tag(
+        State(controller): State<Arc<ThemeController>>,
+        Path(tag): Path<String>,
+        Query(request): Query<PageHttpRequest>,
+    ) -> Result<Response, RhyonError> {
+        // 标签存储为小写，路径中的大小写不影响匹配
+        let tag = tag.trim().to_lowercase();
+        let spec = ArticleSpec::complex().published().has_tag(&tag).build();
+        let articles = controller
+            .articles(
+                spec,
+                QueryPagination::new(request.page, controller.config.page_size),
+            )
+            .await?;
+        if articles.items.is_empty() && request.page > 1 {
+            return controller.not_found();
+        }
+
+        let pagination = PaginationView::from(&articles);
+        controller.page(
+            "tag.html",
+            context! {
+                site => &controller.site,
+                tag,
+                articles => controller.views(articles.items),
+                pagination,
+            },
+        )
+    }
+
+    /// GET /archive - 归档页：按月分组的全部已发布文章
+    pub async fn archive(
+        State(controller): State<Arc<ThemeController>>,
+        Query(request): Query<PageHttpRequest>,
+    ) -> Result<Response, RhyonError> {
+        let articles = controller
+            .articles(
+                ArticleSpec::published(),
+                QueryPagination::new(request.page, controller.config.archive_page_size),
+            )
+            .await?;
+        if articles.items.is_empty() && request.page > 1 {
+            return controller.not_found();
+        }
+
+        let pagination = PaginationView::from(&articles);
+        controller.page(
+            "archive.html",
+            context! {
+                site => &controller.site,
+                groups => ArchiveGroupView::group(articles.items, &controller.site),
+                pagination,
+            },
+        )
+    }
+
+    /// 按发布时间倒序读取一页文章
+    async fn articles(
+        &self,
+        specification: ArticleSpec,
+        pagination: QueryPagination,
+    ) -> Result<QueryPage<ArticleQueryModel>, RhyonError> {
+        let fields = ArticleFieldSet::list_default()
+            .with(ArticleField::AuthorName)
+            .with(ArticleField::Tags);
+        let query = GetArticlesQuery::new(pagination)
+            .with_specification(specification)
+            .with_sort(ArticleSortSpec::published_at_desc())
+            .with_fields(fields);
+        self.application_service.get_articles(query).await
+    }
+
+    fn views(&self, articles: Vec<ArticleQueryModel>) -> Vec<ArticleView> {
+        articles
+            .into_iter()
+            .map(|article| ArticleView::from_model(article, &self.site))
+            .collect()
+    }
+
+    fn page(&self, template: &str, context: minijinja::Value) -> Result<Response, RhyonError> {
+        Ok(self.theme.render(template, context)?.into_response())
+    }
+
+    fn not_found(&self) -> Result<Response, RhyonError> {
+        let page = self
+            .theme
+            .render("not_found.html", context! { site => &self.site })?;
+        Ok((StatusCode::NOT_FOUND, page).into_response())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::http::middleware::load_http_cache_config;
+    use crate::adapters::inbound::http::routes::theme_routes;
+    use crate::adapters::inbound::http::site::config::{load_site_config, load_theme_config};
+    use crate::adapters::outbound::persistence::database::testing::{
+        article_service, publish_article, sqlite_test_db,
+    };
+    use crate::domain::article::commands::CreateArticleCommand;
+    use axum::body::{Body, to_bytes};
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn command(title: &str, tags: &[&str]) -> CreateArticleCommand {
+        CreateArticleCommand {
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..CreateArticleCommand::new(title.to_string(), "body".to_string())
+        }
+    }
+
+    fn app(service: Arc<dyn ArticleApplicationService>) -> axum::Router {
+        let config = load_theme_config();
+        let controller = Arc::new(ThemeController::new(
+            service,
+            Theme::load(&config).unwrap(),
+            load_site_config(),
+            config,
+        ));
+        theme_routes(&load_http_cache_config()).with_state(controller)
+    }
+
+    async fn page(app: axum::Router, uri: &str) -> String {
+        let response = app
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "{uri}");
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn tag_page_lists_published_articles_with_the_tag() {
+        let db = sqlite_test_db().await;
+        let service = article_service(&db);
+        publish_article(&db, &*service, command("Rust tips", &["rust"])).await;
+        publish_article(&db, &*service, command("Cooking", &["food"])).await;
+
+        let html = page(app(service), "/tags/Rust").await;
+        assert!(html.contains("<h1>#rust</h1>"));
+        assert!(html.contains("Rust tips"));
+        assert!(!html.contains("Cooking"));
+    }
+
+    #[tokio::test]
+    async fn html_in_the_article_body_is_not_rendered() {
+        let db = sqlite_test_db().await;
+        let service = article_service(&db);
+        let command = CreateArticleCommand::new(
+            "Hello".to_string(),
+            "Hi <script>alert(1)</script>".to_string(),
+        );
+        publish_article(&db, &*service, command).await;
+
+        let html = page(app(service), "/posts/hello").await;
+        assert!(!html.contains("<script>alert(1)"), "{html}");
+        assert!(html.contains("&lt;script&gt;alert(1)"), "{html}");
+    }
+}
@@ -0,0 +1,199 @@
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::adapters::inbound::http::dto::{
+    CreateWebhookHttpRequest, UpdateWebhookHttpRequest, WebhookDeliveriesHttpRequest,
+    WebhookDeliveryHttpResponse, WebhookHttpResponse,
+};
+use crate::adapters::inbound::http::openapi::{EmptyResponse, ErrorResponse};
+use crate::application::services::WebhookApplicationService;
+use crate::core::response::R;
+use crate::shared::errors::RhyonError;
+
+pub struct WebhookController {
+    application_service: Arc<dyn WebhookApplicationService>,
+}
+
+impl WebhookController {
+    pub fn new(application_service: Arc<dyn WebhookApplicationService>) -> Self {
+        Self {
+            application_service,
+        }
+    }
+}
+
+/// 创建订阅
+///
+/// 响应中包含签名密钥，未指定时自动生成
+#[utoipa::path(
+    post,
+    path = "/admin/webhooks",
+    tag = "admin",
+    security(("admin_token" = [])),
+    request_body = CreateWebhookHttpRequest,
+    responses(
+        (status = 200, description = "新建的订阅", body = R<WebhookHttpResponse>),
+        (status = 422, description = "字段校验失败", body = ErrorResponse),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+    )
+)]
+pub async fn create_webhook(
+    State(controller): State<Arc<WebhookController>>,
+    Json(request): Json<CreateWebhookHttpRequest>,
+) -> Result<R<WebhookHttpResponse>, RhyonError> {
+    let subscription = controller
+        .application_service
+        .create_webhook(request.into())
+        .await?;
+    Ok(R::success(
+        WebhookHttpResponse::from(&subscription).with_secret(&subscription),
+    ))
+}
+
+/// 订阅列表
+#[utoipa::path(
+    get,
+    path = "/admin/webhooks",
+    tag = "admin",
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, description = "订阅列表", body = R<Vec<WebhookHttpResponse>>),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+    )
+)]
+pub async fn list_webhooks(
+    State(controller): State<Arc<WebhookController>>,
+) -> Result<R<Vec<WebhookHttpResponse>>, RhyonError> {
+    let subscriptions = controller.application_service.list_webhooks().await?;
+    Ok(R::success(
+        subscriptions
+            .iter()
+            .map(WebhookHttpResponse::from)
+            .collect(),
+    ))
+}
+
+/// 订阅详情
+#[utoipa::path(
+    get,
+    path = "/admin/webhooks/{id}",
+    tag = "admin",
+    security(("admin_token" = [])),
+    params(("id" = Uuid, Path, description = "订阅ID")),
+    responses(
+        (status = 200, description = "订阅详情", body = R<WebhookHttpResponse>),
+        (status = 404, description = "订阅不存在", body = ErrorResponse),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+    )
+)]
+pub async fn get_webhook(
+    State(controller): State<Arc<WebhookController>>,
+    Path(id): Path<Uuid>,
+) -> Result<R<WebhookHttpResponse>, RhyonError> {
+    let subscription = controller.application_service.get_webhook(id).await?;
+    Ok(R::success(WebhookHttpResponse::from(&subscription)))
+}
+
+/// 修改订阅
+///
+/// 只修改请求中出现的字段
+#[utoipa::path(
+    put,
+    path = "/admin/webhooks/{id}",
+    tag = "admin",
+    security(("admin_token" = [])),
+    params(("id" = Uuid, Path, description = "订阅ID")),
+    request_body = UpdateWebhookHttpRequest,
+    responses(
+        (status = 200, description = "修改后的订阅", body = R<WebhookHttpResponse>),
+        (status = 404, description = "订阅不存在", body = ErrorResponse),
+        (status = 422, description = "字段校验失败", body = ErrorResponse),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+    )
+)]
+pub async fn update_webhook(
+    State(controller): State<Arc<WebhookController>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateWebhookHttpRequest>,
+) -> Result<R<WebhookHttpResponse>, RhyonError> {
+    let subscription = controller
+        .application_service
+        .update_webhook(request.into_command(id))
+        .await?;
+    Ok(R::success(WebhookHttpResponse::from(&subscription)))
+}
+
+/// 删除订阅
+#[utoipa::path(
+    delete,
+    path = "/admin/webhooks/{id}",
+    tag = "admin",
+    security(("admin_token" = [])),
+    params(("id" = Uuid, Path, description = "订阅ID")),
+    responses(
+        (status = 200, description = "订阅已删除", body = EmptyResponse),
+        (status = 404, description = "订阅不存在", body = ErrorResponse),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+    )
+)]
+pub async fn delete_webhook(
+    State(controller): State<Arc<WebhookController>>,
+    Path(id): Path<Uuid>,
+) -> Result<R<()>, RhyonError> {
+    controller.application_service.delete_webhook(id).await?;
+    Ok(R::success_empty())
+}
+
+/// 最近的投递记录
+#[utoipa::path(
+    get,
+    path = "/admin/webhooks/{id}/deliveries",
+    tag = "admin",
+    security(("admin_token" = [])),
+    params(("id" = Uuid, Path, description = "订阅ID"), WebhookDeliveriesHttpRequest),
+    responses(
+        (status = 200, description = "按时间倒序的投递记录", body = R<Vec<WebhookDeliveryHttpResponse>>),
+        (status = 404, description = "订阅不存在", body = ErrorResponse),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+    )
+)]
+pub async fn list_deliveries(
+    State(controller): State<Arc<WebhookController>>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<WebhookDeliveriesHttpRequest>,
+) -> Result<R<Vec<WebhookDeliveryHttpResponse>>, RhyonError> {
+    let deliveries = controller
+        .application_service
+        .list_deliveries(id, params.limit())
+        .await?;
+    Ok(R::success(deliveries.into_iter().map(Into::into).collect()))
+}
+
+/// 手动重新投递
+#[utoipa::path(
+    post,
+    path = "/admin/webhooks/{id}/deliveries/{delivery_id}/redeliver",
+    tag = "admin",
+    security(("admin_token" = [])),
+    params(
+        ("id" = Uuid, Path, description = "订阅ID"),
+        ("delivery_id" = Uuid, Path, description = "投递记录ID")
+    ),
+    responses(
+        (status = 200, description = "新的投递记录", body = R<WebhookDeliveryHttpResponse>),
+        (status = 404, description = "订阅或投递记录不存在", body = ErrorResponse),
+        (status = 401, description = "缺少或错误的管理令牌", body = ErrorResponse),
+    )
+)]
+pub async fn redeliver(
+    State(controller): State<Arc<WebhookController>>,
+    Path((id, delivery_id)): Path<(Uuid, Uuid)>,
+) -> Result<R<WebhookDeliveryHttpResponse>, RhyonError> {
+    let delivery = controller
+        .application_service
+        .redeliver(id, delivery_id)
+        .await?;
+    Ok(R::success(delivery.into()))
+}
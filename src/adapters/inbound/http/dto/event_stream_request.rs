@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// 事件流过滤参数，如 `types=article.created,article.published`
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventStreamHttpRequest {
+    /// 逗号分隔的事件类型，未指定时接收所有事件
+    pub types: Option<String>,
+}
+
+impl EventStreamHttpRequest {
+    /// 要接收的事件类型，未指定时接收所有事件
+    pub fn event_types(&self) -> Option<Vec<String>> {
+        let types: Vec<String> = self
+            .types
+            .as_deref()?
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        (!types.is_empty()).then_some(types)
+    }
+}
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+use crate::domain::article::specifications::ArticleSpec;
+
+/// 订阅源范围，来自 `/tags/{tag}/...` 或 `/authors/{author}/...` 路径，均为空时为全站订阅源
+#[derive(Debug, Default, Deserialize)]
+pub struct FeedScopeHttpRequest {
+    pub tag: Option<String>,
+    pub author: Option<String>,
+}
+
+impl FeedScopeHttpRequest {
+    /// 已发布的文章，按标签（与存储时一样转为小写）或作者筛选
+    pub fn specification(&self) -> ArticleSpec {
+        let builder = ArticleSpec::complex().published();
+        let builder = match &self.tag {
+            Some(tag) => builder.has_tag(tag.trim().to_lowercase()),
+            None => builder,
+        };
+        let builder = match &self.author {
+            Some(author) => builder.author_eq(author),
+            None => builder,
+        };
+        builder.build()
+    }
+
+    /// 订阅源标题，如 `Rhyon · #rust`
+    pub fn title(&self, site_title: &str) -> String {
+        match (&self.tag, &self.author) {
+            (Some(tag), _) => format!("{} · #{}", site_title, tag),
+            (None, Some(author)) => format!("{} · {}", site_title, author),
+            (None, None) => site_title.to_string(),
+        }
+    }
+}
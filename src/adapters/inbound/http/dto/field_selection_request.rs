@@ -0,0 +1,74 @@
+use crate::application::models::{ArticleField, ArticleFieldSet};
+use crate::shared::errors::RhyonError;
+use crate::shared::i18n::Message;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// 当前支持嵌入的关联数据及其对应字段
+const SUPPORTED_INCLUDES: &[(&str, ArticleField)] = &[
+    ("author", ArticleField::AuthorName),
+    ("tags", ArticleField::Tags),
+];
+
+/// 稀疏字段与关联嵌入参数，如 `fields=title,slug&include=author`
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HttpFieldSelectionRequest {
+    /// 逗号分隔的字段列表，如 "title,slug,published_at"
+    pub fields: Option<String>,
+    /// 嵌入的关联数据：author、tags
+    pub include: Option<String>,
+}
+
+impl HttpFieldSelectionRequest {
+    /// 解析字段集，未指定`fields`时返回None（使用完整响应结构）
+    ///
+    /// `include`请求的关联数据会追加到字段集中
+    pub fn field_set(&self) -> Result<Option<ArticleFieldSet>, RhyonError> {
+        let includes = self.includes()?;
+
+        self.fields
+            .as_deref()
+            .map(|fields| {
+                ArticleFieldSet::parse(fields)
+                    .map(|set| includes.iter().copied().fold(set, ArticleFieldSet::with))
+            })
+            .transpose()
+    }
+
+    /// 在默认字段集上追加`include`请求的关联数据
+    pub fn resolve(
+        &self,
+        selected: Option<ArticleFieldSet>,
+        default: fn() -> ArticleFieldSet,
+    ) -> Result<ArticleFieldSet, RhyonError> {
+        match selected {
+            Some(fields) => Ok(fields),
+            None => Ok(self
+                .includes()?
+                .into_iter()
+                .fold(default(), ArticleFieldSet::with)),
+        }
+    }
+
+    fn includes(&self) -> Result<Vec<ArticleField>, RhyonError> {
+        let includes = self.include.as_deref().unwrap_or_default();
+
+        includes
+            .split(',')
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .map(|name| {
+                SUPPORTED_INCLUDES
+                    .iter()
+                    .find(|(include, _)| *include == name)
+                    .map(|(_, field)| *field)
+                    .ok_or_else(|| {
+                        RhyonError::Validation(
+                            Message::new("unsupported-include").with_arg("name", name),
+                        )
+                    })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,34 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct DatabaseHealthHttpResponse {
+    /// `up`或`down`
+    pub status: &'static str,
+    pub backend: String,
+    pub latency_ms: u64,
+    pub pool: ConnectionPoolHttpResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<DatabaseHealthError>,
+}
+
+/// 数据库探针失败原因，不包含驱动返回的错误详情（完整错误只写入日志）
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DatabaseHealthError {
+    /// 获取连接池连接超时
+    PoolTimeout,
+    /// 连接池已关闭
+    PoolClosed,
+    /// 无法建立数据库连接
+    ConnectionFailed,
+    /// 探测语句执行失败
+    QueryFailed,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ConnectionPoolHttpResponse {
+    /// 当前连接数（含空闲连接）
+    pub size: u32,
+    pub idle: usize,
+}
@@ -1,9 +1,23 @@
+pub mod event_stream_request;
+pub mod feed_request;
+pub mod field_selection_request;
+pub mod health_responses;
+pub mod page_request;
 pub mod pagination_request;
 pub mod pagination_response;
 pub mod requests;
 pub mod responses;
+pub mod webhook_requests;
+pub mod webhook_responses;
 
+pub use event_stream_request::*;
+pub use feed_request::*;
+pub use field_selection_request::*;
+pub use health_responses::*;
+pub use page_request::*;
 pub use pagination_request::*;
 pub use pagination_response::*;
 pub use requests::*;
 pub use responses::*;
+pub use webhook_requests::*;
+pub use webhook_responses::*;
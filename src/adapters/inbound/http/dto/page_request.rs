@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+/// 主题页面的页码参数，如 `?page=2`
+#[derive(Debug, Deserialize)]
+pub struct PageHttpRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
@@ -1,15 +1,31 @@
-use crate::shared::pagination::QueryPagination;
+use crate::shared::errors::RhyonError;
+use crate::shared::pagination::{Cursor, CursorPagination, QueryPagination};
 use serde::Deserialize;
+use utoipa::IntoParams;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct HttpPaginationRequest {
+    /// 页码（偏移分页），从1开始
     #[serde(default = "default_page")]
+    #[param(default = 1)]
     pub page: u64,
 
+    /// 每页条数
     #[serde(default = "default_size")]
+    #[param(default = 10)]
     pub size: u64,
 
+    /// 排序条件，格式为 字段:方向，多个字段用逗号分隔
     pub sort: Option<String>, // "published_at:desc,title:asc" - 将在应用层处理
+
+    /// 游标分页参数：携带即启用游标分页，空值表示第一页
+    pub cursor: Option<String>,
+
+    /// 游标分页时是否统计总数
+    #[serde(default)]
+    #[param(default = false)]
+    pub with_total: bool,
 }
 
 impl HttpPaginationRequest {
@@ -17,6 +33,20 @@ impl HttpPaginationRequest {
         QueryPagination::new(self.page, self.size)
     }
 
+    /// 是否使用游标分页
+    pub fn is_cursor_mode(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    pub fn into_cursor_pagination(self) -> Result<CursorPagination, RhyonError> {
+        let cursor = match self.cursor.as_deref() {
+            None | Some("") => None,
+            Some(token) => Some(Cursor::decode(token)?),
+        };
+
+        Ok(CursorPagination::new(cursor, self.size).with_total(self.with_total))
+    }
+
     pub fn sort_string(&self) -> Option<String> {
         self.sort.clone()
     }
@@ -34,6 +64,8 @@ impl Default for HttpPaginationRequest {
             page: 1,
             size: 10,
             sort: Some("published_at:desc".to_string()),
+            cursor: None,
+            with_total: false,
         }
     }
 }
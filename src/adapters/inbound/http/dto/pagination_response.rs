@@ -1,30 +1,62 @@
-use crate::shared::pagination::QueryPage;
+use crate::shared::pagination::{CursorPage, QueryPage};
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct HttpPaginationResponse<T> {
     pub data: Vec<T>,
     pub pagination: HttpPaginationMeta,
 }
 
-#[derive(Debug, Serialize)]
-pub struct HttpPaginationMeta {
+/// 分页元数据（偏移分页或游标分页）
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum HttpPaginationMeta {
+    Offset(HttpOffsetPaginationMeta),
+    Cursor(HttpCursorPaginationMeta),
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HttpOffsetPaginationMeta {
     pub current_page: u64,
     pub page_size: u64,
     pub total_items: u64,
     pub total_pages: u64,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HttpCursorPaginationMeta {
+    pub page_size: u64,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_items: Option<u64>,
+}
+
 impl<T> From<QueryPage<T>> for HttpPaginationResponse<T> {
     fn from(page: QueryPage<T>) -> Self {
         HttpPaginationResponse {
             data: page.items,
-            pagination: HttpPaginationMeta {
+            pagination: HttpPaginationMeta::Offset(HttpOffsetPaginationMeta {
                 current_page: page.current_page,
                 page_size: page.page_size,
                 total_items: page.total_items,
                 total_pages: page.total_pages,
-            },
+            }),
+        }
+    }
+}
+
+impl<T> From<CursorPage<T>> for HttpPaginationResponse<T> {
+    fn from(page: CursorPage<T>) -> Self {
+        HttpPaginationResponse {
+            data: page.items,
+            pagination: HttpPaginationMeta::Cursor(HttpCursorPaginationMeta {
+                page_size: page.page_size,
+                next_cursor: page.next_cursor,
+                prev_cursor: page.prev_cursor,
+                total_items: page.total_items,
+            }),
         }
     }
 }
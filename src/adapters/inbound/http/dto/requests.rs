@@ -1,21 +1,32 @@
 use crate::domain::article::commands::{CreateArticleCommand, PublishArticleCommand};
 use serde::Deserialize;
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, ToSchema)]
 pub struct CreateArticleHttpRequest {
+    /// 摘要，最多500个字符
     pub summary: Option<String>,
+    /// 标题，最多200个字符
     pub title: String,
+    /// 未提供时从标题生成
     pub slug: Option<String>,
+    /// Markdown正文
     pub content: String,
+    /// 作者署名
+    pub author: Option<String>,
+    /// 标签，统一转为小写并去重
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl From<CreateArticleHttpRequest> for CreateArticleCommand {
     fn from(req: CreateArticleHttpRequest) -> Self {
         CreateArticleCommand {
-            title: req.title,
             slug: req.slug,
             summary: req.summary,
-            content: req.content,
+            author: req.author,
+            tags: req.tags,
+            ..CreateArticleCommand::new(req.title, req.content)
         }
     }
 }
@@ -1,8 +1,42 @@
-use crate::application::models::ArticleQueryModel;
+use crate::application::models::{ArticleFieldSet, ArticleQueryModel};
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use serde_json::{Map, Value};
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+/// 文章响应：默认使用完整结构，指定字段集时只输出所选字段
+#[derive(Clone, Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum ArticleHttpPayload<T> {
+    Full(T),
+    /// 指定`fields`时只包含所选字段
+    #[schema(value_type = Object)]
+    Sparse(Map<String, Value>),
+}
+
+impl<T: From<ArticleQueryModel>> ArticleHttpPayload<T> {
+    pub fn from_model(model: ArticleQueryModel, fields: Option<&ArticleFieldSet>) -> Self {
+        match fields {
+            None => ArticleHttpPayload::Full(model.into()),
+            Some(fields) => {
+                let mut full = match serde_json::to_value(model) {
+                    Ok(Value::Object(map)) => map,
+                    _ => Map::new(),
+                };
+                let sparse = fields
+                    .iter()
+                    .filter_map(|field| {
+                        full.remove(field.as_str())
+                            .map(|value| (field.as_str().to_string(), value))
+                    })
+                    .collect();
+                ArticleHttpPayload::Sparse(sparse)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
 pub struct ArticleListHttpResponse {
     pub id: String,
     pub title: String,
@@ -11,6 +45,12 @@ pub struct ArticleListHttpResponse {
     pub status: String,
     pub published_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
+    pub reading_time: u32,
+    pub comment_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
 }
 
 impl From<ArticleQueryModel> for ArticleListHttpResponse {
@@ -23,11 +63,15 @@ impl From<ArticleQueryModel> for ArticleListHttpResponse {
             status: model.status,
             published_at: model.published_at,
             created_at: model.created_at,
+            reading_time: model.reading_time,
+            comment_count: model.comment_count,
+            author_name: model.author_name,
+            tags: model.tags,
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
 pub struct ArticleDetailHttpResponse {
     pub id: String,
     pub title: String,
@@ -38,6 +82,12 @@ pub struct ArticleDetailHttpResponse {
     pub published_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub reading_time: u32,
+    pub comment_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
 }
 
 impl From<ArticleQueryModel> for ArticleDetailHttpResponse {
@@ -52,6 +102,10 @@ impl From<ArticleQueryModel> for ArticleDetailHttpResponse {
             published_at: model.published_at,
             created_at: model.created_at,
             updated_at: model.updated_at,
+            reading_time: model.reading_time,
+            comment_count: model.comment_count,
+            author_name: model.author_name,
+            tags: model.tags,
         }
     }
 }
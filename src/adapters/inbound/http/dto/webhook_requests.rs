@@ -0,0 +1,64 @@
+use crate::domain::webhook::commands::{CreateWebhookCommand, UpdateWebhookCommand};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// 投递记录默认返回的条数
+const DEFAULT_DELIVERY_LIMIT: u64 = 50;
+/// 投递记录最多返回的条数
+const MAX_DELIVERY_LIMIT: u64 = 200;
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, ToSchema)]
+pub struct CreateWebhookHttpRequest {
+    pub url: String,
+    /// 订阅的事件类型，如 "article.published"
+    pub event_types: Vec<String>,
+    /// 签名密钥，未提供时自动生成
+    pub secret: Option<String>,
+}
+
+impl From<CreateWebhookHttpRequest> for CreateWebhookCommand {
+    fn from(req: CreateWebhookHttpRequest) -> Self {
+        CreateWebhookCommand {
+            url: req.url,
+            event_types: req.event_types,
+            secret: req.secret,
+        }
+    }
+}
+
+/// 只修改提供的字段
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, ToSchema)]
+pub struct UpdateWebhookHttpRequest {
+    pub url: Option<String>,
+    pub event_types: Option<Vec<String>>,
+    pub secret: Option<String>,
+    pub active: Option<bool>,
+}
+
+impl UpdateWebhookHttpRequest {
+    pub fn into_command(self, id: Uuid) -> UpdateWebhookCommand {
+        UpdateWebhookCommand {
+            id,
+            url: self.url,
+            event_types: self.event_types,
+            secret: self.secret,
+            active: self.active,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct WebhookDeliveriesHttpRequest {
+    /// 返回的条数，默认50，最多200
+    pub limit: Option<u64>,
+}
+
+impl WebhookDeliveriesHttpRequest {
+    pub fn limit(&self) -> u64 {
+        self.limit
+            .unwrap_or(DEFAULT_DELIVERY_LIMIT)
+            .clamp(1, MAX_DELIVERY_LIMIT)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::domain::webhook::delivery::WebhookDelivery;
+use crate::domain::webhook::subscription::WebhookSubscription;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct WebhookHttpResponse {
+    pub id: String,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub active: bool,
+    /// 密钥只在创建时返回一次
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookHttpResponse {
+    pub fn with_secret(mut self, subscription: &WebhookSubscription) -> Self {
+        self.secret = Some(subscription.secret().to_string());
+        self
+    }
+}
+
+impl From<&WebhookSubscription> for WebhookHttpResponse {
+    fn from(subscription: &WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id().to_string(),
+            url: subscription.url().to_string(),
+            event_types: subscription.event_types().to_vec(),
+            active: subscription.is_active(),
+            secret: None,
+            created_at: *subscription.created_at(),
+            updated_at: *subscription.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, ToSchema)]
+pub struct WebhookDeliveryHttpResponse {
+    pub id: String,
+    pub subscription_id: String,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub status: String,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    pub next_attempt_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryHttpResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id.to_string(),
+            subscription_id: delivery.subscription_id.to_string(),
+            event_type: delivery.event_type,
+            aggregate_id: delivery.aggregate_id,
+            status: delivery.status.as_str().to_string(),
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            payload: delivery.payload,
+            next_attempt_at: delivery.next_attempt_at,
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
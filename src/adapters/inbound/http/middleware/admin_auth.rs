@@ -0,0 +1,96 @@
+use axum::extract::{Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::middleware::Next;
+use axum::response::Response;
+use serde::Deserialize;
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+
+use crate::load_config;
+use crate::shared::errors::RhyonError;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdminConfig {
+    /// 管理接口的访问令牌，为空时拒绝所有管理请求
+    pub token: String,
+}
+
+pub fn load_admin_config() -> AdminConfig {
+    load_config!(admin, AdminConfig)
+}
+
+/// 校验 `Authorization: Bearer <token>` 请求头
+pub async fn require_admin_token(
+    State(config): State<Arc<AdminConfig>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, RhyonError> {
+    let token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match token {
+        Some(token)
+            if !config.token.is_empty()
+                && bool::from(token.as_bytes().ct_eq(config.token.as_bytes())) =>
+        {
+            Ok(next.run(request).await)
+        }
+        _ => Err(RhyonError::Unauthorized),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::get;
+    use tower::ServiceExt;
+
+    async fn status(token: &str, header: Option<&str>) -> StatusCode {
+        let config = Arc::new(AdminConfig {
+            token: token.to_string(),
+        });
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(from_fn_with_state(config, require_admin_token));
+
+        let mut request = Request::builder().uri("/");
+        if let Some(header) = header {
+            request = request.header(AUTHORIZATION, header);
+        }
+        let response = app
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        response.status()
+    }
+
+    #[tokio::test]
+    async fn only_the_configured_token_is_accepted() {
+        assert_eq!(
+            status("secret", Some("Bearer secret")).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status("secret", Some("Bearer secreT")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status("secret", Some("Bearer secret2")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status("secret", Some("secret")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(status("secret", None).await, StatusCode::UNAUTHORIZED);
+        // 未配置令牌时管理接口被禁用
+        assert_eq!(status("", Some("Bearer ")).await, StatusCode::UNAUTHORIZED);
+    }
+}
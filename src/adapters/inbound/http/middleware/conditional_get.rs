@@ -0,0 +1,283 @@
+use axum::body::{Body, to_bytes};
+use axum::extract::{Request, State};
+use axum::http::header::{
+    CACHE_CONTROL, CONTENT_LENGTH, CONTENT_TYPE, ETAG, HeaderName, HeaderValue, IF_MODIFIED_SINCE,
+    IF_NONE_MATCH, LAST_MODIFIED,
+};
+use axum::http::{HeaderMap, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+use crate::load_config;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HttpCacheConfig {
+    /// 文章列表的Cache-Control，为空时不设置
+    pub article_list: String,
+    /// 文章详情的Cache-Control，为空时不设置
+    pub article_detail: String,
+    /// 订阅源的Cache-Control，为空时不设置
+    pub feed: String,
+    /// 主题HTML页面的Cache-Control，为空时不设置
+    pub page: String,
+    /// 站点地图与robots.txt的Cache-Control，为空时不设置
+    pub site: String,
+}
+
+pub fn load_http_cache_config() -> HttpCacheConfig {
+    load_config!(http_cache, HttpCacheConfig)
+}
+
+/// 单个路由的缓存策略
+#[derive(Debug, Clone)]
+pub struct CachePolicy {
+    cache_control: Option<HeaderValue>,
+}
+
+impl CachePolicy {
+    pub fn new(cache_control: &str) -> Self {
+        let cache_control = match HeaderValue::from_str(cache_control.trim()) {
+            Ok(value) if !value.is_empty() => Some(value),
+            Ok(_) => None,
+            Err(_) => {
+                tracing::warn!("⚠️ 无效的Cache-Control配置，已忽略: {}", cache_control);
+                None
+            }
+        };
+        Self { cache_control }
+    }
+}
+
+/// 设置`Last-Modified`响应头，供条件请求中间件比较`If-Modified-Since`
+pub fn last_modified(time: DateTime<Utc>) -> [(HeaderName, String); 1] {
+    [(
+        LAST_MODIFIED,
+        time.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+    )]
+}
+
+/// 条件请求：为成功的GET响应计算基于内容哈希的强ETag并设置Cache-Control，
+/// 客户端缓存仍然有效（`If-None-Match`或`If-Modified-Since`）时返回304
+pub async fn conditional_get(
+    State(policy): State<Arc<CachePolicy>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !matches!(*request.method(), Method::GET | Method::HEAD) {
+        return next.run(request).await;
+    }
+
+    let request_headers = request.headers().clone();
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = to_bytes(body, usize::MAX).await else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+
+    let etag = format!("\"{}\"", hex::encode(&Sha256::digest(&bytes)[..16]));
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        parts.headers.insert(ETAG, value);
+    }
+    if let Some(cache_control) = &policy.cache_control {
+        parts
+            .headers
+            .entry(CACHE_CONTROL)
+            .or_insert(cache_control.clone());
+    }
+
+    if is_not_modified(&request_headers, &parts.headers, &etag) {
+        parts.status = StatusCode::NOT_MODIFIED;
+        parts.headers.remove(CONTENT_LENGTH);
+        parts.headers.remove(CONTENT_TYPE);
+        return Response::from_parts(parts, Body::empty());
+    }
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// 按RFC 9110：存在`If-None-Match`时只比较ETag，否则比较`If-Modified-Since`
+fn is_not_modified(request: &HeaderMap, response: &HeaderMap, etag: &str) -> bool {
+    if let Some(if_none_match) = request.get(IF_NONE_MATCH) {
+        return if_none_match.to_str().is_ok_and(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                // If-None-Match使用弱比较，忽略W/前缀
+                .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+        });
+    }
+
+    let since = request.get(IF_MODIFIED_SINCE).and_then(http_date);
+    let modified = response.get(LAST_MODIFIED).and_then(http_date);
+    matches!((since, modified), (Some(since), Some(modified)) if modified <= since)
+}
+
+fn http_date(value: &HeaderValue) -> Option<DateTime<Utc>> {
+    let value = value.to_str().ok()?;
+    DateTime::parse_from_rfc2822(value)
+        .ok()
+        .map(|time| time.to_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::http::Request;
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::get;
+    use chrono::TimeZone;
+    use tower::ServiceExt;
+
+    const MODIFIED: &str = "Wed, 01 Jan 2025 00:00:00 GMT";
+
+    fn app(cache_control: &str) -> Router {
+        let modified = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        Router::new()
+            .route(
+                "/",
+                get(move || async move { (last_modified(modified), "hello") }),
+            )
+            .route(
+                "/private",
+                get(|| async { ([(CACHE_CONTROL, "private, no-store")], "secret") }),
+            )
+            .route("/missing", get(|| async { StatusCode::NOT_FOUND }))
+            .layer(from_fn_with_state(
+                Arc::new(CachePolicy::new(cache_control)),
+                conditional_get,
+            ))
+    }
+
+    async fn send(
+        app: &Router,
+        method: Method,
+        uri: &str,
+        headers: &[(HeaderName, &str)],
+    ) -> Response {
+        let mut request = Request::builder().method(method).uri(uri);
+        for (name, value) in headers {
+            request = request.header(name, *value);
+        }
+        app.clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn body(response: Response) -> String {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    fn header(response: &Response, name: HeaderName) -> Option<&str> {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    }
+
+    #[tokio::test]
+    async fn matching_if_none_match_returns_not_modified() {
+        let app = app("public, max-age=60");
+        let response = send(&app, Method::GET, "/", &[]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let etag = header(&response, ETAG).unwrap().to_string();
+
+        for tag in [
+            etag.clone(),
+            format!("W/{etag}"),
+            format!("\"other\", {etag}"),
+            "*".into(),
+        ] {
+            let response = send(&app, Method::GET, "/", &[(IF_NONE_MATCH, &tag)]).await;
+            assert_eq!(response.status(), StatusCode::NOT_MODIFIED, "{tag}");
+            assert_eq!(header(&response, ETAG), Some(etag.as_str()));
+            assert_eq!(header(&response, CONTENT_TYPE), None);
+            assert!(body(response).await.is_empty());
+        }
+
+        let response = send(&app, Method::GET, "/", &[(IF_NONE_MATCH, "\"other\"")]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body(response).await, "hello");
+    }
+
+    #[tokio::test]
+    async fn if_modified_since_returns_not_modified_unless_changed_later() {
+        let app = app("public, max-age=60");
+
+        for since in [MODIFIED, "Thu, 02 Jan 2025 00:00:00 GMT"] {
+            let response = send(&app, Method::GET, "/", &[(IF_MODIFIED_SINCE, since)]).await;
+            assert_eq!(response.status(), StatusCode::NOT_MODIFIED, "{since}");
+            assert_eq!(header(&response, LAST_MODIFIED), Some(MODIFIED));
+        }
+
+        let earlier = "Tue, 31 Dec 2024 00:00:00 GMT";
+        let response = send(&app, Method::GET, "/", &[(IF_MODIFIED_SINCE, earlier)]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // 同时携带时只比较ETag
+        let response = send(
+            &app,
+            Method::GET,
+            "/",
+            &[(IF_NONE_MATCH, "\"other\""), (IF_MODIFIED_SINCE, MODIFIED)],
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn head_requests_are_validated_like_get() {
+        let app = app("public, max-age=60");
+        let get_etag = header(&send(&app, Method::GET, "/", &[]).await, ETAG)
+            .unwrap()
+            .to_string();
+
+        let response = send(&app, Method::HEAD, "/", &[]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(header(&response, ETAG), Some(get_etag.as_str()));
+        assert_eq!(header(&response, CACHE_CONTROL), Some("public, max-age=60"));
+
+        let response = send(&app, Method::HEAD, "/", &[(IF_NONE_MATCH, &get_etag)]).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[tokio::test]
+    async fn cache_control_follows_the_route_policy() {
+        let app = app("public, max-age=60");
+        let response = send(&app, Method::GET, "/", &[]).await;
+        assert_eq!(header(&response, CACHE_CONTROL), Some("public, max-age=60"));
+
+        // 304响应同样带有缓存策略，客户端据此刷新缓存有效期
+        let response = send(&app, Method::GET, "/", &[(IF_MODIFIED_SINCE, MODIFIED)]).await;
+        assert_eq!(header(&response, CACHE_CONTROL), Some("public, max-age=60"));
+
+        // 处理器自行设置的Cache-Control优先
+        let response = send(&app, Method::GET, "/private", &[]).await;
+        assert_eq!(header(&response, CACHE_CONTROL), Some("private, no-store"));
+
+        // 非200响应与非GET请求不加缓存头
+        let response = send(&app, Method::GET, "/missing", &[]).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(header(&response, CACHE_CONTROL), None);
+        assert_eq!(header(&response, ETAG), None);
+
+        let response = send(&app, Method::POST, "/", &[]).await;
+        assert_eq!(header(&response, CACHE_CONTROL), None);
+
+        // 策略为空时不设置，但仍然带有ETag
+        let app = self::app("");
+        let response = send(&app, Method::GET, "/", &[]).await;
+        assert_eq!(header(&response, CACHE_CONTROL), None);
+        assert!(header(&response, ETAG).is_some());
+    }
+}
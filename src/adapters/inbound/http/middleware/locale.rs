@@ -0,0 +1,69 @@
+use axum::extract::Request;
+use axum::http::header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE, HeaderValue, VARY};
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::shared::i18n::Locale;
+
+/// 按`Accept-Language`选择接口消息的语言，并在响应中标明所用语言
+pub async fn negotiate_locale(request: Request, next: Next) -> Response {
+    let locale = request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(Locale::negotiate)
+        .unwrap_or_default();
+
+    let mut response = locale.scope(next.run(request)).await;
+
+    let headers = response.headers_mut();
+    headers.insert(CONTENT_LANGUAGE, HeaderValue::from_static(locale.tag()));
+    // 响应内容随请求语言变化，共享缓存需要按语言区分
+    headers.append(VARY, HeaderValue::from_static("accept-language"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::RhyonError;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::middleware::from_fn;
+    use axum::routing::get;
+    use tower::ServiceExt;
+
+    async fn not_found(accept_language: Option<&str>) -> (String, serde_json::Value) {
+        let app = Router::new()
+            .route("/", get(|| async { Err::<(), _>(RhyonError::NotFound) }))
+            .layer(from_fn(negotiate_locale));
+
+        let mut request = Request::get("/");
+        if let Some(accept_language) = accept_language {
+            request = request.header(ACCEPT_LANGUAGE, accept_language);
+        }
+        let response = app
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let language = response.headers()[CONTENT_LANGUAGE]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (language, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn error_messages_follow_accept_language() {
+        let (language, english) = not_found(Some("en-GB,en;q=0.9")).await;
+        assert_eq!(language, "en-US");
+
+        let (language, chinese) = not_found(None).await;
+        assert_eq!(language, "zh-CN");
+
+        assert_eq!(english["code"], chinese["code"]);
+        assert_ne!(english["message"], chinese["message"]);
+        assert!(english["message"].as_str().unwrap().is_ascii());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod admin_auth;
+pub mod conditional_get;
+pub mod locale;
+pub mod read_your_writes;
+
+pub use admin_auth::*;
+pub use conditional_get::*;
+pub use locale::*;
+pub use read_your_writes::*;
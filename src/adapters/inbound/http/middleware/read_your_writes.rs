@@ -0,0 +1,220 @@
+use axum::extract::{Request, State};
+use axum::http::HeaderMap;
+use axum::http::header::{CACHE_CONTROL, COOKIE, HeaderValue, SET_COOKIE};
+use axum::middleware::Next;
+use axum::response::Response;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::shared::consistency::{ProjectionProgress, ReadConsistency};
+
+/// 记录客户端读己之写窗口的Cookie，值为`截止时间（毫秒时间戳）:写入后的事件存储位置`
+const READ_YOUR_WRITES_COOKIE: &str = "rhyon_ryw";
+
+/// 等待投影追上写入位置时的轮询间隔
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+pub struct ReadYourWritesConfig {
+    /// 客户端写入后读取主库的时间窗口
+    pub window_ms: u64,
+    /// 每次读取等待投影追上写入位置的最长时间，超时后照常读取
+    pub wait_ms: u64,
+    /// 读模型的投影进度
+    pub progress: Arc<dyn ProjectionProgress>,
+}
+
+/// 读己之写：客户端写入成功后下发Cookie记录写入位置，窗口内该客户端的读取先等待
+/// 读模型投影越过该位置，再从主库读取
+///
+/// 窗口内的响应带有`Cache-Control: no-store`，避免共享缓存把只对该客户端成立的结果返回给他人
+pub async fn read_your_writes(
+    State(config): State<Arc<ReadYourWritesConfig>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let now = Utc::now().timestamp_millis();
+    let is_write = !request.method().is_safe();
+
+    let consistency = match recent_write(request.headers()) {
+        Some((deadline, position)) if deadline > now => {
+            if !is_write {
+                wait_for_projection(&config, position).await;
+            }
+            ReadConsistency::ReadYourWrites
+        }
+        _ => ReadConsistency::Eventual,
+    };
+    let mut response = consistency.scope(next.run(request)).await;
+    let mut in_window = consistency == ReadConsistency::ReadYourWrites;
+
+    if is_write && response.status().is_success() {
+        match config.progress.head_position().await {
+            Ok(position) => {
+                let cookie = format!(
+                    "{}={}:{}; Max-Age={}; Path=/; HttpOnly; SameSite=Lax",
+                    READ_YOUR_WRITES_COOKIE,
+                    now + config.window_ms as i64,
+                    position,
+                    config.window_ms.div_ceil(1000)
+                );
+                if let Ok(value) = HeaderValue::from_str(&cookie) {
+                    response.headers_mut().append(SET_COOKIE, value);
+                    in_window = true;
+                }
+            }
+            Err(e) => tracing::warn!("⚠️ 读取事件存储位置失败，本次写入不启用读己之写: {}", e),
+        }
+    }
+
+    if in_window {
+        response
+            .headers_mut()
+            .insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    }
+
+    response
+}
+
+/// 等待读模型投影处理到指定位置，超时或出错时放弃等待
+async fn wait_for_projection(config: &ReadYourWritesConfig, position: u64) {
+    let deadline = Instant::now() + Duration::from_millis(config.wait_ms);
+    loop {
+        match config.progress.projected_position().await {
+            Ok(projected) if projected >= position => return,
+            Ok(_) if Instant::now() >= deadline => {
+                tracing::warn!("⚠️ 等待读模型投影到位置{}超时，可能读取到旧数据", position);
+                return;
+            }
+            Ok(_) => tokio::time::sleep(PROGRESS_POLL_INTERVAL).await,
+            Err(e) => {
+                tracing::warn!("⚠️ 读取投影进度失败: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+/// 从Cookie中读取上次写入的窗口截止时间和事件存储位置
+fn recent_write(headers: &HeaderMap) -> Option<(i64, u64)> {
+    let value = headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == READ_YOUR_WRITES_COOKIE)?
+        .1;
+    let (deadline, position) = value.split_once(':')?;
+    Some((deadline.parse().ok()?, position.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::RhyonError;
+    use async_trait::async_trait;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::get;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use tower::ServiceExt;
+
+    /// 事件存储位置固定为`head`，投影进度由测试推进
+    struct FakeProgress {
+        head: u64,
+        projected: AtomicU64,
+    }
+
+    #[async_trait]
+    impl ProjectionProgress for FakeProgress {
+        async fn head_position(&self) -> Result<u64, RhyonError> {
+            Ok(self.head)
+        }
+
+        async fn projected_position(&self) -> Result<u64, RhyonError> {
+            Ok(self.projected.load(Ordering::SeqCst))
+        }
+    }
+
+    fn app(progress: Arc<FakeProgress>) -> Router {
+        let config = Arc::new(ReadYourWritesConfig {
+            window_ms: 5_000,
+            wait_ms: 2_000,
+            progress,
+        });
+        // 读取处理器返回读取时看到的投影进度
+        let read = {
+            let progress = config.progress.clone();
+            move || async move {
+                let projected = progress.projected_position().await.unwrap();
+                format!("{:?}@{}", ReadConsistency::current(), projected)
+            }
+        };
+        Router::new()
+            .route("/", get(read).post(|| async { "ok" }))
+            .layer(from_fn_with_state(config, read_your_writes))
+    }
+
+    async fn send(app: &Router, request: Request) -> (Response, String) {
+        let response = app.clone().oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let body = to_bytes(body, usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        (Response::from_parts(parts, Body::empty()), body)
+    }
+
+    #[tokio::test]
+    async fn reads_after_a_write_wait_for_the_projection() {
+        let progress = Arc::new(FakeProgress {
+            head: 7,
+            projected: AtomicU64::new(3),
+        });
+        let app = app(progress.clone());
+
+        let (response, _) = send(&app, Request::post("/").body(Body::empty()).unwrap()).await;
+        assert_eq!(response.headers()[CACHE_CONTROL], "no-store");
+        let cookie = response.headers()[SET_COOKIE].to_str().unwrap();
+        let cookie = cookie.split(';').next().unwrap().to_string();
+        assert!(cookie.ends_with(":7"), "{cookie}");
+
+        // 投影稍后才追上写入位置
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            progress.projected.store(7, Ordering::SeqCst);
+        });
+        let request = Request::get("/")
+            .header(COOKIE, cookie)
+            .body(Body::empty())
+            .unwrap();
+        let (response, body) = send(&app, request).await;
+        assert_eq!(body, "ReadYourWrites@7");
+        assert_eq!(response.headers()[CACHE_CONTROL], "no-store");
+    }
+
+    #[tokio::test]
+    async fn reads_without_a_recent_write_are_eventual_and_cacheable() {
+        let progress = Arc::new(FakeProgress {
+            head: 7,
+            projected: AtomicU64::new(3),
+        });
+        let app = app(progress);
+
+        let expired = format!(
+            "{}={}:7",
+            READ_YOUR_WRITES_COOKIE,
+            Utc::now().timestamp_millis() - 1
+        );
+        for cookie in [None, Some(expired)] {
+            let mut request = Request::get("/");
+            if let Some(cookie) = cookie {
+                request = request.header(COOKIE, cookie);
+            }
+            let (response, body) = send(&app, request.body(Body::empty()).unwrap()).await;
+            assert_eq!(body, "Eventual@3");
+            assert!(!response.headers().contains_key(CACHE_CONTROL));
+        }
+    }
+}
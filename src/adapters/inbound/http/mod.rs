@@ -1,3 +1,7 @@
 pub mod controllers;
 pub mod dto;
+pub mod middleware;
+pub mod openapi;
 pub mod routes;
+pub mod site;
+pub mod sse;
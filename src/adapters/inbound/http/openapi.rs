@@ -0,0 +1,293 @@
+use utoipa::openapi::path::{ParameterBuilder, ParameterIn};
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::{Required, schema::Type};
+use utoipa::{Modify, OpenApi, ToSchema};
+
+use crate::adapters::inbound::http::controllers::{
+    article_controller, dead_letter_controller, event_stream_controller, feed_controller,
+    health_controller, metrics_controller, site_controller, webhook_controller,
+};
+use crate::shared::errors::{ErrorCode, FieldViolation};
+
+/// HTTP接口的OpenAPI文档，访问 /openapi.json 获取，/docs 查看
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Rhyon",
+        description = "Rhyon博客服务接口",
+        license(name = "AGPL-3.0", identifier = "AGPL-3.0-only")
+    ),
+    paths(
+        article_controller::create_article,
+        article_controller::publish_article,
+        article_controller::get_articles,
+        article_controller::get_article_by_slug,
+        feed_controller::rss,
+        feed_controller::atom,
+        feed_controller::json,
+        site_controller::sitemap,
+        site_controller::article_sitemap,
+        site_controller::tag_sitemap,
+        site_controller::robots,
+        event_stream_controller::stream_events,
+        health_controller::database,
+        metrics_controller::cache,
+        dead_letter_controller::list_dead_letters,
+        webhook_controller::create_webhook,
+        webhook_controller::list_webhooks,
+        webhook_controller::get_webhook,
+        webhook_controller::update_webhook,
+        webhook_controller::delete_webhook,
+        webhook_controller::list_deliveries,
+        webhook_controller::redeliver,
+    ),
+    components(schemas(ErrorResponse, EmptyResponse, ErrorCode, FieldViolation)),
+    modifiers(&AdminSecurity, &ScopedFeeds),
+    tags(
+        (name = "articles", description = "文章"),
+        (name = "feeds", description = "订阅源"),
+        (name = "site", description = "站点地图与robots.txt"),
+        (name = "events", description = "领域事件推送"),
+        (name = "health", description = "健康检查"),
+        (name = "metrics", description = "运行指标"),
+        (name = "admin", description = "管理接口，需要管理令牌"),
+    )
+)]
+pub struct ApiDoc;
+
+/// 错误响应：R信封中不含data，字段校验失败时附带violations
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct ErrorResponse {
+    code: u16,
+    message: String,
+    error: ErrorCode,
+    violations: Option<Vec<FieldViolation>>,
+}
+
+/// 无数据的成功响应：R信封中只有code
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct EmptyResponse {
+    code: u16,
+}
+
+/// 管理接口使用 `Authorization: Bearer <token>` 认证
+struct AdminSecurity;
+
+impl Modify for AdminSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "admin_token",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+    }
+}
+
+/// 订阅源在 `/tags/{tag}` 与 `/authors/{author}` 下另有按标签、作者筛选的版本
+struct ScopedFeeds;
+
+impl ScopedFeeds {
+    const FEEDS: [&'static str; 3] = ["/feed.xml", "/atom.xml", "/feed.json"];
+    const SCOPES: [(&'static str, &'static str); 2] =
+        [("tag", "标签（不区分大小写）"), ("author", "作者署名")];
+}
+
+impl Modify for ScopedFeeds {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        for feed in Self::FEEDS {
+            let Some(item) = openapi.paths.paths.get(feed).cloned() else {
+                continue;
+            };
+            for (name, description) in Self::SCOPES {
+                let mut item = item.clone();
+                let parameter = ParameterBuilder::new()
+                    .name(name)
+                    .parameter_in(ParameterIn::Path)
+                    .required(Required::True)
+                    .description(Some(description))
+                    .schema(Some(utoipa::openapi::schema::Object::with_type(
+                        Type::String,
+                    )))
+                    .build();
+                if let Some(operation) = item.get.as_mut() {
+                    operation.operation_id = operation
+                        .operation_id
+                        .take()
+                        .map(|id| format!("{id}_by_{name}"));
+                    operation
+                        .parameters
+                        .get_or_insert_with(Vec::new)
+                        .push(parameter);
+                }
+                openapi
+                    .paths
+                    .paths
+                    .insert(format!("/{name}s/{{{name}}}{feed}"), item);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::http::controllers::{
+        ArticleController, DeadLetterController, EventStreamController, FeedController,
+        HealthController, MetricsController, SiteController, WebhookController,
+    };
+    use crate::adapters::inbound::http::middleware::{AdminConfig, load_http_cache_config};
+    use crate::adapters::inbound::http::routes::{
+        article_routes, dead_letter_routes, event_stream_routes, feed_routes, health_routes,
+        metrics_routes, site_routes, webhook_routes,
+    };
+    use crate::adapters::inbound::http::site::config::{
+        load_feed_config, load_robots_config, load_site_config,
+    };
+    use crate::adapters::inbound::http::sse::{EventStreamHub, config::load_event_stream_config};
+    use crate::adapters::outbound::cache::{ArticleCache, LruCacheStore};
+    use crate::adapters::outbound::events::{InProcessEventBus, config::load_event_bus_config};
+    use crate::adapters::outbound::persistence::database::testing::{
+        article_service, sqlite_test_db,
+    };
+    use crate::adapters::outbound::persistence::webhook::{
+        SeaOrmWebhookDeliveryRepository, SeaOrmWebhookSubscriptionRepository,
+    };
+    use crate::application::services::WebhookApplicationServiceImpl;
+    use crate::domain::article::events::register_article_events;
+    use crate::shared::events::EventRegistry;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    /// 按main.rs的方式挂载全部有文档的路由（管理接口不含令牌校验）
+    async fn app() -> Router {
+        let db = sqlite_test_db().await;
+        let articles = article_service(&db);
+        let http_cache = load_http_cache_config();
+        let admin_config = Arc::new(AdminConfig {
+            token: "secret".to_string(),
+        });
+
+        let registry = Arc::new(register_article_events(EventRegistry::new()));
+        let webhooks = Arc::new(WebhookApplicationServiceImpl::new(
+            Arc::new(SeaOrmWebhookSubscriptionRepository::new(db.clone())),
+            Arc::new(SeaOrmWebhookDeliveryRepository::new(db.clone())),
+            registry,
+        ));
+        let dead_letters = InProcessEventBus::new(load_event_bus_config()).dead_letters();
+        let hub = Arc::new(EventStreamHub::new(load_event_stream_config()));
+        let store = Arc::new(LruCacheStore::new(10, Duration::from_secs(60)));
+        let cache = Arc::new(ArticleCache::new(store, "memory", Duration::ZERO));
+
+        let feeds = FeedController::new(articles.clone(), load_site_config(), load_feed_config());
+        let site = SiteController::new(articles.clone(), load_site_config(), load_robots_config());
+        let admin = Router::new()
+            .nest(
+                "/dead-letters",
+                dead_letter_routes().with_state(Arc::new(DeadLetterController::new(dead_letters))),
+            )
+            .nest(
+                "/webhooks",
+                webhook_routes().with_state(Arc::new(WebhookController::new(webhooks))),
+            );
+
+        Router::new()
+            .merge(feed_routes(&http_cache).with_state(Arc::new(feeds)))
+            .merge(site_routes(&http_cache).with_state(Arc::new(site)))
+            .nest(
+                "/health",
+                health_routes().with_state(Arc::new(HealthController::new(db))),
+            )
+            .nest(
+                "/metrics",
+                metrics_routes().with_state(Arc::new(MetricsController::new(cache))),
+            )
+            .nest(
+                "/articles",
+                article_routes(&http_cache, admin_config)
+                    .with_state(Arc::new(ArticleController::new(articles))),
+            )
+            .nest(
+                "/events",
+                event_stream_routes().with_state(Arc::new(EventStreamController::new(hub))),
+            )
+            .nest("/admin", admin)
+            .fallback(|| async { StatusCode::IM_A_TEAPOT })
+    }
+
+    /// 用示例值替换路径参数
+    fn concrete(path: &str) -> String {
+        let id = "0190a1b2-c3d4-7e5f-8a9b-0c1d2e3f4a5b";
+        path.replace("{slug}", "hello")
+            .replace("{id}", id)
+            .replace("{delivery_id}", id)
+            .replace("{tag}", "rust")
+            .replace("{author}", "ada")
+            .replace("{file}", "1.xml")
+    }
+
+    #[tokio::test]
+    async fn every_documented_operation_is_routed() {
+        let app = app().await;
+        let openapi = ApiDoc::openapi();
+
+        let mut operations = Vec::new();
+        for (path, item) in &openapi.paths.paths {
+            let methods = [
+                (Method::GET, &item.get),
+                (Method::POST, &item.post),
+                (Method::PUT, &item.put),
+                (Method::DELETE, &item.delete),
+                (Method::PATCH, &item.patch),
+            ];
+            for (method, operation) in methods {
+                if operation.is_some() {
+                    operations.push((method, path.clone()));
+                }
+            }
+        }
+        // 文章4个、订阅源9个、站点4个、管理8个，以及健康检查、指标、事件流各1个
+        assert_eq!(operations.len(), 28);
+
+        for (method, path) in operations {
+            let request = Request::builder()
+                .method(method.clone())
+                .uri(concrete(&path))
+                .header("content-type", "application/json")
+                .body(Body::empty())
+                .unwrap();
+            let status = app.clone().oneshot(request).await.unwrap().status();
+            assert!(
+                status != StatusCode::IM_A_TEAPOT && status != StatusCode::METHOD_NOT_ALLOWED,
+                "{method} {path} is documented but not routed ({status})"
+            );
+        }
+    }
+
+    #[test]
+    fn every_json_handler_is_documented() {
+        let dir = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/adapters/inbound/http/controllers"
+        );
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            // 主题控制器渲染HTML页面，不属于接口文档
+            if name == "mod.rs" || name == "theme_controller.rs" {
+                continue;
+            }
+
+            let source = std::fs::read_to_string(&path).unwrap();
+            let handlers = source.matches("\npub async fn ").count();
+            let documented = source.matches("\n#[utoipa::path(").count();
+            assert!(handlers > 0, "{name}");
+            assert_eq!(handlers, documented, "{name} has undocumented handlers");
+        }
+    }
+}
@@ -1,12 +1,134 @@
-use axum::Router;
-use axum::routing::{get, post};
+use axum::middleware::from_fn_with_state;
+use axum::routing::{delete, get, post, put};
+use axum::{Json, Router};
 use std::sync::Arc;
+use utoipa::OpenApi;
+use utoipa_scalar::{Scalar, Servable};
 
-use crate::adapters::inbound::http::controllers::ArticleController;
+use crate::adapters::inbound::http::controllers::{
+    ArticleController, DeadLetterController, EventStreamController, FeedController,
+    HealthController, MetricsController, SiteController, ThemeController, WebhookController,
+    article_controller, dead_letter_controller, event_stream_controller, feed_controller,
+    health_controller, metrics_controller, site_controller, webhook_controller,
+};
+use crate::adapters::inbound::http::middleware::{
+    AdminConfig, CachePolicy, HttpCacheConfig, conditional_get, require_admin_token,
+};
+use crate::adapters::inbound::http::openapi::ApiDoc;
+
+/// 文章接口，创建与发布需要管理令牌
+pub fn article_routes(
+    http_cache: &HttpCacheConfig,
+    admin: Arc<AdminConfig>,
+) -> Router<Arc<ArticleController>> {
+    let list_policy = Arc::new(CachePolicy::new(&http_cache.article_list));
+    let detail_policy = Arc::new(CachePolicy::new(&http_cache.article_detail));
+    let admin_only = || from_fn_with_state(admin.clone(), require_admin_token);
+
+    Router::new()
+        .route(
+            "/",
+            get(article_controller::get_articles)
+                .layer(from_fn_with_state(list_policy, conditional_get)),
+        )
+        .route(
+            "/",
+            post(article_controller::create_article).layer(admin_only()),
+        )
+        .route(
+            "/{slug}/publish",
+            post(article_controller::publish_article).layer(admin_only()),
+        )
+        .route(
+            "/{slug}",
+            get(article_controller::get_article_by_slug)
+                .layer(from_fn_with_state(detail_policy, conditional_get)),
+        )
+}
+
+/// 全站订阅源及按标签、作者筛选的订阅源
+pub fn feed_routes(http_cache: &HttpCacheConfig) -> Router<Arc<FeedController>> {
+    let policy = Arc::new(CachePolicy::new(&http_cache.feed));
+    let formats = || {
+        Router::new()
+            .route("/feed.xml", get(feed_controller::rss))
+            .route("/atom.xml", get(feed_controller::atom))
+            .route("/feed.json", get(feed_controller::json))
+    };
+
+    formats()
+        .nest("/tags/{tag}", formats())
+        .nest("/authors/{author}", formats())
+        .layer(from_fn_with_state(policy, conditional_get))
+}
+
+/// 站点地图与robots.txt
+pub fn site_routes(http_cache: &HttpCacheConfig) -> Router<Arc<SiteController>> {
+    let policy = Arc::new(CachePolicy::new(&http_cache.site));
+
+    Router::new()
+        .route("/sitemap.xml", get(site_controller::sitemap))
+        .route(
+            "/sitemaps/articles/{file}",
+            get(site_controller::article_sitemap),
+        )
+        .route("/sitemaps/tags.xml", get(site_controller::tag_sitemap))
+        .route("/robots.txt", get(site_controller::robots))
+        .layer(from_fn_with_state(policy, conditional_get))
+}
+
+pub fn theme_routes(http_cache: &HttpCacheConfig) -> Router<Arc<ThemeController>> {
+    let policy = Arc::new(CachePolicy::new(&http_cache.page));
+
+    Router::new()
+        .route("/", get(ThemeController::index))
+        .route("/posts/{slug}", get(ThemeController::article))
+        .route("/tags/{tag}", get(ThemeController::tag))
+        .route("/archive", get(ThemeController::archive))
+        .layer(from_fn_with_state(policy, conditional_get))
+}
+
+pub fn webhook_routes() -> Router<Arc<WebhookController>> {
+    Router::new()
+        .route("/", get(webhook_controller::list_webhooks))
+        .route("/", post(webhook_controller::create_webhook))
+        .route("/{id}", get(webhook_controller::get_webhook))
+        .route("/{id}", put(webhook_controller::update_webhook))
+        .route("/{id}", delete(webhook_controller::delete_webhook))
+        .route("/{id}/deliveries", get(webhook_controller::list_deliveries))
+        .route(
+            "/{id}/deliveries/{delivery_id}/redeliver",
+            post(webhook_controller::redeliver),
+        )
+}
+
+pub fn dead_letter_routes() -> Router<Arc<DeadLetterController>> {
+    Router::new().route("/", get(dead_letter_controller::list_dead_letters))
+}
+
+pub fn event_stream_routes() -> Router<Arc<EventStreamController>> {
+    Router::new().route("/stream", get(event_stream_controller::stream_events))
+}
+
+pub fn health_routes() -> Router<Arc<HealthController>> {
+    Router::new().route("/db", get(health_controller::database))
+}
+
+pub fn metrics_routes() -> Router<Arc<MetricsController>> {
+    Router::new().route("/cache", get(metrics_controller::cache))
+}
+
+/// OpenAPI文档（/openapi.json）及文档页面（/docs）
+pub fn docs_routes<S: Clone + Send + Sync + 'static>() -> Router<S> {
+    let openapi = ApiDoc::openapi();
 
-pub fn article_routes() -> Router<Arc<ArticleController>> {
     Router::new()
-        .route("/", get(ArticleController::get_articles))
-        .route("/", post(ArticleController::create_article))
-        .route("/{slug}", get(ArticleController::get_article_by_slug))
+        .route(
+            "/openapi.json",
+            get({
+                let openapi = openapi.clone();
+                move || std::future::ready(Json(openapi.clone()))
+            }),
+        )
+        .merge(Scalar::with_url("/docs", openapi))
 }
@@ -0,0 +1,100 @@
+use crate::load_config;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use serde::{Deserialize, Serialize};
+
+/// 路径段中保留RFC 3986的非保留字符，其余字符按UTF-8百分号编码
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SiteConfig {
+    /// 站点的公开访问地址，用于生成订阅源等对外链接（不含末尾的`/`）
+    pub url: String,
+    pub title: String,
+    pub description: String,
+    pub language: String,
+    /// 文章页面的路径，`{slug}`会被替换为文章的slug
+    pub article_path: String,
+}
+
+impl SiteConfig {
+    /// 拼接站点内路径的绝对地址
+    pub fn url_for(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    /// 文章页面的站内路径
+    pub fn article_href(&self, slug: &str) -> String {
+        self.article_path.replace("{slug}", slug)
+    }
+
+    /// 文章的规范地址
+    pub fn article_url(&self, slug: &str) -> String {
+        self.url_for(&self.article_href(slug))
+    }
+
+    /// 标签页的规范地址
+    pub fn tag_url(&self, tag: &str) -> String {
+        self.url_for(&format!("tags/{}", utf8_percent_encode(tag, PATH_SEGMENT)))
+    }
+}
+
+pub fn load_site_config() -> SiteConfig {
+    load_config!(site, SiteConfig)
+}
+
+/// 订阅源条目的正文内容
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedContent {
+    /// 渲染后的完整HTML正文
+    Full,
+    /// 只输出摘要
+    Summary,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeedConfig {
+    /// 每个订阅源包含的最新文章数（最多100）
+    pub limit: u64,
+    pub content: FeedContent,
+}
+
+pub fn load_feed_config() -> FeedConfig {
+    load_config!(feed, FeedConfig)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RobotsConfig {
+    /// 允许抓取的路径前缀
+    pub allow: Vec<String>,
+    /// 禁止抓取的路径前缀
+    pub disallow: Vec<String>,
+}
+
+pub fn load_robots_config() -> RobotsConfig {
+    load_config!(robots, RobotsConfig)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ThemeConfig {
+    /// 启用服务端渲染的HTML主题（首页、文章、标签与归档页面）
+    pub enabled: bool,
+    /// 模板覆盖目录，其中的同名模板优先于内置的默认主题，为空时只使用内置主题
+    pub dir: String,
+    /// 首页与标签页每页的文章数
+    pub page_size: u64,
+    /// 归档页每页的文章数
+    pub archive_page_size: u64,
+}
+
+pub fn load_theme_config() -> ThemeConfig {
+    load_config!(theme, ThemeConfig)
+}
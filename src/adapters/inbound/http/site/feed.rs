@@ -0,0 +1,268 @@
+use atom_syndication as atom;
+use chrono::{DateTime, Utc};
+use rss::extension::atom::AtomExtension;
+use rss::extension::dublincore::DublinCoreExtension;
+use serde::Serialize;
+
+use super::config::{FeedContent, SiteConfig};
+use super::markdown::render_markdown;
+use crate::application::models::ArticleQueryModel;
+
+pub const RSS_CONTENT_TYPE: &str = "application/rss+xml; charset=utf-8";
+pub const ATOM_CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+pub const JSON_FEED_CONTENT_TYPE: &str = "application/feed+json; charset=utf-8";
+
+/// 订阅源：频道信息及按发布时间倒序的条目，可输出为RSS 2.0、Atom 1.0和JSON Feed 1.1
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub title: String,
+    pub description: String,
+    pub language: String,
+    /// 站点首页地址
+    pub home_url: String,
+    /// 订阅源自身的地址
+    pub feed_url: String,
+    pub entries: Vec<FeedEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedEntry {
+    /// 条目的永久标识（`urn:uuid:`），文章改名不影响阅读器的已读状态
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    pub summary: String,
+    /// 渲染后的HTML正文，只输出摘要时为None
+    pub content_html: Option<String>,
+    pub published: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    pub author: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl FeedEntry {
+    pub fn from_model(article: ArticleQueryModel, site: &SiteConfig, content: FeedContent) -> Self {
+        let content_html = match content {
+            FeedContent::Full => article.content.as_deref().map(render_markdown),
+            FeedContent::Summary => None,
+        };
+
+        Self {
+            id: format!("urn:uuid:{}", article.id),
+            url: site.article_url(&article.slug),
+            title: article.title,
+            summary: article.summary,
+            content_html,
+            published: article.published_at.unwrap_or(article.created_at),
+            updated: article.updated_at,
+            author: article.author_name,
+            tags: article.tags.unwrap_or_default(),
+        }
+    }
+}
+
+impl Feed {
+    /// 订阅源的最后更新时间，即条目中最晚的更新时间
+    pub fn updated(&self) -> Option<DateTime<Utc>> {
+        self.entries.iter().map(|entry| entry.updated).max()
+    }
+
+    pub fn to_rss(&self) -> String {
+        let items = self
+            .entries
+            .iter()
+            .map(|entry| rss::Item {
+                title: Some(entry.title.clone()),
+                link: Some(entry.url.clone()),
+                description: Some(entry.summary.clone()),
+                content: entry.content_html.clone(),
+                guid: Some(rss::Guid {
+                    value: entry.id.clone(),
+                    permalink: false,
+                }),
+                pub_date: Some(entry.published.to_rfc2822()),
+                categories: entry
+                    .tags
+                    .iter()
+                    .map(|tag| rss::Category {
+                        name: tag.clone(),
+                        domain: None,
+                    })
+                    .collect(),
+                // RSS的author要求是邮箱，作者名使用dc:creator
+                dublin_core_ext: entry.author.clone().map(|author| DublinCoreExtension {
+                    creators: vec![author],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect();
+
+        rss::Channel {
+            title: self.title.clone(),
+            link: self.home_url.clone(),
+            description: self.description.clone(),
+            language: Some(self.language.clone()),
+            last_build_date: self.updated().map(|time| time.to_rfc2822()),
+            generator: Some("Rhyon".to_string()),
+            atom_ext: Some(AtomExtension {
+                links: vec![atom::Link {
+                    href: self.feed_url.clone(),
+                    rel: "self".to_string(),
+                    mime_type: Some("application/rss+xml".to_string()),
+                    ..Default::default()
+                }],
+            }),
+            items,
+            ..Default::default()
+        }
+        .to_string()
+    }
+
+    pub fn to_atom(&self) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| atom::Entry {
+                title: atom::Text::plain(entry.title.clone()),
+                id: entry.id.clone(),
+                updated: entry.updated.fixed_offset(),
+                published: Some(entry.published.fixed_offset()),
+                authors: entry
+                    .author
+                    .iter()
+                    .map(|name| atom::Person {
+                        name: name.clone(),
+                        ..Default::default()
+                    })
+                    .collect(),
+                categories: entry
+                    .tags
+                    .iter()
+                    .map(|tag| atom::Category {
+                        term: tag.clone(),
+                        ..Default::default()
+                    })
+                    .collect(),
+                links: vec![atom::Link {
+                    href: entry.url.clone(),
+                    rel: "alternate".to_string(),
+                    mime_type: Some("text/html".to_string()),
+                    ..Default::default()
+                }],
+                summary: Some(atom::Text::plain(entry.summary.clone())),
+                content: entry.content_html.clone().map(|html| atom::Content {
+                    value: Some(html),
+                    content_type: Some("html".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect();
+
+        atom::Feed {
+            title: atom::Text::plain(self.title.clone()),
+            id: self.feed_url.clone(),
+            // 没有条目时使用固定时间，保证响应内容（及ETag）稳定
+            updated: self
+                .updated()
+                .unwrap_or(DateTime::UNIX_EPOCH)
+                .fixed_offset(),
+            subtitle: Some(atom::Text::plain(self.description.clone())),
+            generator: Some(atom::Generator {
+                value: "Rhyon".to_string(),
+                ..Default::default()
+            }),
+            links: vec![
+                atom::Link {
+                    href: self.home_url.clone(),
+                    rel: "alternate".to_string(),
+                    mime_type: Some("text/html".to_string()),
+                    ..Default::default()
+                },
+                atom::Link {
+                    href: self.feed_url.clone(),
+                    rel: "self".to_string(),
+                    mime_type: Some("application/atom+xml".to_string()),
+                    ..Default::default()
+                },
+            ],
+            lang: Some(self.language.clone()),
+            entries,
+            ..Default::default()
+        }
+        .to_string()
+    }
+
+    pub fn to_json(&self) -> String {
+        let feed = JsonFeed {
+            version: "https://jsonfeed.org/version/1.1",
+            title: &self.title,
+            home_page_url: &self.home_url,
+            feed_url: &self.feed_url,
+            description: &self.description,
+            language: &self.language,
+            items: self
+                .entries
+                .iter()
+                .map(|entry| JsonFeedItem {
+                    id: &entry.id,
+                    url: &entry.url,
+                    title: &entry.title,
+                    summary: &entry.summary,
+                    // content_html与content_text至少需要一个，只输出摘要时以摘要作为正文
+                    content_html: entry.content_html.as_deref(),
+                    content_text: entry
+                        .content_html
+                        .is_none()
+                        .then_some(entry.summary.as_str()),
+                    date_published: entry.published,
+                    date_modified: entry.updated,
+                    authors: entry
+                        .author
+                        .iter()
+                        .map(|name| JsonFeedAuthor { name })
+                        .collect(),
+                    tags: &entry.tags,
+                })
+                .collect(),
+        };
+
+        serde_json::to_string(&feed).unwrap_or_default()
+    }
+}
+
+/// JSON Feed 1.1文档
+#[derive(Serialize)]
+struct JsonFeed<'a> {
+    version: &'static str,
+    title: &'a str,
+    home_page_url: &'a str,
+    feed_url: &'a str,
+    description: &'a str,
+    language: &'a str,
+    items: Vec<JsonFeedItem<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonFeedItem<'a> {
+    id: &'a str,
+    url: &'a str,
+    title: &'a str,
+    summary: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_html: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_text: Option<&'a str>,
+    date_published: DateTime<Utc>,
+    date_modified: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<JsonFeedAuthor<'a>>,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    tags: &'a [String],
+}
+
+#[derive(Serialize)]
+struct JsonFeedAuthor<'a> {
+    name: &'a str,
+}
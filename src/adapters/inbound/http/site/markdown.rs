@@ -0,0 +1,107 @@
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, html};
+
+/// 链接与图片允许使用的URL协议，相对地址不受限制
+const ALLOWED_SCHEMES: [&str; 3] = ["http", "https", "mailto"];
+
+/// 将Markdown正文渲染为HTML（启用表格、删除线、脚注和任务列表）
+///
+/// 文章由外部提交，正文中的原始HTML按文本转义输出，
+/// 使用`javascript:`等协议的链接与图片地址替换为`#`，避免在页面和订阅源中注入脚本
+pub fn render_markdown(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_TASKLISTS;
+
+    let events = Parser::new_ext(markdown, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        event => event,
+    });
+
+    let mut output = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut output, events);
+    output
+}
+
+/// 协议不在白名单中的地址替换为`#`（浏览器会忽略协议中的空白与控制字符，比较前先去除）
+fn safe_url(url: CowStr<'_>) -> CowStr<'_> {
+    let normalized: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let scheme = normalized
+        .split(['/', '?', '#'])
+        .next()
+        .and_then(|head| head.split_once(':'))
+        .map(|(scheme, _)| scheme);
+
+    match scheme {
+        Some(scheme) if !ALLOWED_SCHEMES.contains(&scheme) => CowStr::Borrowed("#"),
+        _ => url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_html_is_escaped() {
+        let html = render_markdown(
+            "Hello <script>alert(1)</script>\n\n<img src=x onerror=alert(1)>\n\n<div>block</div>",
+        );
+
+        assert!(!html.contains("<script"), "{html}");
+        assert!(!html.contains("<img"), "{html}");
+        assert!(!html.contains("<div"), "{html}");
+        assert!(
+            html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn script_urls_are_neutralized() {
+        let html = render_markdown(
+            "[a](javascript:alert(1)) [b](JaVaScRiPt:alert(1)) ![c](data:text/html,x) <vbscript:x>",
+        );
+        assert_eq!(html.matches(r##"href="#""##).count(), 3, "{html}");
+        assert_eq!(html.matches(r##"src="#""##).count(), 1, "{html}");
+
+        let html =
+            render_markdown("[a](https://example.com/x:y) [b](/posts/hello) [c](mailto:a@b.c)");
+        assert!(html.contains(r#"href="https://example.com/x:y""#), "{html}");
+        assert!(html.contains(r#"href="/posts/hello""#), "{html}");
+        assert!(html.contains(r#"href="mailto:a@b.c""#), "{html}");
+    }
+
+    #[test]
+    fn markdown_extensions_are_rendered() {
+        let html = render_markdown("| a |\n|---|\n| b |\n\n~~old~~ - [x] done");
+        assert!(html.contains("<table>"), "{html}");
+        assert!(html.contains("<del>old</del>"), "{html}");
+    }
+}
@@ -0,0 +1,13 @@
+pub mod config;
+pub mod feed;
+pub mod markdown;
+pub mod robots;
+pub mod sitemap;
+pub mod theme;
+pub mod views;
+
+pub use feed::*;
+pub use robots::*;
+pub use sitemap::*;
+pub use theme::*;
+pub use views::*;
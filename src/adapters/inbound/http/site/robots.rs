@@ -0,0 +1,19 @@
+use super::config::RobotsConfig;
+
+pub const ROBOTS_CONTENT_TYPE: &str = "text/plain; charset=utf-8";
+
+/// 生成robots.txt，末尾附上站点地图地址
+pub fn robots_txt(config: &RobotsConfig, sitemap_url: &str) -> String {
+    let mut lines = vec!["User-agent: *".to_string()];
+    lines.extend(config.allow.iter().map(|path| format!("Allow: {}", path)));
+    lines.extend(
+        config
+            .disallow
+            .iter()
+            .map(|path| format!("Disallow: {}", path)),
+    );
+    lines.push(String::new());
+    lines.push(format!("Sitemap: {}", sitemap_url));
+    lines.push(String::new());
+    lines.join("\n")
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use quick_xml::Writer;
+use quick_xml::events::{BytesDecl, BytesText, Event};
+use std::io;
+
+pub const SITEMAP_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+/// 单个站点地图最多包含的URL数（sitemaps.org协议的上限），超过后拆分并使用站点地图索引
+pub const MAX_SITEMAP_URLS: u64 = 50_000;
+
+const SITEMAP_NAMESPACE: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+
+/// 站点地图中的一个地址（索引中则为一个子站点地图）
+#[derive(Debug, Clone)]
+pub struct SitemapUrl {
+    pub loc: String,
+    pub lastmod: Option<DateTime<Utc>>,
+}
+
+/// 生成`<urlset>`站点地图
+pub fn urlset(urls: &[SitemapUrl]) -> String {
+    render("urlset", "url", urls)
+}
+
+/// 生成`<sitemapindex>`站点地图索引
+pub fn sitemap_index(sitemaps: &[SitemapUrl]) -> String {
+    render("sitemapindex", "sitemap", sitemaps)
+}
+
+fn render(root: &str, element: &str, urls: &[SitemapUrl]) -> String {
+    let mut writer = Writer::new(Vec::new());
+    // 写入内存缓冲区不会失败
+    let _ = write_sitemap(&mut writer, root, element, urls);
+    String::from_utf8(writer.into_inner()).unwrap_or_default()
+}
+
+fn write_sitemap(
+    writer: &mut Writer<Vec<u8>>,
+    root: &str,
+    element: &str,
+    urls: &[SitemapUrl],
+) -> io::Result<()> {
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer
+        .create_element(root)
+        .with_attribute(("xmlns", SITEMAP_NAMESPACE))
+        .write_inner_content(|writer| {
+            for url in urls {
+                writer
+                    .create_element(element)
+                    .write_inner_content(|writer| {
+                        writer
+                            .create_element("loc")
+                            .write_text_content(BytesText::new(&url.loc))?;
+                        if let Some(lastmod) = url.lastmod {
+                            let lastmod = lastmod.to_rfc3339_opts(SecondsFormat::Secs, true);
+                            writer
+                                .create_element("lastmod")
+                                .write_text_content(BytesText::new(&lastmod))?;
+                        }
+                        Ok(())
+                    })?;
+            }
+            Ok(())
+        })?;
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use axum::response::Html;
+use minijinja::{Environment, Error, ErrorKind};
+use rust_embed::Embed;
+use serde::Serialize;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::config::ThemeConfig;
+use crate::shared::errors::RhyonError;
+
+#[derive(Embed)]
+#[folder = "themes/default/"]
+struct DefaultThemeAsset;
+
+/// 服务端渲染的HTML主题：模板优先从覆盖目录读取，缺少时使用内嵌的默认主题
+pub struct Theme {
+    env: Environment<'static>,
+}
+
+impl Theme {
+    /// 主题必须提供的页面模板
+    pub const PAGES: [&'static str; 5] = [
+        "index.html",
+        "article.html",
+        "tag.html",
+        "archive.html",
+        "not_found.html",
+    ];
+
+    /// 加载并编译全部页面模板，模板缺失或有语法错误时拒绝启动
+    pub fn load(config: &ThemeConfig) -> Result<Self, Error> {
+        let dir = (!config.dir.trim().is_empty()).then(|| PathBuf::from(config.dir.trim()));
+
+        let mut env = Environment::new();
+        env.set_loader(move |name| load_template(dir.as_deref(), name));
+        for page in Self::PAGES {
+            env.get_template(page)?;
+        }
+
+        Ok(Self { env })
+    }
+
+    pub fn render(&self, name: &str, context: impl Serialize) -> Result<Html<String>, RhyonError> {
+        self.env
+            .get_template(name)
+            .and_then(|template| template.render(context))
+            .map(Html)
+            .map_err(|err| RhyonError::ServerError(format!("模板{}渲染失败: {:#}", name, err)))
+    }
+}
+
+fn load_template(dir: Option<&Path>, name: &str) -> Result<Option<String>, Error> {
+    // 模板名只能指向主题目录内的文件
+    if name.split(['/', '\\']).any(|segment| segment == "..") {
+        return Ok(None);
+    }
+
+    if let Some(dir) = dir {
+        match std::fs::read_to_string(dir.join(name)) {
+            Ok(source) => return Ok(Some(source)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidOperation,
+                    format!("无法读取模板{}", name),
+                )
+                .with_source(err));
+            }
+        }
+    }
+
+    Ok(DefaultThemeAsset::get(name)
+        .map(|file| String::from_utf8_lossy(file.data.as_ref()).into_owned()))
+}
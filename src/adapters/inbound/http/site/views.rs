@@ -0,0 +1,98 @@
+use serde::Serialize;
+
+use super::config::SiteConfig;
+use super::markdown::render_markdown;
+use crate::application::models::ArticleQueryModel;
+use crate::shared::pagination::QueryPage;
+
+/// 主题模板中的文章
+#[derive(Debug, Serialize)]
+pub struct ArticleView {
+    pub title: String,
+    pub slug: String,
+    /// 文章页面的站内路径
+    pub href: String,
+    pub summary: String,
+    /// 渲染后的HTML正文，列表页为None
+    pub content_html: Option<String>,
+    /// 发布日期，如 `2026-10-19`
+    pub published_date: Option<String>,
+    /// 发布时间（RFC 3339），用于`<time datetime>`
+    pub published_at: Option<String>,
+    pub updated_at: String,
+    pub reading_time: u32,
+    pub author: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl ArticleView {
+    pub fn from_model(article: ArticleQueryModel, site: &SiteConfig) -> Self {
+        Self {
+            href: site.article_href(&article.slug),
+            content_html: article.content.as_deref().map(render_markdown),
+            published_date: article
+                .published_at
+                .map(|time| time.format("%Y-%m-%d").to_string()),
+            published_at: article.published_at.map(|time| time.to_rfc3339()),
+            updated_at: article.updated_at.to_rfc3339(),
+            title: article.title,
+            slug: article.slug,
+            summary: article.summary,
+            reading_time: article.reading_time,
+            author: article.author_name,
+            tags: article.tags.unwrap_or_default(),
+        }
+    }
+}
+
+/// 主题模板中的分页信息
+#[derive(Debug, Serialize)]
+pub struct PaginationView {
+    pub page: u64,
+    pub total_pages: u64,
+    pub prev: Option<u64>,
+    pub next: Option<u64>,
+}
+
+impl<T> From<&QueryPage<T>> for PaginationView {
+    fn from(page: &QueryPage<T>) -> Self {
+        let current = page.current_page;
+        Self {
+            page: current,
+            total_pages: page.total_pages,
+            prev: (current > 1).then(|| current - 1),
+            next: (current < page.total_pages).then(|| current + 1),
+        }
+    }
+}
+
+/// 归档页中按月分组的文章
+#[derive(Debug, Serialize)]
+pub struct ArchiveGroupView {
+    /// 分组月份，如 `2026-10`
+    pub month: String,
+    pub articles: Vec<ArticleView>,
+}
+
+impl ArchiveGroupView {
+    /// 按发布月份分组，输入需已按发布时间排序
+    pub fn group(articles: Vec<ArticleQueryModel>, site: &SiteConfig) -> Vec<Self> {
+        let mut groups: Vec<Self> = Vec::new();
+        for article in articles {
+            let month = article
+                .published_at
+                .map(|time| time.format("%Y-%m").to_string())
+                .unwrap_or_default();
+            let view = ArticleView::from_model(article, site);
+
+            match groups.last_mut() {
+                Some(group) if group.month == month => group.articles.push(view),
+                _ => groups.push(Self {
+                    month,
+                    articles: vec![view],
+                }),
+            }
+        }
+        groups
+    }
+}
@@ -0,0 +1,20 @@
+use crate::load_config;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventStreamConfig {
+    /// 为断线重连保留的最近事件数
+    pub replay_buffer_size: usize,
+    /// 每个客户端可积压的事件数，超过后断开连接由客户端重连续传
+    pub channel_capacity: usize,
+    /// 心跳间隔
+    pub keep_alive_secs: u64,
+    /// 读取事件存储的轮询间隔
+    pub poll_interval_ms: u64,
+    /// 每次从事件存储读取的事件数
+    pub batch_size: u64,
+}
+
+pub fn load_event_stream_config() -> EventStreamConfig {
+    load_config!(event_stream, EventStreamConfig)
+}
@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::task::JoinHandle;
+
+use super::{EventStreamHub, StreamedEvent};
+use crate::shared::consistency::ProjectionProgress;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::EventStore;
+use crate::shared::tasks::{BatchJob, spawn_poll_loop};
+
+/// 事件流数据源：按全局位置读取事件存储，推送给本实例的事件流中心
+///
+/// 发件箱中继只在一个实例上投递每个事件，事件流由每个实例各自跟踪事件存储，
+/// 所有实例的客户端都能收到全部事件。只读取到投影检查点为止，
+/// 检查点之前的位置不会再有事务提交，事件按位置顺序推送且不会遗漏
+pub struct EventStreamFeed {
+    hub: Arc<EventStreamHub>,
+    event_store: Arc<dyn EventStore>,
+    progress: Arc<dyn ProjectionProgress>,
+    /// 已推送到的位置
+    position: AtomicU64,
+}
+
+impl EventStreamFeed {
+    pub fn new(
+        hub: Arc<EventStreamHub>,
+        event_store: Arc<dyn EventStore>,
+        progress: Arc<dyn ProjectionProgress>,
+    ) -> Self {
+        Self {
+            hub,
+            event_store,
+            progress,
+            position: AtomicU64::new(0),
+        }
+    }
+
+    /// 在后台任务中持续推送新事件
+    pub fn spawn(self) -> JoinHandle<()> {
+        let config = self.hub.config();
+        let interval = std::time::Duration::from_millis(config.poll_interval_ms);
+        let batch_size = config.batch_size;
+        spawn_poll_loop(self, interval, batch_size)
+    }
+
+    /// 用检查点之前最近的事件填充重放缓冲区，之后从检查点开始推送
+    pub async fn backfill(&self) -> Result<(), RhyonError> {
+        let projected = self.progress.projected_position().await?;
+        let buffer_size = self.hub.config().replay_buffer_size as u64;
+        let mut position = projected.saturating_sub(buffer_size);
+
+        while position < projected {
+            let events = self
+                .event_store
+                .read_all(position, self.hub.config().batch_size)
+                .await?;
+            let Some(last) = events.last().map(|stored| stored.position) else {
+                break;
+            };
+            for stored in events.iter().filter(|stored| stored.position <= projected) {
+                self.hub
+                    .backfill(StreamedEvent::new(stored.position, &stored.envelope));
+            }
+            position = last;
+        }
+
+        self.position.store(projected, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// 推送上次位置之后已投影的一批事件，返回推送的事件数
+    pub async fn feed_batch(&self) -> Result<usize, RhyonError> {
+        let position = self.position.load(Ordering::SeqCst);
+        let projected = self.progress.projected_position().await?;
+        if projected < position {
+            // 读模型被重建，从新的检查点继续跟踪
+            self.position.store(projected, Ordering::SeqCst);
+            return Ok(0);
+        }
+        if projected == position {
+            return Ok(0);
+        }
+
+        let batch_size = self.hub.config().batch_size;
+        let events: Vec<_> = self
+            .event_store
+            .read_all(position, batch_size)
+            .await?
+            .into_iter()
+            .filter(|stored| stored.position <= projected)
+            .collect();
+        for stored in &events {
+            self.hub
+                .publish(StreamedEvent::new(stored.position, &stored.envelope));
+        }
+
+        // 一批未读到检查点时，下一批从本批最后一个事件继续
+        let next = match events.last() {
+            Some(last) if events.len() as u64 >= batch_size => last.position,
+            _ => projected,
+        };
+        self.position.store(next, Ordering::SeqCst);
+        Ok(events.len())
+    }
+}
+
+#[async_trait]
+impl BatchJob for EventStreamFeed {
+    fn name(&self) -> &'static str {
+        "事件流推送"
+    }
+
+    async fn start(&self) {
+        if let Err(e) = self.backfill().await {
+            tracing::error!("❌ 填充事件流重放缓冲区失败: {}", e);
+        }
+    }
+
+    async fn run_batch(&self) -> Result<usize, RhyonError> {
+        self.feed_batch().await
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use serde_json::json;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+
+use super::config::EventStreamConfig;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::{DomainEvent, EventEnvelope, EventHandler};
+
+/// 推送给SSE客户端的事件
+#[derive(Debug, Clone)]
+pub struct StreamedEvent {
+    /// 事件在事件存储中的全局位置，作为SSE事件ID
+    pub position: u64,
+    pub event_type: String,
+    pub data: serde_json::Value,
+}
+
+impl StreamedEvent {
+    pub fn new(position: u64, envelope: &EventEnvelope) -> Self {
+        let data = json!({
+            "event_type": envelope.event_type,
+            "aggregate_type": envelope.aggregate_type,
+            "aggregate_id": envelope.aggregate_id,
+            "occurred_on": envelope.occurred_on,
+            "version": envelope.schema_version,
+            "data": envelope.payload,
+        });
+
+        Self {
+            position,
+            event_type: envelope.event_type.clone(),
+            data,
+        }
+    }
+
+    /// SSE事件ID：全局位置在所有实例之间一致，客户端可以重连到任意实例续传
+    pub fn id(&self) -> String {
+        self.position.to_string()
+    }
+}
+
+/// 事件流中心：按全局位置顺序广播事件给SSE客户端，并保留有限的重放缓冲
+///
+/// 数据库后端由[`EventStreamFeed`](super::EventStreamFeed)从事件存储读取事件；
+/// 内存后端只有一个进程，作为事件总线的订阅者接收事件并自行编号
+pub struct EventStreamHub {
+    sender: broadcast::Sender<StreamedEvent>,
+    state: Mutex<HubState>,
+    config: EventStreamConfig,
+}
+
+struct HubState {
+    /// 已接收的最大位置
+    position: u64,
+    buffer: VecDeque<StreamedEvent>,
+}
+
+impl EventStreamHub {
+    pub fn new(config: EventStreamConfig) -> Self {
+        let (sender, _) = broadcast::channel(config.channel_capacity.max(1));
+
+        Self {
+            sender,
+            state: Mutex::new(HubState {
+                position: 0,
+                buffer: VecDeque::with_capacity(config.replay_buffer_size),
+            }),
+            config,
+        }
+    }
+
+    pub fn config(&self) -> &EventStreamConfig {
+        &self.config
+    }
+
+    /// 订阅实时事件，并返回`last_event_id`之后仍在缓冲区中的事件
+    ///
+    /// 在同一把锁内读取缓冲区和订阅通道，保证重放与实时事件之间不重不漏；
+    /// 无法识别的ID从缓冲区开头重放
+    pub fn subscribe(
+        &self,
+        last_event_id: Option<&str>,
+    ) -> (Vec<StreamedEvent>, broadcast::Receiver<StreamedEvent>) {
+        let state = self.state.lock().expect("event stream lock poisoned");
+        let receiver = self.sender.subscribe();
+
+        let replay = match last_event_id {
+            None => Vec::new(),
+            Some(id) => {
+                let after = id.trim().parse::<u64>().unwrap_or(0);
+                state
+                    .buffer
+                    .iter()
+                    .filter(|event| event.position > after)
+                    .cloned()
+                    .collect()
+            }
+        };
+
+        (replay, receiver)
+    }
+
+    /// 广播一个事件，已接收过的位置被忽略
+    pub fn publish(&self, event: StreamedEvent) {
+        let mut state = self.state.lock().expect("event stream lock poisoned");
+        if event.position <= state.position {
+            return;
+        }
+        self.push(&mut state, event);
+    }
+
+    /// 把启动前的历史事件放入重放缓冲区，不推送给已连接的客户端
+    pub fn backfill(&self, event: StreamedEvent) {
+        let mut state = self.state.lock().expect("event stream lock poisoned");
+        if event.position <= state.position {
+            return;
+        }
+        state.position = event.position;
+        self.buffer(&mut state, event);
+    }
+
+    fn push(&self, state: &mut HubState, event: StreamedEvent) {
+        state.position = event.position;
+        self.buffer(state, event.clone());
+
+        // 没有客户端连接时发送会失败，可以忽略
+        let _ = self.sender.send(event);
+    }
+
+    fn buffer(&self, state: &mut HubState, event: StreamedEvent) {
+        if self.config.replay_buffer_size == 0 {
+            return;
+        }
+        if state.buffer.len() >= self.config.replay_buffer_size {
+            state.buffer.pop_front();
+        }
+        state.buffer.push_back(event);
+    }
+}
+
+#[async_trait]
+impl EventHandler for EventStreamHub {
+    fn name(&self) -> &str {
+        "event-stream"
+    }
+
+    async fn handle(&self, event: &dyn DomainEvent) -> Result<(), RhyonError> {
+        let envelope = EventEnvelope::wrap(event, 0);
+        let mut state = self.state.lock().expect("event stream lock poisoned");
+        let position = state.position + 1;
+        self.push(&mut state, StreamedEvent::new(position, &envelope));
+        Ok(())
+    }
+}
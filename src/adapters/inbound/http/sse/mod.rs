@@ -0,0 +1,6 @@
+pub mod config;
+pub mod event_stream_feed;
+pub mod event_stream_hub;
+
+pub use event_stream_feed::*;
+pub use event_stream_hub::*;
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{CacheMetrics, CacheMetricsSnapshot, CacheStore};
+use crate::application::models::{ArticleFieldSet, ArticleQueryModel};
+use crate::shared::errors::RhyonError;
+
+/// 文章查询缓存：负责键和标签的约定，并统计命中情况
+///
+/// 缓存访问失败只记录日志和错误计数，调用方回退到读仓储
+pub struct ArticleCache {
+    store: Arc<dyn CacheStore>,
+    backend: &'static str,
+    /// 失效后拒绝写入该文章缓存的时长
+    invalidation_hold: Duration,
+    metrics: CacheMetrics,
+}
+
+impl ArticleCache {
+    pub fn new(
+        store: Arc<dyn CacheStore>,
+        backend: &'static str,
+        invalidation_hold: Duration,
+    ) -> Self {
+        Self {
+            store,
+            backend,
+            invalidation_hold,
+            metrics: CacheMetrics::default(),
+        }
+    }
+
+    pub async fn get_by_slug(
+        &self,
+        slug: &str,
+        fields: &ArticleFieldSet,
+    ) -> Option<ArticleQueryModel> {
+        let cached = match self.store.get(&slug_key(slug, fields)).await {
+            Ok(cached) => cached,
+            Err(err) => {
+                self.metrics.record_error();
+                tracing::warn!("⚠️ 读取文章缓存失败: {}", err);
+                None
+            }
+        };
+
+        match cached.and_then(|value| serde_json::from_str(&value).ok()) {
+            Some(article) => {
+                self.metrics.record_hit();
+                Some(article)
+            }
+            None => {
+                self.metrics.record_miss();
+                None
+            }
+        }
+    }
+
+    /// 按查询使用的slug写入（未选择slug字段时查询模型中的slug为空）
+    pub async fn put_by_slug(
+        &self,
+        slug: &str,
+        article: &ArticleQueryModel,
+        fields: &ArticleFieldSet,
+    ) {
+        let value = match serde_json::to_string(article) {
+            Ok(value) => value,
+            Err(err) => {
+                tracing::warn!("⚠️ 序列化文章缓存失败: {}", err);
+                return;
+            }
+        };
+
+        let tags = [article_tag(&article.id)];
+        if let Err(err) = self.store.set(&slug_key(slug, fields), value, &tags).await {
+            self.metrics.record_error();
+            tracing::warn!("⚠️ 写入文章缓存失败: {}", err);
+        }
+    }
+
+    /// 使某篇文章的全部缓存失效
+    pub async fn invalidate(&self, article_id: &str) -> Result<(), RhyonError> {
+        self.store
+            .invalidate_tag(&article_tag(article_id), self.invalidation_hold)
+            .await
+            .inspect_err(|_| self.metrics.record_error())?;
+
+        self.metrics.record_invalidation();
+        tracing::debug!("🧹 文章缓存已失效: {}", article_id);
+        Ok(())
+    }
+
+    pub fn metrics(&self) -> CacheMetricsSnapshot {
+        self.metrics.snapshot(self.backend, self.store.len())
+    }
+}
+
+/// 同一篇文章按不同字段集查询时分别缓存
+fn slug_key(slug: &str, fields: &ArticleFieldSet) -> String {
+    let mut names: Vec<&str> = fields.iter().map(|field| field.as_str()).collect();
+    names.sort_unstable();
+    format!("article:slug:{}:{}", slug, names.join(","))
+}
+
+fn article_tag(article_id: &str) -> String {
+    format!("article:{}", article_id)
+}
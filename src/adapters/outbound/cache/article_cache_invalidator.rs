@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::ArticleCache;
+use crate::domain::article::events::{
+    ArticlePublishedEvent, ArticleUnpublishedEvent, ArticleUpdatedEvent,
+};
+use crate::shared::consistency::ProjectionListener;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::TypedEventHandler;
+
+/// 文章变更后使其缓存失效
+///
+/// 数据库后端在读模型投影提交后失效（[`ProjectionListener`]），否则失效后的查询仍可能
+/// 从尚未投影的读模型读到旧数据并重新缓存；内存后端保存时同步更新读取的数据，
+/// 订阅文章发布、更新或撤回发布事件即可
+#[derive(Clone)]
+pub struct ArticleCacheInvalidator {
+    cache: Arc<ArticleCache>,
+}
+
+impl ArticleCacheInvalidator {
+    pub fn new(cache: Arc<ArticleCache>) -> Self {
+        Self { cache }
+    }
+}
+
+#[async_trait]
+impl TypedEventHandler<ArticlePublishedEvent> for ArticleCacheInvalidator {
+    fn name(&self) -> &str {
+        "article_cache_invalidator"
+    }
+
+    async fn handle(&self, event: &ArticlePublishedEvent) -> Result<(), RhyonError> {
+        self.cache.invalidate(&event.article_id.to_string()).await
+    }
+}
+
+#[async_trait]
+impl TypedEventHandler<ArticleUpdatedEvent> for ArticleCacheInvalidator {
+    fn name(&self) -> &str {
+        "article_cache_invalidator"
+    }
+
+    async fn handle(&self, event: &ArticleUpdatedEvent) -> Result<(), RhyonError> {
+        self.cache.invalidate(&event.article_id.to_string()).await
+    }
+}
+
+#[async_trait]
+impl TypedEventHandler<ArticleUnpublishedEvent> for ArticleCacheInvalidator {
+    fn name(&self) -> &str {
+        "article_cache_invalidator"
+    }
+
+    async fn handle(&self, event: &ArticleUnpublishedEvent) -> Result<(), RhyonError> {
+        self.cache.invalidate(&event.article_id.to_string()).await
+    }
+}
+
+#[async_trait]
+impl ProjectionListener for ArticleCacheInvalidator {
+    async fn projected(&self, aggregate_ids: &[String]) -> Result<(), RhyonError> {
+        for article_id in aggregate_ids {
+            self.cache.invalidate(article_id).await?;
+        }
+        Ok(())
+    }
+}
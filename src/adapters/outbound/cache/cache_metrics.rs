@@ -0,0 +1,65 @@
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use utoipa::ToSchema;
+
+/// 查询缓存的命中统计
+#[derive(Debug, Default)]
+pub struct CacheMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    invalidations: AtomicU64,
+    errors: AtomicU64,
+}
+
+/// 某一时刻的缓存统计
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CacheMetricsSnapshot {
+    pub backend: String,
+    pub hits: u64,
+    pub misses: u64,
+    /// 命中率，尚无请求时为0
+    pub hit_ratio: f64,
+    pub invalidations: u64,
+    /// 缓存存储访问失败的次数（失败时回退到读仓储）
+    pub errors: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries: Option<usize>,
+}
+
+impl CacheMetrics {
+    pub fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_invalidation(&self) {
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self, backend: &str, entries: Option<usize>) -> CacheMetricsSnapshot {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+
+        CacheMetricsSnapshot {
+            backend: backend.to_string(),
+            hits,
+            misses,
+            hit_ratio: if total == 0 {
+                0.0
+            } else {
+                hits as f64 / total as f64
+            },
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            entries,
+        }
+    }
+}
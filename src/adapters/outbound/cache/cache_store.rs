@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use std::time::Duration;
+
+use crate::shared::errors::RhyonError;
+
+/// 缓存存储：值为序列化后的文本，条目可关联标签以便按标签批量失效
+#[async_trait]
+pub trait CacheStore: Send + Sync {
+    async fn get(&self, key: &str) -> Result<Option<String>, RhyonError>;
+
+    /// 写入缓存并关联标签；任一标签处于失效保持期时不写入
+    async fn set(&self, key: &str, value: String, tags: &[String]) -> Result<(), RhyonError>;
+
+    /// 删除关联该标签的全部条目，并在`hold`内拒绝写入带该标签的新条目
+    async fn invalidate_tag(&self, tag: &str, hold: Duration) -> Result<(), RhyonError>;
+
+    /// 当前条目数，无法廉价统计时返回None
+    fn len(&self) -> Option<usize> {
+        None
+    }
+}
@@ -0,0 +1,176 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::ArticleCache;
+use crate::application::models::{ArticleFieldSet, ArticleQueryModel, TagQueryModel};
+use crate::application::ports::ArticleReadRepository;
+use crate::domain::article::specifications::{ArticleSortSpec, ArticleSpec};
+use crate::shared::consistency::ReadConsistency;
+use crate::shared::errors::RhyonError;
+use crate::shared::pagination::{CursorPage, CursorPagination, QueryPage, QueryPagination};
+
+/// 为文章读仓储增加缓存的装饰器
+///
+/// 只缓存访问最集中的按slug查询，列表查询直接转发给被装饰的仓储。
+/// 需要读己之写的请求绕过缓存，直接读取已追上其写入的读模型
+pub struct CachedArticleReadRepository {
+    inner: Arc<dyn ArticleReadRepository>,
+    cache: Arc<ArticleCache>,
+}
+
+impl CachedArticleReadRepository {
+    pub fn new(inner: Arc<dyn ArticleReadRepository>, cache: Arc<ArticleCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl ArticleReadRepository for CachedArticleReadRepository {
+    async fn find_by_specification(
+        &self,
+        specification: ArticleSpec,
+        sort: ArticleSortSpec,
+        pagination: QueryPagination,
+        fields: ArticleFieldSet,
+    ) -> Result<QueryPage<ArticleQueryModel>, RhyonError> {
+        self.inner
+            .find_by_specification(specification, sort, pagination, fields)
+            .await
+    }
+
+    async fn find_by_cursor(
+        &self,
+        specification: ArticleSpec,
+        sort: ArticleSortSpec,
+        pagination: CursorPagination,
+        fields: ArticleFieldSet,
+    ) -> Result<CursorPage<ArticleQueryModel>, RhyonError> {
+        self.inner
+            .find_by_cursor(specification, sort, pagination, fields)
+            .await
+    }
+
+    async fn count_by_specification(&self, specification: ArticleSpec) -> Result<u64, RhyonError> {
+        self.inner.count_by_specification(specification).await
+    }
+
+    async fn find_tags(
+        &self,
+        specification: ArticleSpec,
+    ) -> Result<Vec<TagQueryModel>, RhyonError> {
+        self.inner.find_tags(specification).await
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+        fields: ArticleFieldSet,
+    ) -> Result<Option<ArticleQueryModel>, RhyonError> {
+        if ReadConsistency::current() == ReadConsistency::ReadYourWrites {
+            return self.inner.find_by_slug(slug, fields).await;
+        }
+
+        if let Some(article) = self.cache.get_by_slug(slug, &fields).await {
+            return Ok(Some(article));
+        }
+
+        // 不存在的文章不缓存，新建的文章无需失效即可查到
+        let article = self.inner.find_by_slug(slug, fields.clone()).await?;
+        if let Some(article) = &article {
+            self.cache.put_by_slug(slug, article, &fields).await;
+        }
+        Ok(article)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::cache::LruCacheStore;
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// 记录按slug查询次数的读仓储
+    #[derive(Default)]
+    struct CountingRepository {
+        lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ArticleReadRepository for CountingRepository {
+        async fn find_by_specification(
+            &self,
+            _specification: ArticleSpec,
+            _sort: ArticleSortSpec,
+            _pagination: QueryPagination,
+            _fields: ArticleFieldSet,
+        ) -> Result<QueryPage<ArticleQueryModel>, RhyonError> {
+            unimplemented!()
+        }
+
+        async fn find_by_cursor(
+            &self,
+            _specification: ArticleSpec,
+            _sort: ArticleSortSpec,
+            _pagination: CursorPagination,
+            _fields: ArticleFieldSet,
+        ) -> Result<CursorPage<ArticleQueryModel>, RhyonError> {
+            unimplemented!()
+        }
+
+        async fn count_by_specification(
+            &self,
+            _specification: ArticleSpec,
+        ) -> Result<u64, RhyonError> {
+            unimplemented!()
+        }
+
+        async fn find_tags(
+            &self,
+            _specification: ArticleSpec,
+        ) -> Result<Vec<TagQueryModel>, RhyonError> {
+            unimplemented!()
+        }
+
+        async fn find_by_slug(
+            &self,
+            slug: &str,
+            _fields: ArticleFieldSet,
+        ) -> Result<Option<ArticleQueryModel>, RhyonError> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            Ok(Some(
+                ArticleQueryModel::builder()
+                    .id("1".to_string())
+                    .title("Hello".to_string())
+                    .slug(slug.to_string())
+                    .summary(String::new())
+                    .status("published".to_string())
+                    .created_at(Utc::now())
+                    .updated_at(Utc::now())
+                    .build(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn read_your_writes_lookups_bypass_the_cache() {
+        let inner = Arc::new(CountingRepository::default());
+        let store = Arc::new(LruCacheStore::new(10, Duration::from_secs(60)));
+        let cache = Arc::new(ArticleCache::new(store, "memory", Duration::ZERO));
+        let repository = CachedArticleReadRepository::new(inner.clone(), cache);
+        let find = || repository.find_by_slug("hello", ArticleFieldSet::all());
+
+        find().await.unwrap();
+        find().await.unwrap();
+        assert_eq!(inner.lookups.load(Ordering::SeqCst), 1);
+
+        ReadConsistency::ReadYourWrites
+            .scope(async {
+                find().await.unwrap();
+                find().await.unwrap();
+            })
+            .await;
+        assert_eq!(inner.lookups.load(Ordering::SeqCst), 3);
+    }
+}
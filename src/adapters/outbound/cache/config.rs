@@ -0,0 +1,39 @@
+use crate::load_config;
+use serde::Deserialize;
+
+/// 查询缓存的存储后端
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheBackend {
+    /// 进程内LRU（默认）
+    Memory,
+    /// Redis，多个实例共享缓存
+    Redis,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CacheConfig {
+    /// 关闭时查询直接访问读仓储
+    pub enabled: bool,
+    pub backend: CacheBackend,
+    /// 进程内LRU最多缓存的条目数
+    pub capacity: usize,
+    /// 缓存条目的存活时间
+    pub ttl_secs: u64,
+    /// 文章失效后在该时间内不写入其缓存，避免失效前已开始的查询把旧数据重新写入
+    pub invalidation_hold_ms: u64,
+    pub redis: RedisCacheConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RedisCacheConfig {
+    pub url: String,
+    /// 键前缀，多个应用共用同一个Redis时用于区分
+    pub prefix: String,
+    /// 单次缓存操作的超时时间，超时后回退到读仓储
+    pub timeout_ms: u64,
+}
+
+pub fn load_cache_config() -> CacheConfig {
+    load_config!(cache, CacheConfig)
+}
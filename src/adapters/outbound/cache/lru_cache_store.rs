@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use lru::LruCache;
+use std::collections::{HashMap, HashSet};
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::CacheStore;
+use crate::shared::errors::RhyonError;
+
+/// 进程内的有界LRU缓存，条目在TTL后过期
+pub struct LruCacheStore {
+    ttl: Duration,
+    state: Mutex<LruState>,
+}
+
+struct LruState {
+    entries: LruCache<String, LruEntry>,
+    /// 标签到缓存键的索引
+    tags: HashMap<String, HashSet<String>>,
+    /// 处于失效保持期的标签及其截止时间
+    holds: HashMap<String, Instant>,
+}
+
+struct LruEntry {
+    value: String,
+    tags: Vec<String>,
+    expires_at: Instant,
+}
+
+impl LruCacheStore {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            ttl,
+            state: Mutex::new(LruState {
+                entries: LruCache::new(capacity),
+                tags: HashMap::new(),
+                holds: HashMap::new(),
+            }),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, LruState> {
+        self.state.lock().expect("lru cache lock poisoned")
+    }
+}
+
+impl LruState {
+    /// 移除条目并清理其标签索引
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.pop(key) {
+            self.untag(key, &entry.tags);
+        }
+    }
+
+    fn untag(&mut self, key: &str, tags: &[String]) {
+        for tag in tags {
+            if let Some(keys) = self.tags.get_mut(tag) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    self.tags.remove(tag);
+                }
+            }
+        }
+    }
+
+    fn is_held(&mut self, tags: &[String], now: Instant) -> bool {
+        self.holds.retain(|_, until| *until > now);
+        tags.iter().any(|tag| self.holds.contains_key(tag))
+    }
+}
+
+#[async_trait]
+impl CacheStore for LruCacheStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, RhyonError> {
+        let mut state = self.state();
+
+        let expired = match state.entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => {
+                return Ok(Some(entry.value.clone()));
+            }
+            Some(_) => true,
+            None => false,
+        };
+        if expired {
+            state.remove(key);
+        }
+        Ok(None)
+    }
+
+    async fn set(&self, key: &str, value: String, tags: &[String]) -> Result<(), RhyonError> {
+        let now = Instant::now();
+        let mut state = self.state();
+        if state.is_held(tags, now) {
+            return Ok(());
+        }
+
+        state.remove(key);
+        let entry = LruEntry {
+            value,
+            tags: tags.to_vec(),
+            expires_at: now + self.ttl,
+        };
+        // 容量已满时淘汰最久未使用的条目
+        if let Some((evicted_key, evicted)) = state.entries.push(key.to_string(), entry) {
+            state.untag(&evicted_key, &evicted.tags);
+        }
+        for tag in tags {
+            state
+                .tags
+                .entry(tag.clone())
+                .or_default()
+                .insert(key.to_string());
+        }
+        Ok(())
+    }
+
+    async fn invalidate_tag(&self, tag: &str, hold: Duration) -> Result<(), RhyonError> {
+        let mut state = self.state();
+
+        let keys = state.tags.remove(tag).unwrap_or_default();
+        for key in keys {
+            state.remove(&key);
+        }
+        if !hold.is_zero() {
+            state.holds.insert(tag.to_string(), Instant::now() + hold);
+        }
+        Ok(())
+    }
+
+    fn len(&self) -> Option<usize> {
+        Some(self.state().entries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(tag: &str) -> Vec<String> {
+        vec![tag.to_string()]
+    }
+
+    #[tokio::test]
+    async fn invalidating_a_tag_removes_its_entries_and_holds_new_ones() {
+        let store = LruCacheStore::new(10, Duration::from_secs(60));
+        store.set("a:1", "one".into(), &tags("a")).await.unwrap();
+        store.set("a:2", "two".into(), &tags("a")).await.unwrap();
+        store.set("b:1", "three".into(), &tags("b")).await.unwrap();
+
+        store
+            .invalidate_tag("a", Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert_eq!(store.get("a:1").await.unwrap(), None);
+        assert_eq!(store.get("a:2").await.unwrap(), None);
+        assert_eq!(store.get("b:1").await.unwrap().as_deref(), Some("three"));
+
+        // 保持期内不写入，之后恢复缓存
+        store.set("a:1", "stale".into(), &tags("a")).await.unwrap();
+        assert_eq!(store.get("a:1").await.unwrap(), None);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        store.set("a:1", "fresh".into(), &tags("a")).await.unwrap();
+        assert_eq!(store.get("a:1").await.unwrap().as_deref(), Some("fresh"));
+    }
+}
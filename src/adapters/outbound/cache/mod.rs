@@ -0,0 +1,16 @@
+pub mod article_cache;
+pub mod article_cache_invalidator;
+pub mod cache_metrics;
+pub mod cache_store;
+pub mod cached_article_read_repository;
+pub mod config;
+pub mod lru_cache_store;
+pub mod redis_cache_store;
+
+pub use article_cache::*;
+pub use article_cache_invalidator::*;
+pub use cache_metrics::*;
+pub use cache_store::*;
+pub use cached_article_read_repository::*;
+pub use lru_cache_store::*;
+pub use redis_cache_store::*;
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use redis::{AsyncCommands, RedisError, RedisResult};
+use std::future::Future;
+use std::time::Duration;
+
+use super::CacheStore;
+use super::config::RedisCacheConfig;
+use crate::shared::errors::RhyonError;
+
+/// 基于Redis的缓存存储，多个实例共享缓存与失效
+///
+/// 标签以集合保存其关联的键，失效保持期以带过期时间的标记键表示。
+/// 每次操作都有超时，Redis不可用时请求不会被缓存拖住
+pub struct RedisCacheStore {
+    connection: ConnectionManager,
+    prefix: String,
+    ttl: Duration,
+    timeout: Duration,
+}
+
+impl RedisCacheStore {
+    pub async fn connect(config: &RedisCacheConfig, ttl: Duration) -> Result<Self, RhyonError> {
+        let timeout = Duration::from_millis(config.timeout_ms);
+        let client = redis::Client::open(config.url.as_str()).map_err(redis_error)?;
+        let manager_config = ConnectionManagerConfig::new()
+            .set_connection_timeout(timeout)
+            .set_response_timeout(timeout)
+            .set_number_of_retries(1);
+        let connection = ConnectionManager::new_with_config(client, manager_config)
+            .await
+            .map_err(redis_error)?;
+
+        Ok(Self {
+            connection,
+            prefix: config.prefix.clone(),
+            ttl,
+            timeout,
+        })
+    }
+
+    /// 为一次缓存操作（可能包含断线重连）设置总超时
+    async fn timed<T>(
+        &self,
+        operation: impl Future<Output = RedisResult<T>>,
+    ) -> Result<T, RhyonError> {
+        tokio::time::timeout(self.timeout, operation)
+            .await
+            .map_err(|_| RhyonError::ServerError("Redis缓存操作超时".to_string()))?
+            .map_err(redis_error)
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}{}", self.prefix, key)
+    }
+
+    fn tag_key(&self, tag: &str) -> String {
+        format!("{}tag:{}", self.prefix, tag)
+    }
+
+    fn hold_key(&self, tag: &str) -> String {
+        format!("{}hold:{}", self.prefix, tag)
+    }
+}
+
+#[async_trait]
+impl CacheStore for RedisCacheStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, RhyonError> {
+        let mut connection = self.connection.clone();
+        self.timed(connection.get(self.key(key))).await
+    }
+
+    async fn set(&self, key: &str, value: String, tags: &[String]) -> Result<(), RhyonError> {
+        let mut connection = self.connection.clone();
+
+        if !tags.is_empty() {
+            let hold_keys: Vec<String> = tags.iter().map(|tag| self.hold_key(tag)).collect();
+            let held: u64 = self.timed(connection.exists(hold_keys)).await?;
+            if held > 0 {
+                return Ok(());
+            }
+        }
+
+        let key = self.key(key);
+        let ttl_secs = self.ttl.as_secs().max(1);
+        let mut pipe = redis::pipe();
+        pipe.set_ex(&key, value, ttl_secs).ignore();
+        for tag in tags {
+            let tag_key = self.tag_key(tag);
+            pipe.sadd(&tag_key, &key).ignore();
+            pipe.expire(&tag_key, ttl_secs as i64).ignore();
+        }
+
+        self.timed(pipe.query_async(&mut connection)).await
+    }
+
+    async fn invalidate_tag(&self, tag: &str, hold: Duration) -> Result<(), RhyonError> {
+        let mut connection = self.connection.clone();
+        let tag_key = self.tag_key(tag);
+        let keys: Vec<String> = self.timed(connection.smembers(&tag_key)).await?;
+
+        let mut pipe = redis::pipe();
+        if !keys.is_empty() {
+            pipe.del(keys).ignore();
+        }
+        pipe.del(&tag_key).ignore();
+        if !hold.is_zero() {
+            pipe.cmd("SET")
+                .arg(self.hold_key(tag))
+                .arg(1)
+                .arg("PX")
+                .arg(hold.as_millis() as u64)
+                .ignore();
+        }
+
+        self.timed(pipe.query_async(&mut connection)).await
+    }
+}
+
+fn redis_error(err: RedisError) -> RhyonError {
+    RhyonError::ServerError(format!("Redis缓存错误: {}", err))
+}
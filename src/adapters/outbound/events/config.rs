@@ -0,0 +1,18 @@
+use crate::load_config;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventBusConfig {
+    /// 每个处理器的最大尝试次数，超过后进入死信列表
+    pub max_attempts: u32,
+    /// 重试退避的基础时长（按2的指数增长）
+    pub retry_backoff_ms: u64,
+    /// 重试退避的上限
+    pub max_backoff_ms: u64,
+    /// 死信列表最多保留的条数
+    pub dead_letter_capacity: usize,
+}
+
+pub fn load_event_bus_config() -> EventBusConfig {
+    load_config!(event_bus, EventBusConfig)
+}
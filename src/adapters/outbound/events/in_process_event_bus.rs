@@ -0,0 +1,327 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinSet;
+use utoipa::ToSchema;
+
+use super::config::EventBusConfig;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::{
+    ALL_EVENTS, DomainEvent, EventHandler, EventPublisher, Typed, TypedEventHandler,
+};
+use crate::shared::tasks::Backoff;
+
+/// 重试耗尽后仍处理失败的事件
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DeadLetter {
+    pub handler: String,
+    pub event_type: String,
+    pub aggregate_id: String,
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    pub error: String,
+    pub attempts: u32,
+    pub failed_at: DateTime<Utc>,
+}
+
+/// 死信列表：超出容量时丢弃最早的记录，可在总线移交给发布方后继续读取
+#[derive(Clone)]
+pub struct DeadLetterList {
+    entries: Arc<Mutex<VecDeque<DeadLetter>>>,
+    capacity: usize,
+}
+
+impl DeadLetterList {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(VecDeque::new())),
+            capacity,
+        }
+    }
+
+    fn push(&self, letter: DeadLetter) {
+        let mut entries = self.entries.lock().expect("dead letter lock poisoned");
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(letter);
+    }
+
+    /// 当前的死信（按失败时间排序）
+    pub fn entries(&self) -> Vec<DeadLetter> {
+        self.entries
+            .lock()
+            .expect("dead letter lock poisoned")
+            .iter()
+            .cloned()
+            .collect()
+    }
+}
+
+/// 进程内事件总线：按事件类型将事件分发给已订阅的处理器
+///
+/// 处理器并发执行并各自重试，一个处理器失败不会影响其他处理器；
+/// 全部处理器结束后才返回，任一处理器重试耗尽时返回错误，由发件箱稍后重新投递。
+/// 因此处理器需要能够容忍重复的事件
+pub struct InProcessEventBus {
+    handlers: HashMap<String, Vec<Arc<dyn EventHandler>>>,
+    config: EventBusConfig,
+    dead_letters: DeadLetterList,
+}
+
+impl InProcessEventBus {
+    pub fn new(config: EventBusConfig) -> Self {
+        Self {
+            handlers: HashMap::new(),
+            dead_letters: DeadLetterList::new(config.dead_letter_capacity),
+            config,
+        }
+    }
+
+    /// 订阅指定类型的事件
+    pub fn subscribe(mut self, event_type: &str, handler: impl EventHandler + 'static) -> Self {
+        self.handlers
+            .entry(event_type.to_string())
+            .or_default()
+            .push(Arc::new(handler));
+        self
+    }
+
+    /// 订阅所有事件
+    pub fn subscribe_all(self, handler: impl EventHandler + 'static) -> Self {
+        self.subscribe(ALL_EVENTS, handler)
+    }
+
+    /// 以强类型处理器订阅指定类型的事件
+    pub fn on<E, H>(self, event_type: &str, handler: H) -> Self
+    where
+        E: DomainEvent + 'static,
+        H: TypedEventHandler<E> + 'static,
+    {
+        self.subscribe(event_type, Typed::<E, H>::new(handler))
+    }
+
+    /// 死信列表的句柄
+    pub fn dead_letters(&self) -> DeadLetterList {
+        self.dead_letters.clone()
+    }
+
+    fn handlers_for<'a>(
+        &'a self,
+        event_type: &'a str,
+    ) -> impl Iterator<Item = &'a Arc<dyn EventHandler>> + 'a {
+        [event_type, ALL_EVENTS]
+            .into_iter()
+            .filter_map(|key| self.handlers.get(key))
+            .flatten()
+    }
+}
+
+#[async_trait]
+impl EventPublisher for InProcessEventBus {
+    async fn publish(&self, event: Box<dyn DomainEvent>) -> Result<(), RhyonError> {
+        let event: Arc<dyn DomainEvent> = Arc::from(event);
+
+        // 每个处理器在独立任务中执行，处理器panic也不会影响其他处理器
+        let mut tasks = JoinSet::new();
+        for handler in self.handlers_for(event.event_type()) {
+            let name = handler.name().to_string();
+            let task = dispatch(
+                handler.clone(),
+                event.clone(),
+                self.config.clone(),
+                self.dead_letters.clone(),
+            );
+            tasks.spawn(async move { if task.await { Ok(()) } else { Err(name) } });
+        }
+
+        let mut failed = Vec::new();
+        while let Some(result) = tasks.join_next().await {
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(name)) => failed.push(name),
+                Err(e) => failed.push(e.to_string()),
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(RhyonError::ServerError(format!(
+                "事件 {} 处理失败: {}",
+                event.event_type(),
+                failed.join(", ")
+            )))
+        }
+    }
+
+    /// 逐个发布，某个事件失败时继续发布其余事件，返回第一个错误
+    async fn publish_all(&self, events: Vec<Box<dyn DomainEvent>>) -> Result<(), RhyonError> {
+        let mut result = Ok(());
+        for event in events {
+            if let Err(e) = self.publish(event).await {
+                result = result.and(Err(e));
+            }
+        }
+        result
+    }
+}
+
+/// 调用单个处理器，失败时按指数退避重试，重试耗尽后记入死信列表并返回false
+async fn dispatch(
+    handler: Arc<dyn EventHandler>,
+    event: Arc<dyn DomainEvent>,
+    config: EventBusConfig,
+    dead_letters: DeadLetterList,
+) -> bool {
+    let max_attempts = config.max_attempts.max(1);
+    let backoff = Backoff::new(config.retry_backoff_ms, config.max_backoff_ms);
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+        let error = match handler.handle(event.as_ref()).await {
+            Ok(()) => return true,
+            Err(e) => e,
+        };
+
+        if attempts < max_attempts {
+            tracing::warn!(
+                "⚠️ 事件处理器 {} 处理 {} 失败（第{}次）: {}",
+                handler.name(),
+                event.event_type(),
+                attempts,
+                error
+            );
+            tokio::time::sleep(backoff.delay(attempts)).await;
+            continue;
+        }
+
+        tracing::error!(
+            "❌ 事件处理器 {} 处理 {} 失败，已放弃重试: {}",
+            handler.name(),
+            event.event_type(),
+            error
+        );
+
+        dead_letters.push(DeadLetter {
+            handler: handler.name().to_string(),
+            event_type: event.event_type().to_string(),
+            aggregate_id: event.aggregate_id(),
+            payload: event.payload(),
+            error: error.to_string(),
+            attempts,
+            failed_at: Utc::now(),
+        });
+        return false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::article::events::ArticlePublishedEvent;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use uuid::Uuid;
+
+    /// 前`failures`次调用失败，之后成功
+    struct Flaky {
+        name: &'static str,
+        failures: u32,
+        calls: Arc<AtomicU32>,
+    }
+
+    impl Flaky {
+        fn new(name: &'static str, failures: u32) -> (Self, Arc<AtomicU32>) {
+            let calls = Arc::new(AtomicU32::new(0));
+            let handler = Self {
+                name,
+                failures,
+                calls: calls.clone(),
+            };
+            (handler, calls)
+        }
+    }
+
+    #[async_trait]
+    impl EventHandler for Flaky {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn handle(&self, _event: &dyn DomainEvent) -> Result<(), RhyonError> {
+            // 让出执行权，确认发布方确实等待处理器完成
+            tokio::task::yield_now().await;
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if call <= self.failures {
+                return Err(RhyonError::ServerError(format!("failure {}", call)));
+            }
+            Ok(())
+        }
+    }
+
+    fn bus() -> InProcessEventBus {
+        InProcessEventBus::new(EventBusConfig {
+            max_attempts: 3,
+            retry_backoff_ms: 1,
+            max_backoff_ms: 5,
+            dead_letter_capacity: 2,
+        })
+    }
+
+    fn event() -> Box<dyn DomainEvent> {
+        Box::new(ArticlePublishedEvent::new(
+            Uuid::now_v7(),
+            "hello".to_string(),
+            Utc::now(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn publish_returns_after_handlers_recover() {
+        let (handler, calls) = Flaky::new("flaky", 2);
+        let bus = bus().subscribe(ArticlePublishedEvent::EVENT_TYPE, handler);
+
+        bus.publish(event()).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(bus.dead_letters().entries().is_empty());
+    }
+
+    #[tokio::test]
+    async fn exhausted_handler_fails_publish_without_affecting_others() {
+        let (broken, broken_calls) = Flaky::new("broken", u32::MAX);
+        let (healthy, healthy_calls) = Flaky::new("healthy", 0);
+        let bus = bus()
+            .subscribe(ArticlePublishedEvent::EVENT_TYPE, broken)
+            .subscribe_all(healthy);
+
+        let result = bus.publish(event()).await;
+
+        assert!(
+            matches!(result, Err(RhyonError::ServerError(message)) if message.contains("broken"))
+        );
+        assert_eq!(broken_calls.load(Ordering::SeqCst), 3);
+        assert_eq!(healthy_calls.load(Ordering::SeqCst), 1);
+
+        let dead_letters = bus.dead_letters().entries();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].handler, "broken");
+        assert_eq!(dead_letters[0].attempts, 3);
+        assert!(dead_letters[0].error.ends_with("failure 3"));
+    }
+
+    #[tokio::test]
+    async fn dead_letter_list_keeps_only_the_latest_entries() {
+        let (broken, _) = Flaky::new("broken", u32::MAX);
+        let bus = bus().subscribe_all(broken);
+
+        for _ in 0..3 {
+            assert!(bus.publish(event()).await.is_err());
+        }
+
+        assert_eq!(bus.dead_letters().entries().len(), 2);
+    }
+}
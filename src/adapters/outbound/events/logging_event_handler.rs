@@ -0,0 +1,23 @@
+use crate::shared::errors::RhyonError;
+use crate::shared::events::{DomainEvent, EventHandler};
+use async_trait::async_trait;
+
+/// 记录所有领域事件的处理器
+pub struct LoggingEventHandler;
+
+#[async_trait]
+impl EventHandler for LoggingEventHandler {
+    fn name(&self) -> &str {
+        "logging"
+    }
+
+    async fn handle(&self, event: &dyn DomainEvent) -> Result<(), RhyonError> {
+        tracing::info!(
+            "📢 领域事件: {} - 聚合ID: {} - 时间: {}",
+            event.event_type(),
+            event.aggregate_id(),
+            event.occurred_on()
+        );
+        Ok(())
+    }
+}
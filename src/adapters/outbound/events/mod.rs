@@ -1,3 +1,6 @@
-pub mod in_memory_event_publisher;
+pub mod config;
+pub mod in_process_event_bus;
+pub mod logging_event_handler;
 
-pub use in_memory_event_publisher::*;
+pub use in_process_event_bus::*;
+pub use logging_event_handler::*;
@@ -1,2 +1,4 @@
+pub mod cache;
 pub mod events;
 pub mod persistence;
+pub mod webhook;
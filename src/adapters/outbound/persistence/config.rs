@@ -0,0 +1,34 @@
+use crate::load_config;
+use serde::Deserialize;
+
+/// 文章仓储的存储后端
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersistenceBackend {
+    /// 关系数据库（默认）
+    Database,
+    /// 进程内存储，用于演示模式，重启后数据丢失
+    Memory,
+}
+
+/// 文章写模型的持久化方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersistenceMode {
+    /// 直接读写当前状态行（默认）
+    Crud,
+    /// 通过重放事件流重建聚合
+    EventSourced,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PersistenceConfig {
+    pub backend: PersistenceBackend,
+    pub mode: PersistenceMode,
+    /// 事件溯源模式下每累计多少个事件保存一次快照，0表示不保存
+    pub snapshot_every: u64,
+}
+
+pub fn load_persistence_config() -> PersistenceConfig {
+    load_config!(persistence, PersistenceConfig)
+}
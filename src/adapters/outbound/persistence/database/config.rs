@@ -1,13 +1,157 @@
 use crate::load_config;
 use serde::Deserialize;
 
+/// 数据库驱动
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DbDriver {
+    Postgres,
+    /// 适用于小型个人博客，`database`为数据库文件名
+    Sqlite,
+}
+
+impl DbDriver {
+    /// 按连接地址的协议判断驱动
+    pub fn of_url(url: &str) -> Self {
+        if url.starts_with("sqlite:") {
+            DbDriver::Sqlite
+        } else {
+            DbDriver::Postgres
+        }
+    }
+}
+
+/// PostgreSQL的SSL模式，对应连接参数`sslmode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DbSslMode {
+    Disable,
+    Allow,
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
+}
+
+impl DbSslMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DbSslMode::Disable => "disable",
+            DbSslMode::Allow => "allow",
+            DbSslMode::Prefer => "prefer",
+            DbSslMode::Require => "require",
+            DbSslMode::VerifyCa => "verify-ca",
+            DbSslMode::VerifyFull => "verify-full",
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct DbConfig {
-    pub host: String,
+    pub driver: DbDriver,
+    /// 完整连接地址，设置后忽略其他连接参数
+    pub url: Option<String>,
+    pub host: Option<String>,
     pub port: u16,
     pub username: String,
-    pub password: String,
+    pub password: Option<String>,
     pub database: String,
+    /// PostgreSQL的SSL模式，未设置时使用驱动默认值（prefer）
+    pub ssl_mode: Option<DbSslMode>,
+    /// PostgreSQL使用的schema（设置为连接的search_path）
+    pub schema: Option<String>,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    /// 建立单个连接的超时时间（仅PostgreSQL）
+    pub connect_timeout_ms: u64,
+    /// 从连接池获取连接的超时时间
+    pub acquire_timeout_ms: u64,
+    /// 空闲连接的回收时间，0表示不回收
+    pub idle_timeout_ms: u64,
+    /// SQL语句的日志级别：off、error、warn、info、debug或trace
+    pub log_level: log::LevelFilter,
+    /// 启动时连接数据库的最大尝试次数
+    pub connect_attempts: u32,
+    pub retry_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub replica: DbReplicaConfig,
+}
+
+/// 只读副本，供查询端读取
+#[derive(Debug, Deserialize)]
+pub struct DbReplicaConfig {
+    /// 副本的完整连接地址，未设置时查询端也使用主库
+    pub url: Option<String>,
+    /// 客户端写入后在该时间窗口内的读取先等待读模型投影追上写入，再从主库读取（读己之写），
+    /// 0表示关闭；读模型由投影异步维护，未配置副本时同样生效
+    pub read_your_writes_ms: u64,
+    /// 读己之写窗口内每次读取等待投影的最长时间
+    pub read_your_writes_wait_ms: u64,
+}
+
+impl DbConfig {
+    /// 生成连接地址
+    pub fn connection_url(&self) -> Result<String, String> {
+        if let Some(url) = &self.url {
+            return Ok(self.with_ssl_mode(url.clone()));
+        }
+
+        match self.driver {
+            DbDriver::Postgres => {
+                let host = self.host.as_deref().ok_or("缺少数据库配置: db.host")?;
+                let password = self
+                    .password
+                    .as_deref()
+                    .ok_or("缺少数据库配置: db.password")?;
+                Ok(self.with_ssl_mode(format!(
+                    "postgres://{}:{}@{}:{}/{}",
+                    self.username, password, host, self.port, self.database
+                )))
+            }
+            // 文件不存在时自动创建
+            DbDriver::Sqlite => Ok(format!("sqlite://{}.db?mode=rwc", self.database)),
+        }
+    }
+
+    /// 只读副本的连接地址
+    pub fn replica_url(&self) -> Option<String> {
+        self.replica
+            .url
+            .as_ref()
+            .map(|url| self.with_ssl_mode(url.clone()))
+    }
+
+    /// 为PostgreSQL连接地址追加`sslmode`参数（地址中已指定时保持不变）
+    fn with_ssl_mode(&self, url: String) -> String {
+        match self.ssl_mode {
+            Some(mode)
+                if DbDriver::of_url(&url) == DbDriver::Postgres && !url.contains("sslmode=") =>
+            {
+                let separator = if url.contains('?') { '&' } else { '?' };
+                format!("{}{}sslmode={}", url, separator, mode.as_str())
+            }
+            _ => url,
+        }
+    }
+
+    /// 用于日志的连接目标（不包含密码）
+    pub fn display_target(&self) -> String {
+        match (&self.url, self.driver) {
+            (Some(url), _) => redact(url),
+            (None, DbDriver::Postgres) => format!(
+                "postgres://{}:{}/{}",
+                self.host.as_deref().unwrap_or_default(),
+                self.port,
+                self.database
+            ),
+            (None, DbDriver::Sqlite) => format!("sqlite://{}.db", self.database),
+        }
+    }
+}
+
+/// 去掉连接地址中的用户名和密码
+pub fn redact(url: &str) -> String {
+    url.split('@').next_back().unwrap_or_default().to_string()
 }
 
 pub fn load_db_config() -> DbConfig {
@@ -1,24 +1,115 @@
-use super::config::load_db_config;
-use sea_orm::{Database, DatabaseConnection, DbErr};
+use super::config::{DbConfig, DbDriver, load_db_config, redact};
+use crate::shared::tasks::Backoff;
+use sea_orm::sqlx::ConnectOptions as _;
+use sea_orm::sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr, SqlxSqliteConnector};
+use std::str::FromStr;
+use std::time::Duration;
 
+/// 连接数据库，数据库尚未就绪时按指数退避重试（如docker-compose中与数据库同时启动）
 pub async fn connect() -> Result<DatabaseConnection, DbErr> {
     let config = load_db_config();
+    let connection_string = config.connection_url().map_err(DbErr::Custom)?;
 
-    let connection_string = format!(
-        "postgres://{}:{}@{}:{}/{}",
-        config.username, config.password, config.host, config.port, config.database
-    );
-
-    tracing::debug!(
-        "正在连接数据库: {}://{}:{}/{}",
-        "postgres",
-        config.host,
-        config.port,
-        config.database
-    );
-
-    let db = Database::connect(connection_string).await?;
+    tracing::debug!("正在连接数据库: {}", config.display_target());
 
+    let db = connect_with_retry(&config, &connection_string).await?;
     tracing::info!("✅ 数据库连接成功");
     Ok(db)
 }
+
+/// 连接只读副本，未配置副本时返回None
+pub async fn connect_replica() -> Result<Option<DatabaseConnection>, DbErr> {
+    let config = load_db_config();
+    let Some(connection_string) = config.replica_url() else {
+        return Ok(None);
+    };
+
+    tracing::debug!("正在连接只读副本: {}", redact(&connection_string));
+
+    let db = connect_with_retry(&config, &connection_string).await?;
+    tracing::info!("✅ 只读副本连接成功");
+    Ok(Some(db))
+}
+
+async fn connect_with_retry(config: &DbConfig, url: &str) -> Result<DatabaseConnection, DbErr> {
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+
+        let result = match DbDriver::of_url(url) {
+            DbDriver::Postgres => connect_postgres(config, url).await,
+            DbDriver::Sqlite => connect_sqlite(config, url).await,
+        };
+
+        match result {
+            Ok(db) => return Ok(db),
+            // 只有连接错误值得重试，配置错误重试也不会成功
+            Err(DbErr::Conn(err)) if attempts < config.connect_attempts => {
+                let delay =
+                    Backoff::new(config.retry_backoff_ms, config.max_backoff_ms).delay(attempts);
+                tracing::warn!(
+                    "⚠️ 数据库连接失败（第{}/{}次）: {}，{}毫秒后重试",
+                    attempts,
+                    config.connect_attempts,
+                    err,
+                    delay.as_millis()
+                );
+                tokio::time::sleep(delay).await;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+pub(super) async fn connect_postgres(
+    config: &DbConfig,
+    url: &str,
+) -> Result<DatabaseConnection, DbErr> {
+    let mut options = ConnectOptions::new(url);
+    options
+        .max_connections(config.max_connections)
+        .min_connections(config.min_connections)
+        .connect_timeout(Duration::from_millis(config.connect_timeout_ms))
+        .acquire_timeout(Duration::from_millis(config.acquire_timeout_ms))
+        .sqlx_logging_level(config.log_level);
+
+    if config.idle_timeout_ms > 0 {
+        options.idle_timeout(Duration::from_millis(config.idle_timeout_ms));
+    }
+    if let Some(schema) = &config.schema {
+        options.set_schema_search_path(schema);
+    }
+
+    Database::connect(options).await
+}
+
+/// SQLite连接需要额外的PRAGMA设置，因此直接构建sqlx连接池
+pub(super) async fn connect_sqlite(
+    config: &DbConfig,
+    url: &str,
+) -> Result<DatabaseConnection, DbErr> {
+    if config.schema.is_some() {
+        tracing::warn!("⚠️ SQLite不支持schema，已忽略db.schema配置");
+    }
+
+    let options = SqliteConnectOptions::from_str(url)
+        .map_err(|e| DbErr::Conn(sea_orm::RuntimeErr::SqlxError(e)))?
+        // WAL模式下读写互不阻塞，后台任务与请求可以并发访问
+        .journal_mode(SqliteJournalMode::Wal)
+        .foreign_keys(true)
+        .log_statements(config.log_level);
+
+    let idle_timeout =
+        (config.idle_timeout_ms > 0).then(|| Duration::from_millis(config.idle_timeout_ms));
+    let pool = SqlitePoolOptions::new()
+        .max_connections(config.max_connections)
+        .min_connections(config.min_connections)
+        .acquire_timeout(Duration::from_millis(config.acquire_timeout_ms))
+        .idle_timeout(idle_timeout)
+        .connect_with(options)
+        .await
+        .map_err(|e| DbErr::Conn(sea_orm::RuntimeErr::SqlxError(e)))?;
+
+    Ok(SqlxSqliteConnector::from_sqlx_sqlite_pool(pool))
+}
@@ -0,0 +1,23 @@
+use crate::load_config;
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MigrationConfig {
+    /// 启动服务时自动执行待执行的迁移
+    pub auto: bool,
+}
+
+pub fn load_migration_config() -> MigrationConfig {
+    load_config!(migration, MigrationConfig)
+}
+
+/// 执行全部待执行的迁移，返回执行的迁移数
+pub async fn run_pending_migrations(db: &DatabaseConnection) -> Result<usize, DbErr> {
+    let pending = Migrator::get_pending_migrations(db).await?.len();
+    if pending > 0 {
+        Migrator::up(db, None).await?;
+    }
+    Ok(pending)
+}
@@ -1,2 +1,6 @@
 pub mod config;
 pub mod connection;
+pub mod migration;
+pub mod read_connection;
+#[cfg(test)]
+pub mod testing;
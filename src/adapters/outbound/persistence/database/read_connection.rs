@@ -0,0 +1,24 @@
+use sea_orm::DatabaseConnection;
+
+use crate::shared::consistency::ReadConsistency;
+
+/// 查询端使用的连接：配置了只读副本时读副本，需要读己之写时回到主库
+#[derive(Clone)]
+pub struct ReadConnection {
+    primary: DatabaseConnection,
+    replica: Option<DatabaseConnection>,
+}
+
+impl ReadConnection {
+    pub fn new(primary: DatabaseConnection, replica: Option<DatabaseConnection>) -> Self {
+        Self { primary, replica }
+    }
+
+    /// 按当前请求的一致性要求选择连接
+    pub fn get(&self) -> &DatabaseConnection {
+        match (&self.replica, ReadConsistency::current()) {
+            (Some(replica), ReadConsistency::Eventual) => replica,
+            _ => &self.primary,
+        }
+    }
+}
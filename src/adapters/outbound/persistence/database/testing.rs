@@ -0,0 +1,150 @@
+//! 测试使用的数据库
+
+use chrono::Utc;
+use sea_orm::{ConnectionTrait, Database, DatabaseConnection, Set};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::config::{DbConfig, load_db_config};
+use super::connection::{connect_postgres, connect_sqlite};
+use super::migration::run_pending_migrations;
+use super::read_connection::ReadConnection;
+use crate::adapters::outbound::persistence::config::{
+    PersistenceBackend, PersistenceConfig, PersistenceMode,
+};
+use crate::adapters::outbound::persistence::entities::article_read_view;
+use crate::adapters::outbound::persistence::projection::ArticleReadViewProjector;
+use crate::adapters::outbound::persistence::projection::config::ProjectionConfig;
+use crate::adapters::outbound::persistence::read::SeaOrmArticleReadRepository;
+use crate::adapters::outbound::persistence::unit_of_work::SeaOrmUnitOfWork;
+use crate::application::commands::ArticleCommandHandlerImpl;
+use crate::application::queries::ArticleQueryHandlerImpl;
+use crate::application::services::{ArticleApplicationService, ArticleApplicationServiceImpl};
+use crate::domain::article::commands::{CreateArticleCommand, PublishArticleCommand};
+use crate::domain::article::events::register_article_events;
+use crate::domain::article::value_objects::Slug;
+use crate::shared::events::EventRegistry;
+
+/// 创建已执行全部迁移的SQLite数据库，每次调用使用独立的临时文件
+///
+/// 使用文件而不是内存数据库：后台任务与请求需要多个连接访问同一个库
+pub async fn sqlite_test_db() -> DatabaseConnection {
+    let path = std::env::temp_dir().join(format!("rhyon-test-{}.db", Uuid::now_v7()));
+    let url = format!("sqlite://{}?mode=rwc", path.display());
+
+    let db = connect_sqlite(&load_db_config(), &url)
+        .await
+        .expect("test database should open");
+    run_pending_migrations(&db)
+        .await
+        .expect("migrations should apply");
+    db
+}
+
+/// 仓储测试覆盖的全部后端：总是包含SQLite，设置`RHYON_TEST_POSTGRES_URL`时再加上PostgreSQL
+pub async fn test_databases() -> Vec<DatabaseConnection> {
+    let mut databases = vec![sqlite_test_db().await];
+    if let Ok(url) = std::env::var("RHYON_TEST_POSTGRES_URL") {
+        databases.push(postgres_test_db(&url).await);
+    }
+    databases
+}
+
+/// 每次调用在独立的schema中执行迁移，测试之间互不影响（schema不会自动删除）
+async fn postgres_test_db(url: &str) -> DatabaseConnection {
+    let schema = format!("rhyon_test_{}", Uuid::now_v7().simple());
+    let admin = Database::connect(url)
+        .await
+        .expect("test postgres should be reachable");
+    admin
+        .execute_unprepared(&format!("CREATE SCHEMA {}", schema))
+        .await
+        .expect("test schema should be created");
+
+    let config = DbConfig {
+        schema: Some(schema),
+        ..load_db_config()
+    };
+    let db = connect_postgres(&config, url)
+        .await
+        .expect("test postgres should open");
+    run_pending_migrations(&db)
+        .await
+        .expect("migrations should apply");
+    db
+}
+
+/// 读模型中一篇已发布的文章，其余字段按需覆盖
+pub fn read_view_row(slug: &str) -> article_read_view::ActiveModel {
+    let now = Utc::now();
+    article_read_view::ActiveModel {
+        id: Set(Uuid::now_v7()),
+        title: Set(slug.to_string()),
+        slug: Set(slug.to_string()),
+        summary: Set(String::new()),
+        content: Set(String::new()),
+        status: Set("published".to_string()),
+        author_name: Set(None),
+        tags: Set(serde_json::json!([])),
+        reading_time: Set(1),
+        comment_count: Set(0),
+        created_at: Set(now.into()),
+        updated_at: Set(now.into()),
+        published_at: Set(Some(now.into())),
+    }
+}
+
+/// 数据库上的文章应用服务：命令以CRUD模式写入，查询读取读模型
+pub fn article_service(db: &DatabaseConnection) -> Arc<dyn ArticleApplicationService> {
+    let registry = Arc::new(register_article_events(EventRegistry::new()));
+    let config = PersistenceConfig {
+        backend: PersistenceBackend::Database,
+        mode: PersistenceMode::Crud,
+        snapshot_every: 0,
+    };
+    let unit_of_work = Arc::new(SeaOrmUnitOfWork::new(db.clone(), registry, &config));
+    let read = Arc::new(SeaOrmArticleReadRepository::new(ReadConnection::new(
+        db.clone(),
+        None,
+    )));
+
+    Arc::new(ArticleApplicationServiceImpl::new(
+        Arc::new(ArticleCommandHandlerImpl::new(unit_of_work)),
+        Arc::new(ArticleQueryHandlerImpl::new(read)),
+    ))
+}
+
+/// 把已提交的事件投影到读模型
+pub async fn project(db: &DatabaseConnection) {
+    let registry = Arc::new(register_article_events(EventRegistry::new()));
+    let config = ProjectionConfig {
+        poll_interval_ms: 10,
+        batch_size: 100,
+        gap_grace_ms: 0,
+    };
+    ArticleReadViewProjector::new(db.clone(), registry, config)
+        .project_batch()
+        .await
+        .expect("events should project");
+}
+
+/// 创建并发布文章，随后投影到读模型
+pub async fn publish_article(
+    db: &DatabaseConnection,
+    service: &dyn ArticleApplicationService,
+    command: CreateArticleCommand,
+) {
+    let slug = match &command.slug {
+        Some(slug) => slug.clone(),
+        None => Slug::from_title(&command.title)
+            .unwrap()
+            .value()
+            .to_string(),
+    };
+    service.create_article(command).await.unwrap();
+    service
+        .publish_article(PublishArticleCommand::new(slug))
+        .await
+        .unwrap();
+    project(db).await;
+}
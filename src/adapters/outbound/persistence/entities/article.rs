@@ -1,12 +1,14 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.8
 
+use sea_orm::Set;
 use sea_orm::entity::prelude::*;
-use sea_orm::{NotSet, Set};
 use serde::{Deserialize, Serialize};
 
 // 为新架构添加的转换imports
-use crate::domain::article::aggregate::Article;
-use crate::domain::article::value_objects::{Content, Id, Slug, Status, Summary, Title};
+use crate::domain::article::aggregate::{Article, ArticleState};
+use crate::domain::article::value_objects::{
+    Author, Content, Id, Slug, Status, Summary, Tag, Title,
+};
 use crate::shared::errors::RhyonError;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
@@ -22,6 +24,9 @@ pub struct Model {
     pub slug: String,
     #[sea_orm(column_type = "Text")]
     pub content: String,
+    pub author_name: Option<String>,
+    /// 标签列表（JSON字符串数组）
+    pub tags: Json,
     pub status: String,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
@@ -37,14 +42,19 @@ impl ActiveModelBehavior for ActiveModel {}
 impl From<Article> for ActiveModel {
     fn from(article: Article) -> Self {
         ActiveModel {
-            id: match article.id() {
-                Some(id) => Set(*id.value()),
-                None => NotSet,
-            },
+            id: Set(*article.id().value()),
             title: Set(article.title().value().to_string()),
             slug: Set(article.slug().value().to_string()),
             summary: Set(article.summary().value().to_string()),
             content: Set(article.content().value().to_string()),
+            author_name: Set(article.author().map(|author| author.value().to_string())),
+            tags: Set(serde_json::json!(
+                article
+                    .tags()
+                    .iter()
+                    .map(|tag| tag.value())
+                    .collect::<Vec<_>>()
+            )),
             status: Set(article.status().as_str().to_string()),
             created_at: Set((*article.created_at()).into()),
             updated_at: Set((*article.updated_at()).into()),
@@ -62,19 +72,25 @@ impl TryFrom<Model> for Article {
         let slug = Slug::new(model.slug)?;
         let summary = Summary::new(model.summary)?;
         let content = Content::new(model.content);
+        let author = model.author_name.map(Author::new).transpose()?;
+        let tags = serde_json::from_value(model.tags)
+            .map_err(|e| RhyonError::ServerError(format!("无效的文章标签: {}", e)))?;
+        let tags = Tag::parse_all(tags)?;
         let status = Status::from_str(&model.status)?;
 
-        Ok(Article::reconstitute(
-            Some(Id::from(model.id)),
+        Ok(Article::reconstitute(ArticleState {
+            id: Id::from(model.id),
             slug,
             title,
             summary,
             content,
+            author,
+            tags,
             status,
-            model.created_at.into(),
-            model.updated_at.into(),
-            model.published_at.map(|dt| dt.into()),
-        ))
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+            published_at: model.published_at.map(|dt| dt.into()),
+        }))
     }
 }
 
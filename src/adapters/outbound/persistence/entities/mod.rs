@@ -1,3 +1,10 @@
 pub mod article;
+pub mod article_read_view;
+pub mod article_snapshot;
+pub mod domain_event;
+pub mod outbox;
+pub mod projection_checkpoint;
+pub mod webhook_delivery;
+pub mod webhook_subscription;
 
 pub use article::*;
@@ -0,0 +1,3 @@
+pub mod sea_orm_event_store;
+
+pub use sea_orm_event_store::*;
@@ -0,0 +1,237 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, SqlErr,
+};
+use std::sync::Arc;
+
+use crate::adapters::outbound::persistence::entities::domain_event;
+use crate::core::types::conversions::Converter;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::{EventEnvelope, EventStore, StoredEvent};
+use crate::shared::i18n::Message;
+
+/// 基于SeaORM的事件存储实现，绑定到数据库连接或工作单元的事务
+pub struct SeaOrmEventStore<C = DatabaseConnection> {
+    conn: Arc<C>,
+}
+
+impl SeaOrmEventStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { conn: Arc::new(db) }
+    }
+}
+
+impl SeaOrmEventStore<DatabaseTransaction> {
+    /// 绑定到事务的事件存储，追加的事件随事务一起提交或回滚
+    pub fn in_transaction(txn: Arc<DatabaseTransaction>) -> Self {
+        Self { conn: txn }
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send + Sync> EventStore for SeaOrmEventStore<C> {
+    async fn append(&self, envelopes: &[EventEnvelope]) -> Result<(), RhyonError> {
+        if envelopes.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        let rows = envelopes.iter().map(|envelope| domain_event::ActiveModel {
+            event_id: Set(envelope.event_id),
+            event_type: Set(envelope.event_type.clone()),
+            aggregate_type: Set(envelope.aggregate_type.clone()),
+            aggregate_id: Set(envelope.aggregate_id.clone()),
+            sequence: Set(envelope.sequence as i64),
+            schema_version: Set(envelope.schema_version as i32),
+            causation_id: Set(envelope.causation_id),
+            correlation_id: Set(envelope.correlation_id),
+            occurred_on: Set(envelope.occurred_on.into()),
+            payload: Set(envelope.payload.clone()),
+            recorded_at: Set(now.into()),
+            ..Default::default()
+        });
+
+        domain_event::Entity::insert_many(rows)
+            .exec(self.conn.as_ref())
+            .await
+            .map_err(map_conflict)?;
+        Ok(())
+    }
+
+    async fn read_stream(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+        from_sequence: u64,
+    ) -> Result<Vec<StoredEvent>, RhyonError> {
+        let rows = domain_event::Entity::find()
+            .filter(domain_event::Column::AggregateType.eq(aggregate_type))
+            .filter(domain_event::Column::AggregateId.eq(aggregate_id))
+            .filter(domain_event::Column::Sequence.gte(from_sequence as i64))
+            .order_by_asc(domain_event::Column::Sequence)
+            .all(self.conn.as_ref())
+            .await?;
+
+        Ok(rows.into_iter().map(StoredEvent::from).collect())
+    }
+
+    async fn read_all(
+        &self,
+        after_position: u64,
+        limit: u64,
+    ) -> Result<Vec<StoredEvent>, RhyonError> {
+        let rows = domain_event::Entity::find()
+            .filter(domain_event::Column::Position.gt(after_position as i64))
+            .order_by_asc(domain_event::Column::Position)
+            .limit(limit)
+            .all(self.conn.as_ref())
+            .await?;
+
+        Ok(rows.into_iter().map(StoredEvent::from).collect())
+    }
+
+    async fn last_sequence(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+    ) -> Result<u64, RhyonError> {
+        let last: Option<Option<i64>> = domain_event::Entity::find()
+            .select_only()
+            .column_as(domain_event::Column::Sequence.max(), "last_sequence")
+            .filter(domain_event::Column::AggregateType.eq(aggregate_type))
+            .filter(domain_event::Column::AggregateId.eq(aggregate_id))
+            .into_tuple()
+            .one(self.conn.as_ref())
+            .await?;
+
+        Ok(last.flatten().unwrap_or(0) as u64)
+    }
+
+    async fn head_position(&self) -> Result<u64, RhyonError> {
+        let head: Option<Option<i64>> = domain_event::Entity::find()
+            .select_only()
+            .column_as(domain_event::Column::Position.max(), "head_position")
+            .into_tuple()
+            .one(self.conn.as_ref())
+            .await?;
+
+        Ok(head.flatten().unwrap_or(0) as u64)
+    }
+}
+
+impl From<domain_event::Model> for StoredEvent {
+    fn from(row: domain_event::Model) -> Self {
+        Self {
+            position: row.position as u64,
+            envelope: EventEnvelope {
+                event_id: row.event_id,
+                event_type: row.event_type,
+                aggregate_type: row.aggregate_type,
+                aggregate_id: row.aggregate_id,
+                sequence: row.sequence as u64,
+                schema_version: row.schema_version as u32,
+                causation_id: row.causation_id,
+                correlation_id: row.correlation_id,
+                occurred_on: row.occurred_on.convert(),
+                payload: row.payload,
+            },
+        }
+    }
+}
+
+/// 同一聚合的序号冲突说明有并发写入
+fn map_conflict(err: DbErr) -> RhyonError {
+    match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => {
+            RhyonError::Conflict(Message::new("concurrent-modification"))
+        }
+        _ => RhyonError::Database(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::database::testing::test_databases;
+    use sea_orm::TransactionTrait;
+    use uuid::Uuid;
+
+    fn envelope(aggregate_id: &str, sequence: u64) -> EventEnvelope {
+        EventEnvelope {
+            event_id: Uuid::new_v4(),
+            event_type: "article.created".to_string(),
+            aggregate_type: "article".to_string(),
+            aggregate_id: aggregate_id.to_string(),
+            sequence,
+            schema_version: 1,
+            causation_id: None,
+            correlation_id: None,
+            occurred_on: Utc::now(),
+            payload: serde_json::json!({ "sequence": sequence }),
+        }
+    }
+
+    fn sequences(events: &[StoredEvent]) -> Vec<u64> {
+        events.iter().map(|event| event.envelope.sequence).collect()
+    }
+
+    #[tokio::test]
+    async fn events_are_read_by_aggregate_and_by_position() {
+        for db in test_databases().await {
+            let store = SeaOrmEventStore::new(db);
+            let caused = envelope("a", 1).with_causation_id(Uuid::new_v4());
+            store
+                .append(&[caused.clone(), envelope("a", 2)])
+                .await
+                .unwrap();
+            store.append(&[envelope("b", 1)]).await.unwrap();
+            store.append(&[envelope("a", 3)]).await.unwrap();
+
+            assert_eq!(
+                sequences(&store.read_stream("article", "a", 2).await.unwrap()),
+                [2, 3]
+            );
+            assert_eq!(store.last_sequence("article", "a").await.unwrap(), 3);
+            assert_eq!(store.last_sequence("article", "c").await.unwrap(), 0);
+
+            let all = store.read_all(0, 10).await.unwrap();
+            assert_eq!(all.len(), 4);
+            assert_eq!(all[0].envelope.event_id, caused.event_id);
+            assert_eq!(all[0].envelope.causation_id, caused.causation_id);
+            assert_eq!(store.head_position().await.unwrap(), all[3].position);
+            let rest = store.read_all(all[1].position, 10).await.unwrap();
+            assert_eq!(rest.len(), 2);
+            assert_eq!(rest[0].envelope.aggregate_id, "b");
+
+            // 同一聚合的序号已被占用
+            assert!(matches!(
+                store.append(&[envelope("a", 3)]).await,
+                Err(RhyonError::Conflict(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn events_appended_in_a_transaction_follow_its_outcome() {
+        for db in test_databases().await {
+            let store = SeaOrmEventStore::new(db.clone());
+
+            let txn = Arc::new(db.begin().await.unwrap());
+            let bound = SeaOrmEventStore::in_transaction(txn.clone());
+            bound.append(&[envelope("a", 1)]).await.unwrap();
+            assert_eq!(bound.last_sequence("article", "a").await.unwrap(), 1);
+            drop(bound);
+            Arc::into_inner(txn).unwrap().rollback().await.unwrap();
+            assert_eq!(store.last_sequence("article", "a").await.unwrap(), 0);
+
+            let txn = Arc::new(db.begin().await.unwrap());
+            let bound = SeaOrmEventStore::in_transaction(txn.clone());
+            bound.append(&[envelope("a", 1)]).await.unwrap();
+            drop(bound);
+            Arc::into_inner(txn).unwrap().commit().await.unwrap();
+            assert_eq!(store.last_sequence("article", "a").await.unwrap(), 1);
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use super::InMemoryArticleStore;
+use crate::application::models::{
+    ArticleField, ArticleFieldSet, ArticleQueryModel, TagQueryModel, estimate_reading_time,
+};
+use crate::application::ports::ArticleReadRepository;
+use crate::domain::article::aggregate::Article;
+use crate::domain::article::specifications::{ArticleSortSpec, ArticleSpec};
+use crate::shared::errors::RhyonError;
+use crate::shared::i18n::Message;
+use crate::shared::pagination::{
+    CursorDirection, CursorPage, CursorPagination, QueryPage, QueryPagination,
+};
+use crate::shared::query::{SortCriteria, Specification};
+
+/// 基于内存存储的文章读仓储，查询条件与排序均在内存中求值
+pub struct InMemoryArticleReadRepository {
+    store: InMemoryArticleStore,
+}
+
+impl InMemoryArticleReadRepository {
+    pub fn new(store: InMemoryArticleStore) -> Self {
+        Self { store }
+    }
+
+    /// 满足规约的全部文章，条件引用未知字段时与SQL实现一样返回错误
+    fn matching(
+        &self,
+        specification: &ArticleSpec,
+        fields: &ArticleFieldSet,
+    ) -> Result<Vec<ArticleQueryModel>, RhyonError> {
+        let criteria = specification.to_query_criteria();
+        criteria.ensure_fields(is_article_field)?;
+
+        // 与SQL一样按完整的行求值条件，再只返回选择的字段
+        let every_field = ArticleFieldSet::only(ArticleField::ALL);
+        Ok(self
+            .store
+            .all()
+            .iter()
+            .filter(|article| criteria.is_satisfied_by(&to_query_model(article, &every_field)))
+            .map(|article| to_query_model(article, fields))
+            .collect())
+    }
+
+    /// 排序结果追加id作为决胜字段，保证分页稳定
+    fn sorted(
+        mut models: Vec<ArticleQueryModel>,
+        sort: &SortCriteria,
+    ) -> Result<Vec<ArticleQueryModel>, RhyonError> {
+        sort.ensure_fields(is_article_field)?;
+        models.sort_by(|a, b| sort.compare(a, b));
+        Ok(models)
+    }
+}
+
+fn is_article_field(name: &str) -> bool {
+    ArticleField::parse(name).is_some()
+}
+
+/// 按字段集生成查询模型，未选择的关联数据保持为空
+fn to_query_model(article: &Article, fields: &ArticleFieldSet) -> ArticleQueryModel {
+    let content = article.content().value();
+    let builder = ArticleQueryModel::builder()
+        .id(article.id().value().to_string())
+        .title(article.title().value().to_string())
+        .slug(article.slug().value().to_string())
+        .summary(article.summary().value().to_string())
+        .status(article.status().as_str().to_string())
+        .published_at(article.published_at().copied())
+        .created_at(*article.created_at())
+        .updated_at(*article.updated_at())
+        .reading_time(estimate_reading_time(content))
+        .author_name(
+            fields
+                .contains(ArticleField::AuthorName)
+                .then(|| article.author().map(|author| author.value().to_string()))
+                .flatten(),
+        )
+        .tags(fields.contains(ArticleField::Tags).then(|| {
+            article
+                .tags()
+                .iter()
+                .map(|tag| tag.value().to_string())
+                .collect()
+        }));
+
+    if fields.contains(ArticleField::Content) {
+        builder.content(content.to_string()).build()
+    } else {
+        builder.build()
+    }
+}
+
+#[async_trait]
+impl ArticleReadRepository for InMemoryArticleReadRepository {
+    async fn find_by_specification(
+        &self,
+        specification: ArticleSpec,
+        sort: ArticleSortSpec,
+        pagination: QueryPagination,
+        fields: ArticleFieldSet,
+    ) -> Result<QueryPage<ArticleQueryModel>, RhyonError> {
+        let repo_pagination = pagination.to_repository_pagination();
+        let sort = sort.to_sort_criteria().asc("id");
+
+        let models = Self::sorted(self.matching(&specification, &fields)?, &sort)?;
+        let total = models.len() as u64;
+        let items = models
+            .into_iter()
+            .skip(repo_pagination.offset() as usize)
+            .take(repo_pagination.limit() as usize)
+            .collect();
+
+        Ok(QueryPage::new(
+            items,
+            pagination.page(),
+            pagination.size(),
+            total,
+        ))
+    }
+
+    async fn find_by_cursor(
+        &self,
+        specification: ArticleSpec,
+        sort: ArticleSortSpec,
+        pagination: CursorPagination,
+        fields: ArticleFieldSet,
+    ) -> Result<CursorPage<ArticleQueryModel>, RhyonError> {
+        let sort_criteria = sort.to_sort_criteria();
+
+        // 与SQL实现一致：按排序键加id排序，向前翻页时反向扫描
+        let keyset = sort_criteria.clone().asc("id");
+        let keyset = match pagination.direction() {
+            CursorDirection::Next => keyset,
+            CursorDirection::Prev => keyset.reversed(),
+        };
+
+        if let Some(cursor) = pagination.cursor() {
+            cursor.ensure_matches(&sort_criteria)?;
+            Uuid::parse_str(&cursor.id)
+                .map_err(|_| RhyonError::Validation(Message::new("invalid-cursor")))?;
+        }
+
+        let models = self.matching(&specification, &fields)?;
+        let total = pagination.include_total().then_some(models.len() as u64);
+
+        let window = Self::sorted(models, &keyset)?
+            .into_iter()
+            .filter(|model| match pagination.cursor() {
+                Some(cursor) => keyset.compare(model, cursor) == Ordering::Greater,
+                None => true,
+            })
+            .take(pagination.fetch_limit() as usize)
+            .collect();
+
+        Ok(CursorPage::from_window(
+            window,
+            &pagination,
+            &sort_criteria,
+            total,
+        ))
+    }
+
+    async fn count_by_specification(&self, specification: ArticleSpec) -> Result<u64, RhyonError> {
+        let models = self.matching(&specification, &ArticleFieldSet::list_default())?;
+        Ok(models.len() as u64)
+    }
+
+    async fn find_tags(
+        &self,
+        specification: ArticleSpec,
+    ) -> Result<Vec<TagQueryModel>, RhyonError> {
+        let fields = ArticleFieldSet::only([ArticleField::Tags, ArticleField::UpdatedAt]);
+        let mut tags: BTreeMap<String, DateTime<Utc>> = BTreeMap::new();
+        for model in self.matching(&specification, &fields)? {
+            for tag in model.tags.unwrap_or_default() {
+                let updated_at = tags.entry(tag).or_insert(model.updated_at);
+                *updated_at = (*updated_at).max(model.updated_at);
+            }
+        }
+
+        Ok(tags
+            .into_iter()
+            .map(|(tag, updated_at)| TagQueryModel { tag, updated_at })
+            .collect())
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+        fields: ArticleFieldSet,
+    ) -> Result<Option<ArticleQueryModel>, RhyonError> {
+        Ok(self
+            .store
+            .find_by_slug(slug)
+            .map(|article| to_query_model(&article, &fields)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::article::value_objects::{Content, Id, Slug, Status, Summary, Title};
+    use crate::shared::pagination::Cursor;
+    use chrono::{Duration, TimeZone};
+    use std::cmp::Reverse;
+
+    const PAGE_SIZE: u64 = 4;
+
+    fn day(n: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap() + Duration::days(n)
+    }
+
+    /// 发布时间与标题大量重复，另有两篇草稿
+    fn seed() -> (InMemoryArticleReadRepository, Vec<Article>) {
+        let articles: Vec<_> = (0..17)
+            .map(|i| {
+                let status = if i % 8 == 7 {
+                    Status::Draft
+                } else {
+                    Status::Published
+                };
+                Article::builder()
+                    .id(Id::from(Uuid::new_v4()))
+                    .slug(Slug::new(format!("article-{}", i)).unwrap())
+                    .title(Title::new(format!("title-{}", i % 4)).unwrap())
+                    .summary(Summary::new("summary".to_string()).unwrap())
+                    .content(Content::new("content".to_string()))
+                    .status(status)
+                    .created_at(day(0))
+                    .updated_at(day(0))
+                    .published_at((status == Status::Published).then(|| day(i % 3)))
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+
+        let store = InMemoryArticleStore::new();
+        store.put_all(articles.clone()).unwrap();
+        (InMemoryArticleReadRepository::new(store), articles)
+    }
+
+    /// 已发布文章按给定键排序后的id，键相同时按id升序
+    fn expected_ids<K: Ord>(articles: &[Article], key: impl Fn(&Article) -> K) -> Vec<String> {
+        let mut published: Vec<_> = articles
+            .iter()
+            .filter(|article| *article.status() == Status::Published)
+            .collect();
+        published.sort_by_key(|article| (key(article), article.id().value().to_string()));
+        published
+            .into_iter()
+            .map(|article| article.id().value().to_string())
+            .collect()
+    }
+
+    fn sorts(articles: &[Article]) -> Vec<(ArticleSortSpec, Vec<String>)> {
+        vec![
+            (
+                ArticleSortSpec::PublishedAtDesc,
+                expected_ids(articles, |a| Reverse(a.published_at().copied())),
+            ),
+            (
+                ArticleSortSpec::PublishedAtAsc,
+                expected_ids(articles, |a| a.published_at().copied()),
+            ),
+            (
+                ArticleSortSpec::TitleAsc,
+                expected_ids(articles, |a| a.title().value().to_string()),
+            ),
+            (
+                ArticleSortSpec::Custom(SortCriteria::new().asc("title").desc("published_at")),
+                expected_ids(articles, |a| {
+                    (
+                        a.title().value().to_string(),
+                        Reverse(a.published_at().copied()),
+                    )
+                }),
+            ),
+        ]
+    }
+
+    #[tokio::test]
+    async fn offset_pages_follow_the_sort_with_id_breaking_ties() {
+        let (repo, articles) = seed();
+
+        for (sort, expected) in sorts(&articles) {
+            let mut visited = Vec::new();
+            for number in 1.. {
+                let page = repo
+                    .find_by_specification(
+                        ArticleSpec::published(),
+                        sort.clone(),
+                        QueryPagination::new(number, PAGE_SIZE),
+                        ArticleFieldSet::list_default(),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(page.total_items, expected.len() as u64);
+                assert_eq!(
+                    page.total_pages,
+                    expected.len().div_ceil(PAGE_SIZE as usize) as u64
+                );
+                if page.items.is_empty() {
+                    break;
+                }
+                assert!(page.items.len() as u64 <= PAGE_SIZE);
+                visited.extend(page.items.into_iter().map(|model| model.id));
+            }
+            assert_eq!(visited, expected, "{:?}", sort);
+        }
+    }
+
+    #[tokio::test]
+    async fn cursor_pages_visit_every_article_once_in_both_directions() {
+        let (repo, articles) = seed();
+
+        let page = |sort: ArticleSortSpec, token: Option<String>| {
+            let cursor = token.map(|token| Cursor::decode(&token).unwrap());
+            repo.find_by_cursor(
+                ArticleSpec::published(),
+                sort,
+                CursorPagination::new(cursor, PAGE_SIZE),
+                ArticleFieldSet::list_default(),
+            )
+        };
+        let ids = |page: &CursorPage<ArticleQueryModel>| -> Vec<String> {
+            page.items.iter().map(|model| model.id.clone()).collect()
+        };
+
+        for (sort, expected) in sorts(&articles) {
+            let mut pages = vec![page(sort.clone(), None).await.unwrap()];
+            while let Some(next) = pages.last().unwrap().next_cursor.clone() {
+                pages.push(page(sort.clone(), Some(next)).await.unwrap());
+            }
+            let forward: Vec<_> = pages.iter().flat_map(ids).collect();
+            assert_eq!(forward, expected, "{:?}", sort);
+
+            // 从最后一页向前翻回第一页，每页内容与向后翻时一致
+            let mut current = pages.pop().unwrap();
+            while let Some(prev) = current.prev_cursor.clone() {
+                current = page(sort.clone(), Some(prev)).await.unwrap();
+                assert_eq!(ids(&current), ids(&pages.pop().unwrap()), "{:?}", sort);
+            }
+            assert!(pages.is_empty(), "{:?}", sort);
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_sort_fields_are_rejected() {
+        let (repo, _) = seed();
+
+        let result = repo
+            .find_by_specification(
+                ArticleSpec::published(),
+                ArticleSortSpec::Custom(SortCriteria::new().asc("password")),
+                QueryPagination::new(1, PAGE_SIZE),
+                ArticleFieldSet::list_default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(RhyonError::Validation(_))));
+    }
+}
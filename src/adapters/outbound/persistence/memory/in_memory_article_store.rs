@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+use crate::domain::article::aggregate::Article;
+use crate::shared::errors::RhyonError;
+use crate::shared::i18n::Message;
+
+/// 内存中的文章存储，由内存读写仓储共享
+#[derive(Clone, Default)]
+pub struct InMemoryArticleStore {
+    articles: Arc<RwLock<HashMap<Uuid, Article>>>,
+}
+
+impl InMemoryArticleStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 原子地插入或替换一批文章，任何slug已被其他文章使用时整批都不写入
+    pub fn put_all(&self, batch: Vec<Article>) -> Result<(), RhyonError> {
+        let mut articles = self.articles.write().expect("article store lock poisoned");
+
+        // 被本批替换的旧版本不参与slug冲突检查
+        let replaced = |existing: &&Article| batch.iter().any(|a| a.id() == existing.id());
+        for article in &batch {
+            let slug_taken = articles
+                .values()
+                .filter(|existing| !replaced(existing))
+                .chain(batch.iter())
+                .any(|other| other.slug() == article.slug() && other.id() != article.id());
+            if slug_taken {
+                return Err(RhyonError::Conflict(
+                    Message::new("slug-taken").with_arg("slug", article.slug().value()),
+                ));
+            }
+        }
+
+        for article in batch {
+            articles.insert(*article.id().value(), article);
+        }
+        Ok(())
+    }
+
+    pub fn contains(&self, id: &Uuid) -> bool {
+        self.read().contains_key(id)
+    }
+
+    pub fn find_by_slug(&self, slug: &str) -> Option<Article> {
+        self.read()
+            .values()
+            .find(|article| article.slug().value() == slug)
+            .cloned()
+    }
+
+    /// 当前全部文章的快照
+    pub fn all(&self) -> Vec<Article> {
+        self.read().values().cloned().collect()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<Uuid, Article>> {
+        self.articles.read().expect("article store lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::article::value_objects::{Content, Slug, Title};
+
+    fn article(slug: &str) -> Article {
+        Article::create(
+            Title::new(format!("Title of {}", slug)).unwrap(),
+            Slug::new(slug.to_string()).unwrap(),
+            None,
+            Content::new("content".to_string()),
+            None,
+            Vec::new(),
+        )
+        .unwrap()
+    }
+
+    fn slugs(store: &InMemoryArticleStore) -> Vec<String> {
+        let mut slugs: Vec<_> = store
+            .all()
+            .iter()
+            .map(|article| article.slug().value().to_string())
+            .collect();
+        slugs.sort();
+        slugs
+    }
+
+    #[test]
+    fn slug_taken_by_another_article_rejects_the_whole_batch() {
+        let store = InMemoryArticleStore::new();
+        store.put_all(vec![article("first")]).unwrap();
+
+        let fresh = article("fresh");
+        let result = store.put_all(vec![fresh.clone(), article("first")]);
+
+        assert!(matches!(result, Err(RhyonError::Conflict(_))));
+        assert!(!store.contains(fresh.id().value()));
+        assert_eq!(slugs(&store), ["first"]);
+    }
+
+    #[test]
+    fn slugs_must_be_unique_within_a_batch() {
+        let store = InMemoryArticleStore::new();
+
+        let result = store.put_all(vec![article("twin"), article("twin")]);
+
+        assert!(matches!(result, Err(RhyonError::Conflict(_))));
+        assert!(store.all().is_empty());
+    }
+
+    #[test]
+    fn articles_keep_or_swap_their_own_slugs() {
+        let store = InMemoryArticleStore::new();
+        let mut first = article("first");
+        let mut second = article("second");
+        store.put_all(vec![first.clone(), second.clone()]).unwrap();
+
+        // 替换自身不算冲突
+        first
+            .update_title(Title::new("Renamed".to_string()).unwrap())
+            .unwrap();
+        store.put_all(vec![first.clone()]).unwrap();
+        assert_eq!(
+            store.find_by_slug("first").unwrap().title().value(),
+            "Renamed"
+        );
+
+        // 同一批内互换slug时按替换后的状态检查
+        first
+            .update_slug(Slug::new("second".to_string()).unwrap())
+            .unwrap();
+        second
+            .update_slug(Slug::new("first".to_string()).unwrap())
+            .unwrap();
+        store.put_all(vec![first.clone(), second.clone()]).unwrap();
+
+        assert_eq!(store.find_by_slug("second").unwrap().id(), first.id());
+        assert_eq!(store.find_by_slug("first").unwrap().id(), second.id());
+        assert_eq!(store.all().len(), 2);
+    }
+}
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+use super::InMemoryArticleStore;
+use crate::domain::article::aggregate::Article;
+use crate::domain::article::ports::article_write_repository::ArticleWriteRepository;
+use crate::domain::article::value_objects::Slug;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::DomainEvent;
+use crate::shared::i18n::Message;
+
+/// 工作单元内基于内存存储的文章写仓储
+///
+/// 修改和领域事件先暂存，由工作单元提交时一次性写入存储并发布事件
+pub struct InMemoryArticleWriteRepository {
+    store: InMemoryArticleStore,
+    pending: Mutex<PendingChanges>,
+}
+
+/// 尚未提交的文章和领域事件
+#[derive(Default)]
+struct PendingChanges {
+    articles: HashMap<Uuid, Article>,
+    events: Vec<Box<dyn DomainEvent>>,
+}
+
+impl InMemoryArticleWriteRepository {
+    pub fn new(store: InMemoryArticleStore) -> Self {
+        Self {
+            store,
+            pending: Mutex::default(),
+        }
+    }
+
+    /// 取出暂存的修改，交由工作单元提交
+    pub fn into_changes(self) -> (Vec<Article>, Vec<Box<dyn DomainEvent>>) {
+        let pending = self
+            .pending
+            .into_inner()
+            .expect("pending changes lock poisoned");
+        (pending.articles.into_values().collect(), pending.events)
+    }
+
+    fn stage(&self, mut article: Article) {
+        let mut pending = self.pending.lock().expect("pending changes lock poisoned");
+        pending.events.extend(article.get_uncommitted_events());
+        pending.articles.insert(*article.id().value(), article);
+    }
+
+    fn exists(&self, id: &Uuid) -> bool {
+        self.store.contains(id)
+            || self
+                .pending
+                .lock()
+                .expect("pending changes lock poisoned")
+                .articles
+                .contains_key(id)
+    }
+}
+
+#[async_trait]
+impl ArticleWriteRepository for InMemoryArticleWriteRepository {
+    async fn save(&self, article: Article) -> Result<Uuid, RhyonError> {
+        let id = *article.id().value();
+        if self.exists(&id) {
+            return Err(RhyonError::Conflict(
+                Message::new("article-exists").with_arg("id", id.to_string()),
+            ));
+        }
+
+        self.stage(article);
+        Ok(id)
+    }
+
+    async fn update(&self, article: Article) -> Result<(), RhyonError> {
+        if !self.exists(article.id().value()) {
+            return Err(RhyonError::NotFound);
+        }
+
+        self.stage(article);
+        Ok(())
+    }
+
+    async fn find_for_update(&self, slug: &Slug) -> Result<Option<Article>, RhyonError> {
+        // 优先读取本工作单元暂存的版本
+        let pending = self.pending.lock().expect("pending changes lock poisoned");
+        if let Some(article) = pending.articles.values().find(|a| a.slug() == slug) {
+            return Ok(Some(article.clone()));
+        }
+
+        Ok(self
+            .store
+            .find_by_slug(slug.value())
+            .filter(|article| !pending.articles.contains_key(article.id().value())))
+    }
+}
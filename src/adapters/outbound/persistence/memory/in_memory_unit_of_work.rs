@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::{InMemoryArticleStore, InMemoryArticleWriteRepository};
+use crate::application::ports::{TransactionalRepositories, UnitOfWork, UnitOfWorkSession};
+use crate::domain::article::ports::article_write_repository::ArticleWriteRepository;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::EventPublisher;
+
+/// 基于内存存储的工作单元
+///
+/// 没有发件箱，领域事件在修改写入存储后直接发布
+pub struct InMemoryUnitOfWork {
+    store: InMemoryArticleStore,
+    publisher: Arc<dyn EventPublisher>,
+}
+
+impl InMemoryUnitOfWork {
+    pub fn new(store: InMemoryArticleStore, publisher: Arc<dyn EventPublisher>) -> Self {
+        Self { store, publisher }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWorkSession>, RhyonError> {
+        Ok(Box::new(InMemoryUnitOfWorkSession {
+            store: self.store.clone(),
+            publisher: self.publisher.clone(),
+            articles: InMemoryArticleWriteRepository::new(self.store.clone()),
+        }))
+    }
+}
+
+struct InMemoryUnitOfWorkSession {
+    store: InMemoryArticleStore,
+    publisher: Arc<dyn EventPublisher>,
+    articles: InMemoryArticleWriteRepository,
+}
+
+impl TransactionalRepositories for InMemoryUnitOfWorkSession {
+    fn articles(&self) -> &dyn ArticleWriteRepository {
+        &self.articles
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkSession for InMemoryUnitOfWorkSession {
+    async fn commit(self: Box<Self>) -> Result<(), RhyonError> {
+        let (articles, events) = self.articles.into_changes();
+        self.store.put_all(articles)?;
+
+        // 修改已生效，处理失败的事件已记入死信列表，不再让本次命令失败
+        if let Err(e) = self.publisher.publish_all(events).await {
+            tracing::warn!("⚠️ 领域事件处理失败: {}", e);
+        }
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), RhyonError> {
+        // 暂存的修改随会话一起丢弃
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::article::aggregate::Article;
+    use crate::domain::article::value_objects::{Content, Slug, Title};
+    use crate::shared::events::DomainEvent;
+    use std::sync::Mutex;
+
+    /// 记录收到的事件类型
+    #[derive(Default)]
+    struct RecordingPublisher {
+        event_types: Mutex<Vec<String>>,
+    }
+
+    impl RecordingPublisher {
+        fn event_types(&self) -> Vec<String> {
+            self.event_types.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl EventPublisher for RecordingPublisher {
+        async fn publish(&self, event: Box<dyn DomainEvent>) -> Result<(), RhyonError> {
+            self.publish_all(vec![event]).await
+        }
+
+        async fn publish_all(&self, events: Vec<Box<dyn DomainEvent>>) -> Result<(), RhyonError> {
+            let mut event_types = self.event_types.lock().unwrap();
+            event_types.extend(events.iter().map(|event| event.event_type().to_string()));
+            Ok(())
+        }
+    }
+
+    fn article(slug: &str) -> Article {
+        Article::create(
+            Title::new("Title".to_string()).unwrap(),
+            Slug::new(slug.to_string()).unwrap(),
+            None,
+            Content::new("content".to_string()),
+            None,
+            Vec::new(),
+        )
+        .unwrap()
+    }
+
+    fn unit_of_work() -> (
+        InMemoryUnitOfWork,
+        InMemoryArticleStore,
+        Arc<RecordingPublisher>,
+    ) {
+        let store = InMemoryArticleStore::new();
+        let publisher = Arc::new(RecordingPublisher::default());
+        let unit_of_work = InMemoryUnitOfWork::new(store.clone(), publisher.clone());
+        (unit_of_work, store, publisher)
+    }
+
+    #[tokio::test]
+    async fn commit_writes_staged_articles_and_publishes_their_events() {
+        let (unit_of_work, store, publisher) = unit_of_work();
+
+        let session = unit_of_work.begin().await.unwrap();
+        let mut draft = article("committed");
+        draft.publish().unwrap();
+        let id = session.articles().save(draft).await.unwrap();
+
+        // 提交前对存储不可见
+        assert!(!store.contains(&id));
+        session.commit().await.unwrap();
+
+        assert!(store.contains(&id));
+        assert_eq!(
+            publisher.event_types(),
+            ["article.created", "article.published"]
+        );
+    }
+
+    #[tokio::test]
+    async fn rollback_discards_staged_articles_and_events() {
+        let (unit_of_work, store, publisher) = unit_of_work();
+
+        let session = unit_of_work.begin().await.unwrap();
+        let id = session.articles().save(article("discarded")).await.unwrap();
+        session.rollback().await.unwrap();
+
+        assert!(!store.contains(&id));
+        assert!(store.find_by_slug("discarded").is_none());
+        assert!(publisher.event_types().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_commit_keeps_the_store_unchanged_and_publishes_nothing() {
+        let (unit_of_work, store, publisher) = unit_of_work();
+        store.put_all(vec![article("taken")]).unwrap();
+
+        let session = unit_of_work.begin().await.unwrap();
+        let fresh = article("fresh");
+        let fresh_id = *fresh.id().value();
+        session.articles().save(fresh).await.unwrap();
+        session.articles().save(article("taken")).await.unwrap();
+
+        let result = session.commit().await;
+
+        assert!(matches!(result, Err(RhyonError::Conflict(_))));
+        assert!(!store.contains(&fresh_id));
+        assert!(publisher.event_types().is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod in_memory_article_read_repository;
+pub mod in_memory_article_store;
+pub mod in_memory_article_write_repository;
+pub mod in_memory_unit_of_work;
+
+pub use in_memory_article_read_repository::*;
+pub use in_memory_article_store::*;
+pub use in_memory_article_write_repository::*;
+pub use in_memory_unit_of_work::*;
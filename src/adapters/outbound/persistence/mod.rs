@@ -1,4 +1,11 @@
+pub mod config;
 pub mod database;
 pub mod entities;
+pub mod event_store;
+pub mod memory;
+pub mod outbox;
+pub mod projection;
 pub mod read;
+pub mod unit_of_work;
+pub mod webhook;
 pub mod write;
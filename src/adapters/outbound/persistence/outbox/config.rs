@@ -0,0 +1,22 @@
+use crate::load_config;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutboxConfig {
+    /// 没有待投递事件时的轮询间隔
+    pub poll_interval_ms: u64,
+    /// 每批最多投递的事件数
+    pub batch_size: u64,
+    /// 最大投递次数，超过后不再重试
+    pub max_attempts: i32,
+    /// 重试退避的基础时长（按2的指数增长）
+    pub retry_backoff_ms: u64,
+    /// 重试退避的上限
+    pub max_backoff_ms: u64,
+    /// 认领事件的租约时长，实例在投递中途退出时，事件在租约到期后被重新认领
+    pub lease_ms: u64,
+}
+
+pub fn load_outbox_config() -> OutboxConfig {
+    load_config!(outbox, OutboxConfig)
+}
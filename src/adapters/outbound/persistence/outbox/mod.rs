@@ -0,0 +1,6 @@
+pub mod config;
+pub mod outbox_relay;
+pub mod outbox_writer;
+
+pub use outbox_relay::*;
+pub use outbox_writer::*;
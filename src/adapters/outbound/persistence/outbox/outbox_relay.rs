@@ -0,0 +1,289 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use sea_orm::entity::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::{Expr, LockBehavior};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+
+use super::config::OutboxConfig;
+use crate::adapters::outbound::persistence::entities::outbox;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::{DomainEvent, EventEnvelope, EventPublisher, EventRegistry};
+use crate::shared::tasks::{Backoff, BatchJob, spawn_poll_loop};
+
+/// 发件箱中继：后台轮询未投递的事件，通过事件发布器投递并标记为已投递
+pub struct OutboxRelay {
+    db: DatabaseConnection,
+    publisher: Arc<dyn EventPublisher>,
+    registry: Arc<EventRegistry>,
+    config: OutboxConfig,
+}
+
+impl OutboxRelay {
+    pub fn new(
+        db: DatabaseConnection,
+        publisher: Arc<dyn EventPublisher>,
+        registry: Arc<EventRegistry>,
+        config: OutboxConfig,
+    ) -> Self {
+        Self {
+            db,
+            publisher,
+            registry,
+            config,
+        }
+    }
+
+    /// 在后台任务中持续运行
+    pub fn spawn(self) -> JoinHandle<()> {
+        let interval = std::time::Duration::from_millis(self.config.poll_interval_ms);
+        let batch_size = self.config.batch_size;
+        spawn_poll_loop(self, interval, batch_size)
+    }
+
+    /// 投递一批到期的事件，返回处理的事件数
+    ///
+    /// 先在短事务中认领事件，投递在事务之外进行，最后在另一个短事务中记录结果，
+    /// 等待事件处理器（含事件总线的重试退避）时不持有行锁和连接
+    pub async fn relay_batch(&self) -> Result<usize, RhyonError> {
+        let rows = self.claim().await?;
+        let count = rows.len();
+
+        let mut results = Vec::with_capacity(count);
+        for row in rows {
+            let result = match self.decode(&row) {
+                Ok(event) => self.publisher.publish(event).await,
+                Err(e) => Err(e),
+            };
+            results.push((row, result));
+        }
+
+        self.record(results).await?;
+        Ok(count)
+    }
+
+    /// 认领一批到期的事件：把下次投递时间推迟到租约结束，其他实例在租约内不会再次认领
+    async fn claim(&self) -> Result<Vec<outbox::Model>, RhyonError> {
+        let txn = self.db.begin().await?;
+        let now = Utc::now();
+
+        // SKIP LOCKED 允许多个实例并行认领而不互相等待
+        let rows = outbox::Entity::find()
+            .filter(outbox::Column::DispatchedAt.is_null())
+            .filter(outbox::Column::Attempts.lt(self.config.max_attempts))
+            .filter(outbox::Column::NextAttemptAt.lte(now))
+            .order_by_asc(outbox::Column::CreatedAt)
+            .limit(self.config.batch_size)
+            .lock_with_behavior(
+                sea_orm::sea_query::LockType::Update,
+                LockBehavior::SkipLocked,
+            )
+            .all(&txn)
+            .await?;
+
+        if !rows.is_empty() {
+            let lease_until = now + Duration::milliseconds(self.config.lease_ms as i64);
+            outbox::Entity::update_many()
+                .col_expr(
+                    outbox::Column::NextAttemptAt,
+                    Expr::value(DateTimeWithTimeZone::from(lease_until)),
+                )
+                .filter(outbox::Column::Id.is_in(rows.iter().map(|row| row.id)))
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await?;
+        Ok(rows)
+    }
+
+    /// 记录投递结果：成功的标记为已投递，失败的按退避安排下次投递
+    async fn record(
+        &self,
+        results: Vec<(outbox::Model, Result<(), RhyonError>)>,
+    ) -> Result<(), RhyonError> {
+        if results.is_empty() {
+            return Ok(());
+        }
+
+        let txn = self.db.begin().await?;
+        for (row, result) in results {
+            let attempts = row.attempts + 1;
+            let mut active = row.into_active_model();
+            active.attempts = Set(attempts);
+
+            match result {
+                Ok(()) => {
+                    active.dispatched_at = Set(Some(Utc::now().into()));
+                    active.last_error = Set(None);
+                }
+                Err(e) => {
+                    tracing::warn!("⚠️ 领域事件投递失败（第{}次）: {}", attempts, e);
+                    active.last_error = Set(Some(e.to_string()));
+                    let delay = self.backoff().delay(attempts as u32);
+                    active.next_attempt_at = Set((Utc::now() + delay).into());
+                }
+            }
+
+            active.update(&txn).await?;
+        }
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /// 从发件箱记录还原领域事件
+    fn decode(&self, row: &outbox::Model) -> Result<Box<dyn DomainEvent>, RhyonError> {
+        let envelope: EventEnvelope = serde_json::from_value(row.envelope.clone())
+            .map_err(|e| RhyonError::ServerError(format!("无效的事件信封: {}", e)))?;
+        self.registry.deserialize(&envelope)
+    }
+
+    fn backoff(&self) -> Backoff {
+        Backoff::new(self.config.retry_backoff_ms, self.config.max_backoff_ms)
+    }
+}
+
+#[async_trait]
+impl BatchJob for OutboxRelay {
+    fn name(&self) -> &'static str {
+        "发件箱事件投递"
+    }
+
+    async fn run_batch(&self) -> Result<usize, RhyonError> {
+        self.relay_batch().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::database::testing::sqlite_test_db;
+    use crate::adapters::outbound::persistence::outbox::OutboxWriter;
+    use crate::domain::article::events::{ArticlePublishedEvent, register_article_events};
+    use std::sync::OnceLock;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use uuid::Uuid;
+
+    /// 可切换成功或失败的发布器
+    #[derive(Default)]
+    struct SwitchPublisher {
+        failing: AtomicBool,
+    }
+
+    #[async_trait]
+    impl EventPublisher for SwitchPublisher {
+        async fn publish(&self, _event: Box<dyn DomainEvent>) -> Result<(), RhyonError> {
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(RhyonError::ServerError("handler failed".to_string()));
+            }
+            Ok(())
+        }
+
+        async fn publish_all(&self, _events: Vec<Box<dyn DomainEvent>>) -> Result<(), RhyonError> {
+            unreachable!("relay publishes events one by one")
+        }
+    }
+
+    /// 投递过程中检查事件已被认领：另一个中继认领不到，租约已对其他连接可见
+    struct LeaseProbe {
+        db: DatabaseConnection,
+        other: OnceLock<OutboxRelay>,
+        checked: AtomicBool,
+    }
+
+    #[async_trait]
+    impl EventPublisher for LeaseProbe {
+        async fn publish(&self, _event: Box<dyn DomainEvent>) -> Result<(), RhyonError> {
+            let other = self.other.get().expect("other relay is set");
+            assert_eq!(other.relay_batch().await.unwrap(), 0);
+
+            let row = outbox::Entity::find().one(&self.db).await.unwrap().unwrap();
+            assert!(row.next_attempt_at > Utc::now() + Duration::seconds(30));
+            assert!(row.dispatched_at.is_none());
+
+            self.checked.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn publish_all(&self, _events: Vec<Box<dyn DomainEvent>>) -> Result<(), RhyonError> {
+            unreachable!("relay publishes events one by one")
+        }
+    }
+
+    fn config() -> OutboxConfig {
+        OutboxConfig {
+            poll_interval_ms: 10,
+            batch_size: 10,
+            max_attempts: 3,
+            retry_backoff_ms: 0,
+            max_backoff_ms: 0,
+            lease_ms: 60_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn only_successful_deliveries_are_marked_dispatched() {
+        let db = sqlite_test_db().await;
+        let event = ArticlePublishedEvent::new(Uuid::now_v7(), "hello".to_string(), Utc::now());
+        OutboxWriter::append(&db, &[EventEnvelope::wrap(&event, 1)])
+            .await
+            .unwrap();
+
+        let publisher = Arc::new(SwitchPublisher::default());
+        publisher.failing.store(true, Ordering::SeqCst);
+        let registry = Arc::new(register_article_events(EventRegistry::new()));
+        let relay = OutboxRelay::new(db.clone(), publisher.clone(), registry, config());
+
+        assert_eq!(relay.relay_batch().await.unwrap(), 1);
+        let row = outbox::Entity::find().one(&db).await.unwrap().unwrap();
+        assert_eq!(row.attempts, 1);
+        assert!(row.dispatched_at.is_none());
+        assert!(row.last_error.unwrap().contains("handler failed"));
+
+        publisher.failing.store(false, Ordering::SeqCst);
+        assert_eq!(relay.relay_batch().await.unwrap(), 1);
+        let row = outbox::Entity::find().one(&db).await.unwrap().unwrap();
+        assert_eq!(row.attempts, 2);
+        assert!(row.dispatched_at.is_some());
+        assert!(row.last_error.is_none());
+
+        // 已投递的事件不再处理
+        assert_eq!(relay.relay_batch().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn events_are_leased_and_published_outside_the_claim_transaction() {
+        let db = sqlite_test_db().await;
+        let event = ArticlePublishedEvent::new(Uuid::now_v7(), "hello".to_string(), Utc::now());
+        OutboxWriter::append(&db, &[EventEnvelope::wrap(&event, 1)])
+            .await
+            .unwrap();
+
+        let registry = Arc::new(register_article_events(EventRegistry::new()));
+        let probe = Arc::new(LeaseProbe {
+            db: db.clone(),
+            other: OnceLock::new(),
+            checked: AtomicBool::new(false),
+        });
+        let other = OutboxRelay::new(
+            db.clone(),
+            Arc::new(SwitchPublisher::default()),
+            registry.clone(),
+            config(),
+        );
+        assert!(probe.other.set(other).is_ok());
+        let relay = OutboxRelay::new(db.clone(), probe.clone(), registry, config());
+
+        assert_eq!(relay.relay_batch().await.unwrap(), 1);
+        assert!(probe.checked.load(Ordering::SeqCst));
+
+        let row = outbox::Entity::find().one(&db).await.unwrap().unwrap();
+        assert_eq!(row.attempts, 1);
+        assert!(row.dispatched_at.is_some());
+    }
+}
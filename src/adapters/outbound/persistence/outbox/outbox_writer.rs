@@ -0,0 +1,42 @@
+use chrono::Utc;
+use sea_orm::{ConnectionTrait, EntityTrait, Set};
+
+use crate::adapters::outbound::persistence::entities::outbox;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::EventEnvelope;
+
+/// 发件箱写入器：在聚合所在的事务中记录待投递的领域事件
+pub struct OutboxWriter;
+
+impl OutboxWriter {
+    pub async fn append<C: ConnectionTrait>(
+        conn: &C,
+        envelopes: &[EventEnvelope],
+    ) -> Result<(), RhyonError> {
+        if envelopes.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        let rows = envelopes
+            .iter()
+            .map(|envelope| {
+                Ok(outbox::ActiveModel {
+                    id: Set(envelope.event_id),
+                    aggregate_id: Set(envelope.aggregate_id.clone()),
+                    event_type: Set(envelope.event_type.clone()),
+                    envelope: Set(serde_json::to_value(envelope)
+                        .map_err(|e| RhyonError::ServerError(e.to_string()))?),
+                    created_at: Set(now.into()),
+                    attempts: Set(0),
+                    last_error: Set(None),
+                    next_attempt_at: Set(now.into()),
+                    dispatched_at: Set(None),
+                })
+            })
+            .collect::<Result<Vec<_>, RhyonError>>()?;
+
+        outbox::Entity::insert_many(rows).exec(conn).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use std::sync::Arc;
+
+use super::ARTICLE_READ_VIEW_PROJECTION;
+use crate::adapters::outbound::persistence::entities::projection_checkpoint;
+use crate::adapters::outbound::persistence::event_store::SeaOrmEventStore;
+use crate::shared::consistency::ProjectionProgress;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::EventStore;
+
+/// 文章读模型的投影进度，读取主库上的事件存储和检查点
+pub struct ArticleReadViewProgress {
+    db: DatabaseConnection,
+    event_store: Arc<dyn EventStore>,
+}
+
+impl ArticleReadViewProgress {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self {
+            event_store: Arc::new(SeaOrmEventStore::new(db.clone())),
+            db,
+        }
+    }
+}
+
+#[async_trait]
+impl ProjectionProgress for ArticleReadViewProgress {
+    async fn head_position(&self) -> Result<u64, RhyonError> {
+        self.event_store.head_position().await
+    }
+
+    async fn projected_position(&self) -> Result<u64, RhyonError> {
+        let checkpoint = projection_checkpoint::Entity::find_by_id(ARTICLE_READ_VIEW_PROJECTION)
+            .one(&self.db)
+            .await?;
+        Ok(checkpoint.map_or(0, |checkpoint| checkpoint.position as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::database::testing::{
+        article_service, project, sqlite_test_db,
+    };
+    use crate::domain::article::commands::CreateArticleCommand;
+
+    #[tokio::test]
+    async fn projected_position_catches_up_with_the_head_after_projection() {
+        let db = sqlite_test_db().await;
+        let progress = ArticleReadViewProgress::new(db.clone());
+        assert_eq!(progress.head_position().await.unwrap(), 0);
+        assert_eq!(progress.projected_position().await.unwrap(), 0);
+
+        article_service(&db)
+            .create_article(CreateArticleCommand::new(
+                "Hello".to_string(),
+                "body".to_string(),
+            ))
+            .await
+            .unwrap();
+        let head = progress.head_position().await.unwrap();
+        assert!(head > 0);
+        assert!(progress.projected_position().await.unwrap() < head);
+
+        project(&db).await;
+        assert_eq!(progress.projected_position().await.unwrap(), head);
+    }
+}
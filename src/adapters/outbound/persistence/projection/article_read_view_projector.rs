@@ -0,0 +1,466 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+
+use super::config::ProjectionConfig;
+use crate::adapters::outbound::persistence::entities::{
+    article, article_read_view, projection_checkpoint,
+};
+use crate::adapters::outbound::persistence::event_store::SeaOrmEventStore;
+use crate::application::models::estimate_reading_time;
+use crate::domain::article::events::{
+    ARTICLE_AGGREGATE_TYPE, ArticleCreatedEvent, ArticlePublishedEvent, ArticleUnpublishedEvent,
+    ArticleUpdatedEvent,
+};
+use crate::domain::article::value_objects::Status;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::{EventEnvelope, EventRegistry, EventStore, StoredEvent};
+use crate::shared::tasks::{BatchJob, spawn_poll_loop};
+
+/// 投影在检查点表中的名称
+pub const ARTICLE_READ_VIEW_PROJECTION: &str = "article_read_view";
+
+/// 从文章表建立基线时每条INSERT写入的行数，避免超出数据库的绑定参数上限
+const SEED_CHUNK_SIZE: u64 = 500;
+
+/// 文章读模型投影器：按全局位置消费事件存储，维护`article_read_view`表
+pub struct ArticleReadViewProjector {
+    db: DatabaseConnection,
+    event_store: Arc<dyn EventStore>,
+    registry: Arc<EventRegistry>,
+    config: ProjectionConfig,
+}
+
+impl ArticleReadViewProjector {
+    pub fn new(
+        db: DatabaseConnection,
+        registry: Arc<EventRegistry>,
+        config: ProjectionConfig,
+    ) -> Self {
+        Self {
+            event_store: Arc::new(SeaOrmEventStore::new(db.clone())),
+            db,
+            registry,
+            config,
+        }
+    }
+
+    /// 在后台任务中持续追赶事件存储
+    pub fn spawn(self) -> JoinHandle<()> {
+        let interval = std::time::Duration::from_millis(self.config.poll_interval_ms);
+        let batch_size = self.config.batch_size;
+        spawn_poll_loop(self, interval, batch_size)
+    }
+
+    /// 处理检查点之后的一批事件，返回处理的事件数
+    pub async fn project_batch(&self) -> Result<usize, RhyonError> {
+        let txn = self.db.begin().await?;
+
+        // 锁定检查点，多个实例同时运行时只有一个在推进投影
+        let checkpoint = Self::lock_checkpoint(&txn).await?;
+        let events = self
+            .event_store
+            .read_all(checkpoint, self.config.batch_size)
+            .await?;
+
+        let (position, count) = self.apply_contiguous(&txn, checkpoint, events).await?;
+        if position > checkpoint {
+            Self::save_checkpoint(&txn, position).await?;
+        }
+
+        txn.commit().await?;
+        Ok(count)
+    }
+
+    /// 清空读模型并从头重放全部事件，返回处理的事件数
+    ///
+    /// 整个重建在同一事务中完成，期间查询仍读取到旧数据
+    pub async fn rebuild(&self) -> Result<usize, RhyonError> {
+        let txn = self.db.begin().await?;
+        Self::lock_checkpoint(&txn).await?;
+
+        article_read_view::Entity::delete_many().exec(&txn).await?;
+        Self::seed_from_articles(&txn).await?;
+
+        let mut position = 0;
+        let mut total = 0;
+        loop {
+            let events = self
+                .event_store
+                .read_all(position, self.config.batch_size)
+                .await?;
+            if events.is_empty() {
+                break;
+            }
+
+            let (next, count) = self.apply_contiguous(&txn, position, events).await?;
+            total += count;
+            if next == position {
+                // 剩余事件所在的事务尚未提交，交给后台投影继续处理
+                break;
+            }
+            position = next;
+        }
+
+        Self::save_checkpoint(&txn, position).await?;
+        txn.commit().await?;
+        Ok(total)
+    }
+
+    /// 按位置顺序应用事件，遇到尚未超时的位置空洞时停止
+    ///
+    /// 自增位置在事务提交前就已分配，空洞可能是仍在进行中的事务，
+    /// 跳过它会导致该事务提交后的事件永远不被投影
+    async fn apply_contiguous<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        from: u64,
+        events: Vec<StoredEvent>,
+    ) -> Result<(u64, usize), RhyonError> {
+        let grace = Duration::milliseconds(self.config.gap_grace_ms as i64);
+        let mut position = from;
+        let mut count = 0;
+
+        for stored in events {
+            if stored.position != position + 1 && Utc::now() - stored.envelope.occurred_on < grace {
+                tracing::debug!(
+                    "⏳ 事件位置{}之前存在空洞，等待进行中的事务提交",
+                    stored.position
+                );
+                break;
+            }
+
+            self.apply(conn, &stored.envelope).await?;
+            position = stored.position;
+            count += 1;
+        }
+
+        Ok((position, count))
+    }
+
+    /// 将单个事件应用到读模型，其他聚合的事件直接忽略
+    async fn apply<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        envelope: &EventEnvelope,
+    ) -> Result<(), RhyonError> {
+        if envelope.aggregate_type != ARTICLE_AGGREGATE_TYPE {
+            return Ok(());
+        }
+
+        let event = self.registry.deserialize(envelope)?;
+        let any = event.as_any();
+
+        if let Some(e) = any.downcast_ref::<ArticleCreatedEvent>() {
+            let row = article_read_view::ActiveModel {
+                id: Set(e.article_id),
+                title: Set(e.title.clone()),
+                slug: Set(e.slug.clone()),
+                reading_time: Set(estimate_reading_time(&e.content) as i32),
+                summary: Set(e.summary.clone()),
+                content: Set(e.content.clone()),
+                status: Set(Status::Draft.as_str().to_string()),
+                author_name: Set(e.author.clone()),
+                tags: Set(serde_json::json!(e.tags)),
+                created_at: Set(e.occurred_on.into()),
+                updated_at: Set(e.occurred_on.into()),
+                published_at: Set(None),
+                ..Default::default()
+            };
+
+            // 重复投影同一事件时覆盖为创建时的状态，保持幂等
+            article_read_view::Entity::insert(row)
+                .on_conflict(
+                    OnConflict::column(article_read_view::Column::Id)
+                        .update_columns([
+                            article_read_view::Column::Title,
+                            article_read_view::Column::Slug,
+                            article_read_view::Column::Summary,
+                            article_read_view::Column::Content,
+                            article_read_view::Column::ReadingTime,
+                            article_read_view::Column::AuthorName,
+                            article_read_view::Column::Tags,
+                            article_read_view::Column::Status,
+                            article_read_view::Column::UpdatedAt,
+                            article_read_view::Column::PublishedAt,
+                        ])
+                        .to_owned(),
+                )
+                .exec(conn)
+                .await?;
+        } else if let Some(e) = any.downcast_ref::<ArticleUpdatedEvent>() {
+            article_read_view::Entity::update_many()
+                .col_expr(article_read_view::Column::Title, Expr::value(&e.title))
+                .col_expr(article_read_view::Column::Slug, Expr::value(&e.slug))
+                .col_expr(article_read_view::Column::Summary, Expr::value(&e.summary))
+                .col_expr(article_read_view::Column::Content, Expr::value(&e.content))
+                .col_expr(
+                    article_read_view::Column::ReadingTime,
+                    Expr::value(estimate_reading_time(&e.content) as i32),
+                )
+                .col_expr(
+                    article_read_view::Column::AuthorName,
+                    Expr::value(e.author.clone()),
+                )
+                .col_expr(
+                    article_read_view::Column::Tags,
+                    Expr::value(serde_json::json!(e.tags)),
+                )
+                .col_expr(
+                    article_read_view::Column::UpdatedAt,
+                    Expr::value(e.occurred_on),
+                )
+                .filter(article_read_view::Column::Id.eq(e.article_id))
+                .exec(conn)
+                .await?;
+        } else if let Some(e) = any.downcast_ref::<ArticlePublishedEvent>() {
+            article_read_view::Entity::update_many()
+                .col_expr(
+                    article_read_view::Column::Status,
+                    Expr::value(Status::Published.as_str()),
+                )
+                .col_expr(
+                    article_read_view::Column::PublishedAt,
+                    Expr::value(e.published_at),
+                )
+                .col_expr(
+                    article_read_view::Column::UpdatedAt,
+                    Expr::value(e.occurred_on),
+                )
+                .filter(article_read_view::Column::Id.eq(e.article_id))
+                .exec(conn)
+                .await?;
+        } else if let Some(e) = any.downcast_ref::<ArticleUnpublishedEvent>() {
+            article_read_view::Entity::update_many()
+                .col_expr(
+                    article_read_view::Column::Status,
+                    Expr::value(Status::Draft.as_str()),
+                )
+                .col_expr(
+                    article_read_view::Column::UpdatedAt,
+                    Expr::value(e.occurred_on),
+                )
+                .filter(article_read_view::Column::Id.eq(e.article_id))
+                .exec(conn)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// 引入事件存储之前创建的文章没有事件历史，以文章表的当前状态作为基线
+    ///
+    /// 随后重放的事件都携带完整的字段值，重复应用到基线上结果不变
+    async fn seed_from_articles<C: ConnectionTrait>(conn: &C) -> Result<(), RhyonError> {
+        let mut pages = article::Entity::find()
+            .order_by_asc(article::Column::Id)
+            .paginate(conn, SEED_CHUNK_SIZE);
+
+        while let Some(rows) = pages.fetch_and_next().await? {
+            if rows.is_empty() {
+                break;
+            }
+
+            let views = rows.into_iter().map(|row| article_read_view::ActiveModel {
+                id: Set(row.id),
+                title: Set(row.title),
+                slug: Set(row.slug),
+                reading_time: Set(estimate_reading_time(&row.content) as i32),
+                summary: Set(row.summary),
+                content: Set(row.content),
+                status: Set(row.status),
+                author_name: Set(row.author_name),
+                tags: Set(row.tags),
+                created_at: Set(row.created_at),
+                updated_at: Set(row.updated_at),
+                published_at: Set(row.published_at),
+                ..Default::default()
+            });
+
+            article_read_view::Entity::insert_many(views)
+                .exec(conn)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn is_initialized(&self) -> Result<bool, RhyonError> {
+        let checkpoint = projection_checkpoint::Entity::find_by_id(ARTICLE_READ_VIEW_PROJECTION)
+            .one(&self.db)
+            .await?;
+        Ok(checkpoint.is_some())
+    }
+
+    /// 读取并锁定检查点，不存在时从0开始
+    async fn lock_checkpoint<C: ConnectionTrait>(conn: &C) -> Result<u64, RhyonError> {
+        let initial = projection_checkpoint::ActiveModel {
+            name: Set(ARTICLE_READ_VIEW_PROJECTION.to_string()),
+            position: Set(0),
+            updated_at: Set(Utc::now().into()),
+        };
+        projection_checkpoint::Entity::insert(initial)
+            .on_conflict(
+                OnConflict::column(projection_checkpoint::Column::Name)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(conn)
+            .await?;
+
+        let checkpoint = projection_checkpoint::Entity::find_by_id(ARTICLE_READ_VIEW_PROJECTION)
+            .lock_exclusive()
+            .one(conn)
+            .await?
+            .ok_or_else(|| RhyonError::ServerError("投影检查点不存在".to_string()))?;
+
+        Ok(checkpoint.position as u64)
+    }
+
+    async fn save_checkpoint<C: ConnectionTrait>(
+        conn: &C,
+        position: u64,
+    ) -> Result<(), RhyonError> {
+        projection_checkpoint::Entity::update(projection_checkpoint::ActiveModel {
+            name: Set(ARTICLE_READ_VIEW_PROJECTION.to_string()),
+            position: Set(position as i64),
+            updated_at: Set(Utc::now().into()),
+        })
+        .exec(conn)
+        .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BatchJob for ArticleReadViewProjector {
+    fn name(&self) -> &'static str {
+        "文章读模型投影"
+    }
+
+    /// 首次运行时没有检查点，先完整构建一次读模型
+    async fn start(&self) {
+        match self.is_initialized().await {
+            Ok(true) => {}
+            Ok(false) => match self.rebuild().await {
+                Ok(count) => tracing::info!("✅ 文章读模型已初始化，重放事件{}个", count),
+                Err(e) => tracing::error!("❌ 文章读模型初始化失败: {}", e),
+            },
+            Err(e) => tracing::error!("❌ 读取投影检查点失败: {}", e),
+        }
+    }
+
+    async fn run_batch(&self) -> Result<usize, RhyonError> {
+        self.project_batch().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::config::{
+        PersistenceBackend, PersistenceConfig, PersistenceMode,
+    };
+    use crate::adapters::outbound::persistence::database::testing::sqlite_test_db;
+    use crate::adapters::outbound::persistence::unit_of_work::SeaOrmUnitOfWork;
+    use crate::application::commands::{ArticleCommandHandler, ArticleCommandHandlerImpl};
+    use crate::domain::article::commands::{CreateArticleCommand, PublishArticleCommand};
+    use crate::domain::article::events::register_article_events;
+    use uuid::Uuid;
+
+    fn projector(db: &DatabaseConnection) -> ArticleReadViewProjector {
+        let registry = Arc::new(register_article_events(EventRegistry::new()));
+        let config = ProjectionConfig {
+            poll_interval_ms: 10,
+            batch_size: 100,
+            gap_grace_ms: 0,
+        };
+        ArticleReadViewProjector::new(db.clone(), registry, config)
+    }
+
+    fn handler(db: &DatabaseConnection) -> ArticleCommandHandlerImpl {
+        let registry = Arc::new(register_article_events(EventRegistry::new()));
+        let config = PersistenceConfig {
+            backend: PersistenceBackend::Database,
+            mode: PersistenceMode::Crud,
+            snapshot_every: 0,
+        };
+        ArticleCommandHandlerImpl::new(Arc::new(SeaOrmUnitOfWork::new(
+            db.clone(),
+            registry,
+            &config,
+        )))
+    }
+
+    async fn view(db: &DatabaseConnection, id: Uuid) -> article_read_view::Model {
+        article_read_view::Entity::find_by_id(id)
+            .one(db)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn article_events_project_author_and_tags() {
+        let db = sqlite_test_db().await;
+        let projector = projector(&db);
+        let handler = handler(&db);
+
+        let command = CreateArticleCommand {
+            author: Some(" Ada ".to_string()),
+            tags: vec!["Rust".to_string(), "web".to_string(), "rust".to_string()],
+            ..CreateArticleCommand::new("Hello".to_string(), "body".to_string())
+        };
+        let id = handler.handle_create(command).await.unwrap();
+        assert_eq!(projector.project_batch().await.unwrap(), 1);
+
+        let row = view(&db, id).await;
+        assert_eq!(row.author_name.as_deref(), Some("Ada"));
+        assert_eq!(row.tags, serde_json::json!(["rust", "web"]));
+        assert_eq!(row.status, "draft");
+
+        handler
+            .handle_publish(PublishArticleCommand::new("hello".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(projector.project_batch().await.unwrap(), 1);
+
+        let row = view(&db, id).await;
+        assert_eq!(row.status, "published");
+        assert_eq!(row.tags, serde_json::json!(["rust", "web"]));
+    }
+
+    #[tokio::test]
+    async fn rebuild_seeds_articles_without_events_in_chunks() {
+        let db = sqlite_test_db().await;
+        let now = Utc::now();
+        let rows = (0..=SEED_CHUNK_SIZE).map(|n| article::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            title: Set(format!("Post {n}")),
+            slug: Set(format!("post-{n}")),
+            summary: Set(String::new()),
+            content: Set("body".to_string()),
+            author_name: Set(Some("Ada".to_string())),
+            tags: Set(serde_json::json!(["rust"])),
+            status: Set("published".to_string()),
+            created_at: Set(now.into()),
+            updated_at: Set(now.into()),
+            published_at: Set(Some(now.into())),
+        });
+        article::Entity::insert_many(rows).exec(&db).await.unwrap();
+
+        assert_eq!(projector(&db).rebuild().await.unwrap(), 0);
+
+        let views = article_read_view::Entity::find().all(&db).await.unwrap();
+        assert_eq!(views.len() as u64, SEED_CHUNK_SIZE + 1);
+        assert!(views.iter().all(|row| {
+            row.author_name.as_deref() == Some("Ada") && row.tags == serde_json::json!(["rust"])
+        }));
+    }
+}
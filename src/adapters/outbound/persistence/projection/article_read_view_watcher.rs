@@ -0,0 +1,213 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::task::JoinHandle;
+
+use super::ArticleReadViewProgress;
+use super::config::ProjectionConfig;
+use crate::adapters::outbound::persistence::event_store::SeaOrmEventStore;
+use crate::domain::article::events::ARTICLE_AGGREGATE_TYPE;
+use crate::shared::consistency::{ProjectionListener, ProjectionProgress};
+use crate::shared::errors::RhyonError;
+use crate::shared::events::EventStore;
+use crate::shared::tasks::{BatchJob, spawn_poll_loop};
+
+/// 跟踪文章读模型的投影检查点，检查点推进后把其间变更的文章通知给监听者
+///
+/// 每个实例各自运行：只有一个实例的投影器在推进检查点，
+/// 其他实例也能据此使自己进程内的缓存失效
+pub struct ArticleReadViewWatcher {
+    progress: ArticleReadViewProgress,
+    event_store: Arc<dyn EventStore>,
+    listener: Arc<dyn ProjectionListener>,
+    config: ProjectionConfig,
+    /// 已通知到的检查点位置
+    notified: AtomicU64,
+}
+
+impl ArticleReadViewWatcher {
+    pub fn new(
+        db: DatabaseConnection,
+        listener: Arc<dyn ProjectionListener>,
+        config: ProjectionConfig,
+    ) -> Self {
+        Self {
+            progress: ArticleReadViewProgress::new(db.clone()),
+            event_store: Arc::new(SeaOrmEventStore::new(db)),
+            listener,
+            config,
+            notified: AtomicU64::new(0),
+        }
+    }
+
+    /// 在后台任务中持续跟踪检查点
+    pub fn spawn(self) -> JoinHandle<()> {
+        let interval = std::time::Duration::from_millis(self.config.poll_interval_ms);
+        let batch_size = self.config.batch_size;
+        spawn_poll_loop(self, interval, batch_size)
+    }
+
+    /// 通知上次检查点之后已投影的一批事件所涉及的文章，返回处理的事件数
+    pub async fn notify_batch(&self) -> Result<usize, RhyonError> {
+        let notified = self.notified.load(Ordering::SeqCst);
+        let projected = self.progress.projected_position().await?;
+        if projected < notified {
+            // 读模型被重建，从新的检查点继续跟踪
+            self.notified.store(projected, Ordering::SeqCst);
+            return Ok(0);
+        }
+        if projected == notified {
+            return Ok(0);
+        }
+
+        let events: Vec<_> = self
+            .event_store
+            .read_all(notified, self.config.batch_size)
+            .await?
+            .into_iter()
+            .filter(|stored| stored.position <= projected)
+            .collect();
+        let Some(last) = events.last().map(|stored| stored.position) else {
+            self.notified.store(projected, Ordering::SeqCst);
+            return Ok(0);
+        };
+
+        let mut article_ids: Vec<String> = Vec::new();
+        for stored in &events {
+            let envelope = &stored.envelope;
+            if envelope.aggregate_type == ARTICLE_AGGREGATE_TYPE
+                && !article_ids.contains(&envelope.aggregate_id)
+            {
+                article_ids.push(envelope.aggregate_id.clone());
+            }
+        }
+        if !article_ids.is_empty() {
+            self.listener.projected(&article_ids).await?;
+        }
+
+        // 一批未读到检查点时，下一批从本批最后一个事件继续
+        let next = if events.len() as u64 >= self.config.batch_size {
+            last
+        } else {
+            projected
+        };
+        self.notified.store(next, Ordering::SeqCst);
+        Ok(events.len())
+    }
+}
+
+#[async_trait]
+impl BatchJob for ArticleReadViewWatcher {
+    fn name(&self) -> &'static str {
+        "文章读模型变更通知"
+    }
+
+    /// 从当前检查点开始跟踪，启动前的变更不再通知
+    async fn start(&self) {
+        match self.progress.projected_position().await {
+            Ok(position) => self.notified.store(position, Ordering::SeqCst),
+            Err(e) => tracing::error!("❌ 读取投影检查点失败: {}", e),
+        }
+    }
+
+    async fn run_batch(&self) -> Result<usize, RhyonError> {
+        self.notify_batch().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::cache::{
+        ArticleCache, ArticleCacheInvalidator, CachedArticleReadRepository, LruCacheStore,
+    };
+    use crate::adapters::outbound::persistence::database::read_connection::ReadConnection;
+    use crate::adapters::outbound::persistence::database::testing::{
+        article_service, project, sqlite_test_db,
+    };
+    use crate::adapters::outbound::persistence::read::SeaOrmArticleReadRepository;
+    use crate::application::models::ArticleFieldSet;
+    use crate::application::ports::ArticleReadRepository;
+    use crate::domain::article::commands::{CreateArticleCommand, PublishArticleCommand};
+    use std::time::Duration;
+
+    /// 一个应用实例：各自的进程内缓存与检查点跟踪
+    struct Instance {
+        repository: CachedArticleReadRepository,
+        watcher: ArticleReadViewWatcher,
+    }
+
+    impl Instance {
+        async fn start(db: &DatabaseConnection) -> Self {
+            let store = Arc::new(LruCacheStore::new(100, Duration::from_secs(60)));
+            let cache = Arc::new(ArticleCache::new(store, "memory", Duration::ZERO));
+            let read = Arc::new(SeaOrmArticleReadRepository::new(ReadConnection::new(
+                db.clone(),
+                None,
+            )));
+            let config = ProjectionConfig {
+                poll_interval_ms: 10,
+                batch_size: 1,
+                gap_grace_ms: 0,
+            };
+            let listener = Arc::new(ArticleCacheInvalidator::new(cache.clone()));
+            let watcher = ArticleReadViewWatcher::new(db.clone(), listener, config);
+            watcher.start().await;
+
+            Self {
+                repository: CachedArticleReadRepository::new(read, cache),
+                watcher,
+            }
+        }
+
+        async fn status(&self, slug: &str) -> String {
+            self.repository
+                .find_by_slug(slug, ArticleFieldSet::all())
+                .await
+                .unwrap()
+                .unwrap()
+                .status
+        }
+
+        /// 处理完检查点之前的全部事件
+        async fn catch_up(&self) {
+            while self.watcher.notify_batch().await.unwrap() > 0 {}
+        }
+    }
+
+    #[tokio::test]
+    async fn every_instance_invalidates_its_cache_once_the_projection_commits() {
+        let db = sqlite_test_db().await;
+        let service = article_service(&db);
+        service
+            .create_article(CreateArticleCommand::new(
+                "Hello".to_string(),
+                "body".to_string(),
+            ))
+            .await
+            .unwrap();
+        project(&db).await;
+
+        let instances = [Instance::start(&db).await, Instance::start(&db).await];
+        for instance in &instances {
+            assert_eq!(instance.status("hello").await, "draft");
+        }
+
+        service
+            .publish_article(PublishArticleCommand::new("hello".to_string()))
+            .await
+            .unwrap();
+        // 投影提交前读模型未变，缓存保持不变
+        for instance in &instances {
+            instance.catch_up().await;
+            assert_eq!(instance.status("hello").await, "draft");
+        }
+
+        project(&db).await;
+        for instance in &instances {
+            instance.catch_up().await;
+            assert_eq!(instance.status("hello").await, "published");
+        }
+    }
+}
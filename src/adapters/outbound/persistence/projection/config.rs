@@ -0,0 +1,16 @@
+use crate::load_config;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectionConfig {
+    /// 没有新事件时的轮询间隔
+    pub poll_interval_ms: u64,
+    /// 每批最多处理的事件数
+    pub batch_size: u64,
+    /// 事件位置出现空洞时的等待时长，超过后视为已回滚的事务并跳过
+    pub gap_grace_ms: u64,
+}
+
+pub fn load_projection_config() -> ProjectionConfig {
+    load_config!(projection, ProjectionConfig)
+}
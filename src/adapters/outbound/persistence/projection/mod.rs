@@ -0,0 +1,8 @@
+pub mod article_read_view_progress;
+pub mod article_read_view_projector;
+pub mod article_read_view_watcher;
+pub mod config;
+
+pub use article_read_view_progress::*;
+pub use article_read_view_projector::*;
+pub use article_read_view_watcher::*;
@@ -1,153 +1,484 @@
-use crate::adapters::outbound::persistence::entities::{Column, Entity};
+use crate::adapters::outbound::persistence::entities::article_read_view::{Column, Entity};
+use crate::application::models::{ArticleField, ArticleFieldSet};
 use crate::domain::article::specifications::{ArticleSortSpec, ArticleSpec};
-use crate::shared::query::{QueryCriteria, QueryValue, SortCriteria, SortField};
-use sea_orm::{ColumnTrait, Condition, QueryOrder};
+use crate::shared::errors::RhyonError;
+use crate::shared::i18n::Message;
+use crate::shared::pagination::{CursorDirection, CursorPagination};
+use crate::shared::query::{QueryCriteria, QueryValue, SortCriteria, SortDirection, SortField};
+use sea_orm::sea_query::{Alias, Expr, Func, FunctionCall, NullOrdering, SimpleExpr};
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseBackend, Order, QueryFilter, QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
 
 pub struct QueryTranslator;
 
 impl QueryTranslator {
-    /// 将ArticleSpec转换为SeaORM条件
-    pub fn translate_specification(spec: &ArticleSpec) -> Condition {
+    /// 将ArticleSpec转换为SeaORM条件（JSON数组条件需要按数据库生成SQL）
+    pub fn translate_specification(
+        spec: &ArticleSpec,
+        backend: DatabaseBackend,
+    ) -> Result<Condition, RhyonError> {
         let criteria = spec.to_query_criteria();
-        Self::translate_criteria(&criteria)
+        Self::translate_criteria(&criteria, backend)
     }
 
-    /// 将QueryCriteria转换为SeaORM的Condition
-    fn translate_criteria(criteria: &QueryCriteria) -> Condition {
-        match criteria {
+    /// 将QueryCriteria转换为SeaORM的Condition，引用未知字段时返回错误
+    pub fn translate_criteria(
+        criteria: &QueryCriteria,
+        backend: DatabaseBackend,
+    ) -> Result<Condition, RhyonError> {
+        let condition = match criteria {
             QueryCriteria::Equals(field, value) => {
-                let column = Self::field_to_column(field);
+                let column = Self::field_to_column(field)?;
                 Condition::all().add(column.eq(Self::value_to_sea_orm(value)))
             }
-            QueryCriteria::NotEquals(field, value) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.ne(Self::value_to_sea_orm(value)))
-            }
-            QueryCriteria::Contains(field, value) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.contains(value))
-            }
-            QueryCriteria::StartsWith(field, value) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.starts_with(value))
-            }
-            QueryCriteria::EndsWith(field, value) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.ends_with(value))
-            }
-            QueryCriteria::IsNull(field) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.is_null())
-            }
-            QueryCriteria::IsNotNull(field) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.is_not_null())
-            }
-            QueryCriteria::GreaterThan(field, value) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.gt(Self::value_to_sea_orm(value)))
-            }
-            QueryCriteria::GreaterThanOrEqual(field, value) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.gte(Self::value_to_sea_orm(value)))
-            }
-            QueryCriteria::LessThan(field, value) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.lt(Self::value_to_sea_orm(value)))
-            }
-            QueryCriteria::LessThanOrEqual(field, value) => {
-                let column = Self::field_to_column(field);
-                Condition::all().add(column.lte(Self::value_to_sea_orm(value)))
-            }
-            QueryCriteria::In(field, values) => {
-                let column = Self::field_to_column(field);
-                let sea_orm_values: Vec<_> = values.iter().map(Self::value_to_sea_orm).collect();
-                Condition::all().add(column.is_in(sea_orm_values))
-            }
-            QueryCriteria::NotIn(field, values) => {
-                let column = Self::field_to_column(field);
-                let sea_orm_values: Vec<_> = values.iter().map(Self::value_to_sea_orm).collect();
-                Condition::all().add(column.is_not_in(sea_orm_values))
-            }
-            QueryCriteria::Between(field, start, end) => {
-                let column = Self::field_to_column(field);
-                Condition::all()
-                    .add(column.gte(Self::value_to_sea_orm(start)))
-                    .add(column.lte(Self::value_to_sea_orm(end)))
+            QueryCriteria::ArrayContains(field, value) => {
+                let column = Self::field_to_column(field)?;
+                Condition::all().add(Self::array_contains(column, value, backend))
             }
             QueryCriteria::And(left, right) => Condition::all()
-                .add(Self::translate_criteria(left))
-                .add(Self::translate_criteria(right)),
-            QueryCriteria::Or(left, right) => Condition::any()
-                .add(Self::translate_criteria(left))
-                .add(Self::translate_criteria(right)),
-            QueryCriteria::Not(criteria) => {
-                // SeaORM没有直接的NOT支持，这里简化处理
-                Self::translate_criteria(criteria)
-            }
-        }
+                .add(Self::translate_criteria(left, backend)?)
+                .add(Self::translate_criteria(right, backend)?),
+        };
+
+        Ok(condition)
     }
 
-    /// 将ArticleSortSpec应用到查询
+    /// 将ArticleSortSpec应用到查询，追加id作为决胜字段，保证OFFSET分页时各页不重不漏
     pub fn apply_sort(
         query: sea_orm::Select<Entity>,
         sort_spec: &ArticleSortSpec,
-    ) -> sea_orm::Select<Entity> {
-        let sort_criteria = sort_spec.to_sort_criteria();
+    ) -> Result<sea_orm::Select<Entity>, RhyonError> {
+        let sort_criteria = sort_spec.to_sort_criteria().asc("id");
         Self::apply_sort_criteria(query, &sort_criteria)
     }
 
-    /// 应用通用排序条件
+    /// 应用通用排序条件，排序字段未知时返回错误
     fn apply_sort_criteria(
         query: sea_orm::Select<Entity>,
         criteria: &SortCriteria,
-    ) -> sea_orm::Select<Entity> {
+    ) -> Result<sea_orm::Select<Entity>, RhyonError> {
         let mut q = query;
 
         for field in &criteria.fields {
-            q = Self::apply_sort_field(q, field);
+            q = Self::apply_sort_field(q, field)?;
         }
 
-        q
+        Ok(q)
     }
 
     /// 应用单个排序字段
+    ///
+    /// 显式指定NULL位置（视NULL为最大值：升序在后、降序在前），
+    /// 保证不同数据库及键集分页条件下的排序一致
     fn apply_sort_field(
         query: sea_orm::Select<Entity>,
         field: &SortField,
+    ) -> Result<sea_orm::Select<Entity>, RhyonError> {
+        let column = Self::field_to_column(&field.field_name)?;
+
+        Ok(match field.direction {
+            SortDirection::Asc => query.order_by_with_nulls(column, Order::Asc, NullOrdering::Last),
+            SortDirection::Desc => {
+                query.order_by_with_nulls(column, Order::Desc, NullOrdering::First)
+            }
+        })
+    }
+
+    /// 应用键集分页：按排序键加id决胜字段排序，并过滤出游标之后的数据
+    ///
+    /// 向前翻页时反转排序方向扫描，调用方需要将结果再次反转
+    pub fn apply_keyset(
+        query: sea_orm::Select<Entity>,
+        sort_spec: &ArticleSortSpec,
+        pagination: &CursorPagination,
+    ) -> Result<sea_orm::Select<Entity>, RhyonError> {
+        let keyset = Self::keyset_sort(sort_spec);
+        let keyset = match pagination.direction() {
+            CursorDirection::Next => keyset,
+            CursorDirection::Prev => keyset.reversed(),
+        };
+
+        let mut query = Self::apply_sort_criteria(query, &keyset)?;
+
+        if let Some(cursor) = pagination.cursor() {
+            cursor.ensure_matches(&sort_spec.to_sort_criteria())?;
+
+            let id = Uuid::parse_str(&cursor.id)
+                .map_err(|_| RhyonError::Validation(Message::new("invalid-cursor")))?;
+            let values = cursor
+                .values
+                .iter()
+                .map(|value| match value {
+                    QueryValue::Null => None,
+                    value => Some(Self::value_to_sea_orm(value)),
+                })
+                .chain(std::iter::once(Some(sea_orm::Value::Uuid(Some(Box::new(
+                    id,
+                ))))));
+
+            let keys = keyset
+                .fields
+                .iter()
+                .zip(values)
+                .map(|(field, value)| {
+                    Ok((
+                        Self::field_to_column(&field.field_name)?,
+                        field.direction.clone(),
+                        value,
+                    ))
+                })
+                .collect::<Result<Vec<_>, RhyonError>>()?;
+
+            query = query.filter(Self::keyset_condition(&keys));
+        }
+
+        Ok(query)
+    }
+
+    /// 键集分页使用的排序条件（末尾追加id作为决胜字段）
+    fn keyset_sort(sort_spec: &ArticleSortSpec) -> SortCriteria {
+        sort_spec.to_sort_criteria().asc("id")
+    }
+
+    /// 构建 `(k1 > v1) OR (k1 = v1 AND k2 > v2) OR ...` 形式的键集条件
+    fn keyset_condition(keys: &[(Column, SortDirection, Option<sea_orm::Value>)]) -> Condition {
+        let mut condition = Condition::any();
+
+        for (i, (column, direction, value)) in keys.iter().enumerate() {
+            let Some(after) = Self::after_condition(*column, direction, value) else {
+                continue;
+            };
+
+            let branch = keys[..i]
+                .iter()
+                .fold(Condition::all(), |branch, (column, _, value)| {
+                    branch.add(match value {
+                        Some(value) => column.eq(value.clone()),
+                        None => column.is_null(),
+                    })
+                });
+
+            condition = condition.add(branch.add(after));
+        }
+
+        condition
+    }
+
+    /// 单个字段“位于游标之后”的条件，与`apply_sort_field`的NULL位置保持一致
+    fn after_condition(
+        column: Column,
+        direction: &SortDirection,
+        value: &Option<sea_orm::Value>,
+    ) -> Option<Condition> {
+        match (direction, value) {
+            (SortDirection::Asc, Some(value)) => Some(
+                Condition::any()
+                    .add(column.gt(value.clone()))
+                    .add(column.is_null()),
+            ),
+            (SortDirection::Asc, None) => None,
+            (SortDirection::Desc, Some(value)) => {
+                Some(Condition::all().add(column.lt(value.clone())))
+            }
+            (SortDirection::Desc, None) => Some(Condition::all().add(column.is_not_null())),
+        }
+    }
+
+    /// 只查询字段集中的列
+    pub fn select_fields(
+        query: sea_orm::Select<Entity>,
+        fields: &ArticleFieldSet,
     ) -> sea_orm::Select<Entity> {
-        let column = Self::field_to_column(&field.field_name);
+        query
+            .select_only()
+            .columns(fields.iter().map(Self::column_of))
+    }
 
-        match field.direction {
-            crate::shared::query::SortDirection::Asc => query.order_by_asc(column),
-            crate::shared::query::SortDirection::Desc => query.order_by_desc(column),
+    /// 将字段名转换为SeaORM列，不支持的字段返回错误（与内存求值一致）
+    fn field_to_column(field_name: &str) -> Result<Column, RhyonError> {
+        ArticleField::parse(field_name)
+            .map(Self::column_of)
+            .ok_or_else(|| {
+                RhyonError::Validation(
+                    Message::new("unsupported-field").with_arg("name", field_name),
+                )
+            })
+    }
+
+    fn column_of(field: ArticleField) -> Column {
+        match field {
+            ArticleField::Id => Column::Id,
+            ArticleField::Title => Column::Title,
+            ArticleField::Slug => Column::Slug,
+            ArticleField::Summary => Column::Summary,
+            ArticleField::Content => Column::Content,
+            ArticleField::Status => Column::Status,
+            ArticleField::PublishedAt => Column::PublishedAt,
+            ArticleField::CreatedAt => Column::CreatedAt,
+            ArticleField::UpdatedAt => Column::UpdatedAt,
+            ArticleField::ReadingTime => Column::ReadingTime,
+            ArticleField::CommentCount => Column::CommentCount,
+            ArticleField::AuthorName => Column::AuthorName,
+            ArticleField::Tags => Column::Tags,
         }
     }
 
-    /// 将字段名转换为SeaORM列
-    fn field_to_column(field_name: &str) -> Column {
-        match field_name {
-            "id" => Column::Id,
-            "title" => Column::Title,
-            "slug" => Column::Slug,
-            "summary" => Column::Summary,
-            "content" => Column::Content,
-            "status" => Column::Status,
-            "published_at" => Column::PublishedAt,
-            "created_at" => Column::CreatedAt,
-            "updated_at" => Column::UpdatedAt,
-            _ => Column::Id, // 默认使用ID列，实际中应该抛出错误
+    /// JSON数组列包含元素：PostgreSQL使用jsonb的`@>`，SQLite展开`json_each`逐个比较
+    fn array_contains(column: Column, value: &QueryValue, backend: DatabaseBackend) -> SimpleExpr {
+        let column = Expr::col((Entity, column));
+        let value = Self::value_to_sea_orm(value);
+
+        match backend {
+            DatabaseBackend::Postgres => Expr::cust_with_exprs(
+                "$1 @> jsonb_build_array($2)",
+                [column.into(), Expr::val(value).into()],
+            ),
+            _ => Expr::cust_with_exprs(
+                "EXISTS (SELECT 1 FROM json_each(?) WHERE json_each.value = ?)",
+                [column.into(), Expr::val(value).into()],
+            ),
         }
     }
 
+    /// 把JSON数组列展开为多行的表函数，元素在`value`列中：
+    /// PostgreSQL使用`jsonb_array_elements_text`，SQLite使用`json_each`
+    pub fn json_array_elements(column: Column, backend: DatabaseBackend) -> FunctionCall {
+        let name = match backend {
+            DatabaseBackend::Postgres => "jsonb_array_elements_text",
+            _ => "json_each",
+        };
+        Func::cust(Alias::new(name)).arg(Expr::col((Entity, column)))
+    }
+
     /// 将QueryValue转换为SeaORM值
+    ///
+    /// 整数也按双精度绑定：PostgreSQL驱动按SQL文本缓存预处理语句，
+    /// 同一语句先后绑定整数和浮点数时，后者会按先前的参数类型被错误解码
     fn value_to_sea_orm(value: &QueryValue) -> sea_orm::Value {
         match value {
             QueryValue::String(s) => sea_orm::Value::String(Some(Box::new(s.clone()))),
-            QueryValue::Integer(i) => sea_orm::Value::BigInt(Some(*i)),
+            QueryValue::Integer(i) => sea_orm::Value::Double(Some(*i as f64)),
             QueryValue::Float(f) => sea_orm::Value::Double(Some(*f)),
             QueryValue::Boolean(b) => sea_orm::Value::Bool(Some(*b)),
+            QueryValue::DateTime(dt) => sea_orm::Value::ChronoDateTimeUtc(Some(Box::new(*dt))),
             QueryValue::Null => sea_orm::Value::String(None),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::database::testing::{
+        read_view_row, test_databases,
+    };
+    use crate::adapters::outbound::persistence::entities::article_read_view;
+    use crate::application::models::ArticleQueryModel;
+    use chrono::{DateTime, TimeZone, Utc};
+    use proptest::prelude::*;
+    use proptest::test_runner::{Config, TestRunner};
+    use sea_orm::{ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+    use std::collections::BTreeSet;
+
+    struct Fixture {
+        title: &'static str,
+        status: &'static str,
+        author: Option<&'static str>,
+        tags: &'static [&'static str],
+        reading_time: u32,
+        published_at: Option<DateTime<Utc>>,
+    }
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, day, 8, 30, 0).unwrap()
+    }
+
+    // 标题覆盖大小写、特殊字符、多字节字符和空串
+    fn fixtures() -> Vec<Fixture> {
+        let fixture = |title, status, author, tags, reading_time, published| Fixture {
+            title,
+            status,
+            author,
+            tags,
+            reading_time,
+            published_at: published,
+        };
+        vec![
+            fixture(
+                "Rust",
+                "published",
+                Some("alice"),
+                &["rust", "db"],
+                3,
+                Some(at(1)),
+            ),
+            fixture(
+                "rust tips",
+                "published",
+                Some("bob"),
+                &["rust"],
+                7,
+                Some(at(2)),
+            ),
+            fixture("100% safe", "draft", None, &[], 1, None),
+            fixture(
+                "snake_case",
+                "published",
+                Some("Alice"),
+                &["style"],
+                12,
+                Some(at(3)),
+            ),
+            fixture("back\\slash", "draft", Some("bob"), &["db"], 5, None),
+            fixture("中文标题", "published", None, &["中文"], 2, Some(at(4))),
+            fixture("", "draft", Some(""), &[""], 0, None),
+            fixture(
+                "RUST",
+                "published",
+                Some("carol"),
+                &["Rust"],
+                9,
+                Some(at(1)),
+            ),
+        ]
+    }
+
+    fn string_value() -> impl Strategy<Value = String> {
+        prop_oneof![
+            Just(String::new()),
+            Just("Rust".to_string()),
+            Just("rust".to_string()),
+            Just("%".to_string()),
+            Just("_".to_string()),
+            Just("\\".to_string()),
+            Just("100%".to_string()),
+            Just("e_c".to_string()),
+            Just("中文".to_string()),
+            Just("alice".to_string()),
+            Just("bob".to_string()),
+            Just("db".to_string()),
+            Just("published".to_string()),
+            Just("draft".to_string()),
+        ]
+    }
+
+    fn text_field() -> impl Strategy<Value = &'static str> {
+        prop_oneof![Just("title"), Just("status"), Just("author_name")]
+    }
+
+    fn leaf() -> impl Strategy<Value = QueryCriteria> {
+        let reading_time = prop_oneof![
+            (0i64..14).prop_map(QueryValue::Integer),
+            Just(QueryValue::Float(2.5)),
+        ];
+        let published_at = (1u32..6).prop_map(|day| QueryValue::DateTime(at(day)));
+
+        prop_oneof![
+            (text_field(), string_value()).prop_map(|(f, v)| QueryCriteria::eq(f, v)),
+            reading_time.prop_map(|v| QueryCriteria::eq("reading_time", v)),
+            published_at.prop_map(|v| QueryCriteria::eq("published_at", v)),
+            string_value().prop_map(|v| QueryCriteria::array_contains("tags", v)),
+        ]
+    }
+
+    fn criteria() -> impl Strategy<Value = QueryCriteria> {
+        leaf().prop_recursive(4, 16, 2, |inner| {
+            (inner.clone(), inner).prop_map(|(l, r)| l.and(r))
+        })
+    }
+
+    async fn seed(db: &DatabaseConnection) -> Vec<ArticleQueryModel> {
+        let mut models = Vec::new();
+        for (i, fixture) in fixtures().into_iter().enumerate() {
+            let tags: Vec<String> = fixture.tags.iter().map(|tag| tag.to_string()).collect();
+            let row = article_read_view::ActiveModel {
+                title: Set(fixture.title.to_string()),
+                status: Set(fixture.status.to_string()),
+                author_name: Set(fixture.author.map(str::to_string)),
+                tags: Set(serde_json::json!(tags)),
+                reading_time: Set(fixture.reading_time as i32),
+                published_at: Set(fixture.published_at.map(Into::into)),
+                ..read_view_row(&format!("article-{}", i))
+            };
+            let row = article_read_view::Entity::insert(row)
+                .exec_with_returning(db)
+                .await
+                .unwrap();
+
+            models.push(
+                ArticleQueryModel::builder()
+                    .id(row.id.to_string())
+                    .title(row.title)
+                    .slug(row.slug)
+                    .summary(row.summary)
+                    .status(row.status)
+                    .published_at(fixture.published_at)
+                    .created_at(row.created_at.into())
+                    .updated_at(row.updated_at.into())
+                    .reading_time(fixture.reading_time)
+                    .author_name(row.author_name)
+                    .tags(Some(tags))
+                    .build(),
+            );
+        }
+        models
+    }
+
+    #[test]
+    fn in_memory_evaluation_agrees_with_sql() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        for db in runtime.block_on(test_databases()) {
+            let models = runtime.block_on(seed(&db));
+            let backend = db.get_database_backend();
+
+            let mut runner = TestRunner::new(Config::with_cases(512));
+            runner
+                .run(&criteria(), |criteria| {
+                    let condition =
+                        QueryTranslator::translate_criteria(&criteria, backend).unwrap();
+                    let in_sql: BTreeSet<String> = runtime
+                        .block_on(Entity::find().filter(condition).all(&db))
+                        .unwrap()
+                        .into_iter()
+                        .map(|row| row.id.to_string())
+                        .collect();
+                    let in_memory: BTreeSet<String> = models
+                        .iter()
+                        .filter(|model| criteria.evaluate(*model).unwrap() == Some(true))
+                        .map(|model| model.id.clone())
+                        .collect();
+
+                    prop_assert_eq!(in_sql, in_memory, "{:?} criteria: {:?}", backend, criteria);
+                    Ok(())
+                })
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected_by_both_sides() {
+        let criteria = QueryCriteria::eq("title", "Rust").and(QueryCriteria::eq("author", "x"));
+        let model = ArticleQueryModel::builder()
+            .id(Uuid::now_v7().to_string())
+            .title("Rust".to_string())
+            .slug("rust".to_string())
+            .summary(String::new())
+            .status("draft".to_string())
+            .created_at(at(1))
+            .updated_at(at(1))
+            .build();
+
+        assert!(criteria.evaluate(&model).is_err());
+        assert!(
+            criteria
+                .ensure_fields(|field| ArticleField::parse(field).is_some())
+                .is_err()
+        );
+        assert!(QueryTranslator::translate_criteria(&criteria, DatabaseBackend::Sqlite).is_err());
+
+        let sort = ArticleSortSpec::Custom(SortCriteria::new().asc("author"));
+        assert!(QueryTranslator::apply_sort(Entity::find(), &sort).is_err());
+    }
+}
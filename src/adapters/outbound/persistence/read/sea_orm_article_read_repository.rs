@@ -1,23 +1,136 @@
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter};
+use sea_orm::entity::prelude::{DateTimeWithTimeZone, Json};
+use sea_orm::sea_query::{Alias, Expr};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Order, PaginatorTrait,
+    QueryFilter, QueryOrder, QueryResult, QuerySelect, QueryTrait, TryGetable,
+};
+use uuid::Uuid;
 
-use crate::adapters::outbound::persistence::entities::{Column, Entity};
+use crate::adapters::outbound::persistence::database::read_connection::ReadConnection;
+use crate::adapters::outbound::persistence::entities::article_read_view::{Column, Entity};
 use crate::adapters::outbound::persistence::read::QueryTranslator;
-use crate::application::models::ArticleQueryModel;
+use crate::application::models::{ArticleField, ArticleFieldSet, ArticleQueryModel, TagQueryModel};
 use crate::application::ports::ArticleReadRepository;
 use crate::core::types::conversions::Converter;
 use crate::domain::article::specifications::{ArticleSortSpec, ArticleSpec};
 use crate::shared::errors::RhyonError;
-use crate::shared::pagination::{QueryPage, QueryPagination};
+use crate::shared::pagination::{CursorPage, CursorPagination, QueryPage, QueryPagination};
 
+/// 基于`article_read_view`投影的文章读仓储，配置了只读副本时从副本读取
 pub struct SeaOrmArticleReadRepository {
-    db: DatabaseConnection,
+    db: ReadConnection,
 }
 
 impl SeaOrmArticleReadRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+    pub fn new(db: ReadConnection) -> Self {
         Self { db }
     }
+
+    /// 需要读取的字段：请求的字段、id以及排序字段（用于生成游标）
+    fn selected_fields(fields: ArticleFieldSet, sort: &ArticleSortSpec) -> ArticleFieldSet {
+        sort.to_sort_criteria()
+            .fields
+            .iter()
+            .filter_map(|field| ArticleField::parse(&field.field_name))
+            .fold(fields.with(ArticleField::Id), ArticleFieldSet::with)
+    }
+}
+
+/// 稀疏字段查询结果，未选择的列为None
+#[derive(Debug)]
+struct ArticleRow {
+    id: Uuid,
+    title: Option<String>,
+    slug: Option<String>,
+    summary: Option<String>,
+    content: Option<String>,
+    status: Option<String>,
+    published_at: Option<DateTimeWithTimeZone>,
+    created_at: Option<DateTimeWithTimeZone>,
+    updated_at: Option<DateTimeWithTimeZone>,
+    reading_time: Option<i32>,
+    comment_count: Option<i32>,
+    author_name: Option<String>,
+    tags: Option<Json>,
+}
+
+impl FromQueryResult for ArticleRow {
+    fn from_query_result(res: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        let columns = res.column_names();
+        let get = |col| SelectedColumn {
+            res,
+            pre,
+            col,
+            columns: &columns,
+        };
+
+        Ok(Self {
+            id: res.try_get(pre, "id")?,
+            title: get("title").value()?,
+            slug: get("slug").value()?,
+            summary: get("summary").value()?,
+            content: get("content").value()?,
+            status: get("status").value()?,
+            published_at: get("published_at").value()?.flatten(),
+            created_at: get("created_at").value()?,
+            updated_at: get("updated_at").value()?,
+            reading_time: get("reading_time").value()?,
+            comment_count: get("comment_count").value()?,
+            author_name: get("author_name").value()?.flatten(),
+            tags: get("tags").value()?,
+        })
+    }
+}
+
+/// 稀疏字段查询中的一列
+struct SelectedColumn<'a> {
+    res: &'a QueryResult,
+    pre: &'a str,
+    col: &'a str,
+    columns: &'a [String],
+}
+
+impl SelectedColumn<'_> {
+    /// 未选择的列返回None，其余读取错误照常返回
+    fn value<T: TryGetable>(&self) -> Result<Option<T>, DbErr> {
+        let name = format!("{}{}", self.pre, self.col);
+        if !self.columns.contains(&name) {
+            return Ok(None);
+        }
+        self.res.try_get(self.pre, self.col).map(Some)
+    }
+}
+
+impl From<ArticleRow> for ArticleQueryModel {
+    /// 未选择的字段使用默认值填充，由入站适配器按字段集裁剪输出
+    fn from(row: ArticleRow) -> Self {
+        let builder = ArticleQueryModel::builder()
+            .id(row.id.to_string())
+            .title(row.title.unwrap_or_default())
+            .slug(row.slug.unwrap_or_default())
+            .summary(row.summary.unwrap_or_default())
+            .status(row.status.unwrap_or_default())
+            .published_at(row.published_at.map(|dt| dt.convert()))
+            .created_at(row.created_at.map(|dt| dt.convert()).unwrap_or_default())
+            .updated_at(row.updated_at.map(|dt| dt.convert()).unwrap_or_default())
+            .reading_time(row.reading_time.unwrap_or_default().max(0) as u32)
+            .comment_count(row.comment_count.unwrap_or_default().max(0) as u32)
+            .author_name(row.author_name)
+            .tags(row.tags.and_then(|tags| serde_json::from_value(tags).ok()));
+
+        match row.content {
+            Some(content) => builder.content(content).build(),
+            None => builder.build(),
+        }
+    }
+}
+
+/// 标签聚合查询结果
+#[derive(Debug, FromQueryResult)]
+struct TagRow {
+    tag: String,
+    updated_at: DateTimeWithTimeZone,
 }
 
 #[async_trait]
@@ -27,6 +140,7 @@ impl ArticleReadRepository for SeaOrmArticleReadRepository {
         specification: ArticleSpec,
         sort: ArticleSortSpec,
         pagination: QueryPagination,
+        fields: ArticleFieldSet,
     ) -> Result<QueryPage<ArticleQueryModel>, RhyonError> {
         let repo_pagination = pagination.to_repository_pagination();
 
@@ -34,35 +148,26 @@ impl ArticleReadRepository for SeaOrmArticleReadRepository {
         let mut query = Entity::find();
 
         // 应用查询条件
-        let condition = QueryTranslator::translate_specification(&specification);
+        let db = self.db.get();
+        let condition =
+            QueryTranslator::translate_specification(&specification, db.get_database_backend())?;
         query = query.filter(condition);
 
         // 应用排序
-        query = QueryTranslator::apply_sort(query, &sort);
+        query = QueryTranslator::apply_sort(query, &sort)?;
+
+        // 只读取需要的列
+        let fields = Self::selected_fields(fields, &sort);
+        let query = QueryTranslator::select_fields(query, &fields).into_model::<ArticleRow>();
 
         // 执行分页查询
-        let paginator = query.paginate(&self.db, repo_pagination.limit());
+        let paginator = query.paginate(db, repo_pagination.limit());
         let (page_num, _) = repo_pagination.to_sea_orm_params();
 
         let total = paginator.num_items().await?;
         let items = paginator.fetch_page(page_num).await?;
 
-        // 转换为查询模型（列表查询不包含content字段）
-        let models = items
-            .into_iter()
-            .map(|model| {
-                ArticleQueryModel::for_list(
-                    model.id.to_string(),
-                    model.title,
-                    model.slug,
-                    model.summary,
-                    model.status,
-                    model.published_at.map(|dt| dt.convert()),
-                    model.created_at.convert(),
-                    model.updated_at.convert(),
-                )
-            })
-            .collect();
+        let models = items.into_iter().map(ArticleQueryModel::from).collect();
 
         Ok(QueryPage::new(
             models,
@@ -72,24 +177,400 @@ impl ArticleReadRepository for SeaOrmArticleReadRepository {
         ))
     }
 
-    async fn find_by_slug(&self, slug: &str) -> Result<Option<ArticleQueryModel>, RhyonError> {
-        let model = Entity::find()
-            .filter(Column::Slug.eq(slug))
-            .one(&self.db)
+    async fn find_by_cursor(
+        &self,
+        specification: ArticleSpec,
+        sort: ArticleSortSpec,
+        pagination: CursorPagination,
+        fields: ArticleFieldSet,
+    ) -> Result<CursorPage<ArticleQueryModel>, RhyonError> {
+        let db = self.db.get();
+        let condition =
+            QueryTranslator::translate_specification(&specification, db.get_database_backend())?;
+        let filtered = Entity::find().filter(condition);
+
+        // 仅在需要时统计总数
+        let total = if pagination.include_total() {
+            Some(filtered.clone().count(db).await?)
+        } else {
+            None
+        };
+
+        let fields = Self::selected_fields(fields, &sort);
+        let query = QueryTranslator::apply_keyset(filtered, &sort, &pagination)?;
+        let items = QueryTranslator::select_fields(query, &fields)
+            .limit(pagination.fetch_limit())
+            .into_model::<ArticleRow>()
+            .all(db)
+            .await?;
+
+        let models = items.into_iter().map(ArticleQueryModel::from).collect();
+
+        Ok(CursorPage::from_window(
+            models,
+            &pagination,
+            &sort.to_sort_criteria(),
+            total,
+        ))
+    }
+
+    async fn count_by_specification(&self, specification: ArticleSpec) -> Result<u64, RhyonError> {
+        let db = self.db.get();
+        let condition =
+            QueryTranslator::translate_specification(&specification, db.get_database_backend())?;
+        Ok(Entity::find().filter(condition).count(db).await?)
+    }
+
+    async fn find_tags(
+        &self,
+        specification: ArticleSpec,
+    ) -> Result<Vec<TagQueryModel>, RhyonError> {
+        let db = self.db.get();
+        let backend = db.get_database_backend();
+        let condition = QueryTranslator::translate_specification(&specification, backend)?;
+
+        // 把每篇文章的标签数组展开为多行，再按标签聚合最近的更新时间
+        let element = Alias::new("tag_element");
+        let tag = Expr::col((element.clone(), Alias::new("value")));
+        let mut query = Entity::find()
+            .select_only()
+            .column_as(tag.clone(), "tag")
+            .column_as(Expr::col((Entity, Column::UpdatedAt)).max(), "updated_at")
+            .filter(condition)
+            .group_by(tag.clone())
+            .order_by(tag, Order::Asc);
+        QueryTrait::query(&mut query).from_function(
+            QueryTranslator::json_array_elements(Column::Tags, backend),
+            element,
+        );
+
+        let rows = query.into_model::<TagRow>().all(db).await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| TagQueryModel {
+                tag: row.tag,
+                updated_at: row.updated_at.convert(),
+            })
+            .collect())
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+        fields: ArticleFieldSet,
+    ) -> Result<Option<ArticleQueryModel>, RhyonError> {
+        let query = Entity::find().filter(Column::Slug.eq(slug));
+        let row = QueryTranslator::select_fields(query, &fields.with(ArticleField::Id))
+            .into_model::<ArticleRow>()
+            .one(self.db.get())
             .await?;
 
-        Ok(model.map(|m| {
-            ArticleQueryModel::for_detail(
-                m.id.to_string(),
-                m.title,
-                m.slug,
-                m.summary,
-                m.content, // 详情查询包含完整内容
-                m.status,
-                m.published_at.map(|dt| dt.convert()),
-                m.created_at.convert(),
-                m.updated_at.convert(),
-            )
-        }))
+        Ok(row.map(ArticleQueryModel::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::database::testing::{
+        read_view_row, sqlite_test_db, test_databases,
+    };
+    use crate::adapters::outbound::persistence::entities::article_read_view;
+    use crate::shared::pagination::Cursor;
+    use crate::shared::query::SortCriteria;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use sea_orm::{DatabaseConnection, Set};
+    use std::collections::HashSet;
+
+    const PAGE_SIZE: u64 = 4;
+
+    fn day(n: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap() + Duration::days(n)
+    }
+
+    /// 插入一行并返回对应的查询模型，作为内存排序的参照
+    async fn insert(
+        db: &DatabaseConnection,
+        slug: &str,
+        title: &str,
+        published_at: Option<DateTime<Utc>>,
+    ) -> ArticleQueryModel {
+        let row = article_read_view::ActiveModel {
+            title: Set(title.to_string()),
+            published_at: Set(published_at.map(Into::into)),
+            ..read_view_row(slug)
+        };
+        let row = Entity::insert(row).exec_with_returning(db).await.unwrap();
+
+        ArticleQueryModel::builder()
+            .id(row.id.to_string())
+            .title(row.title)
+            .slug(row.slug)
+            .summary(row.summary)
+            .status(row.status)
+            .published_at(published_at)
+            .created_at(row.created_at.into())
+            .updated_at(row.updated_at.into())
+            .build()
+    }
+
+    /// 发布时间与标题大量重复，并包含NULL，覆盖决胜字段与NULL位置
+    async fn seed(db: &DatabaseConnection) -> Vec<ArticleQueryModel> {
+        let mut models = Vec::new();
+        for i in 0..23 {
+            let published_at = match i % 5 {
+                0 => None,
+                n => Some(day(n as i64 % 3)),
+            };
+            let title = format!("title-{}", i % 4);
+            models.push(insert(db, &format!("article-{}", i), &title, published_at).await);
+        }
+        models
+    }
+
+    fn expected_ids(models: &[ArticleQueryModel], sort: &ArticleSortSpec) -> Vec<String> {
+        let criteria = sort.to_sort_criteria().asc("id");
+        let mut models: Vec<_> = models.iter().collect();
+        models.sort_by(|l, r| criteria.compare(*l, *r));
+        models.into_iter().map(|model| model.id.clone()).collect()
+    }
+
+    async fn page(
+        repo: &SeaOrmArticleReadRepository,
+        sort: &ArticleSortSpec,
+        token: Option<&str>,
+    ) -> CursorPage<ArticleQueryModel> {
+        let cursor = token.map(|token| Cursor::decode(token).unwrap());
+        repo.find_by_cursor(
+            ArticleSpec::published(),
+            sort.clone(),
+            CursorPagination::new(cursor, PAGE_SIZE),
+            ArticleFieldSet::list_default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn ids(page: &CursorPage<ArticleQueryModel>) -> Vec<String> {
+        page.items.iter().map(|model| model.id.clone()).collect()
+    }
+
+    fn sorts() -> Vec<ArticleSortSpec> {
+        vec![
+            ArticleSortSpec::PublishedAtDesc,
+            ArticleSortSpec::PublishedAtAsc,
+            ArticleSortSpec::TitleAsc,
+            ArticleSortSpec::Custom(SortCriteria::new().asc("title").desc("published_at")),
+        ]
+    }
+
+    #[tokio::test]
+    async fn paging_forward_and_back_visits_every_row_once_in_order() {
+        for db in test_databases().await {
+            let backend = db.get_database_backend();
+            let models = seed(&db).await;
+            let repo = SeaOrmArticleReadRepository::new(ReadConnection::new(db, None));
+
+            for sort in sorts() {
+                let expected = expected_ids(&models, &sort);
+
+                // 向后翻到最后一页
+                let mut forward = Vec::new();
+                let mut current = page(&repo, &sort, None).await;
+                assert!(current.prev_cursor.is_none(), "{:?} {:?}", backend, sort);
+                loop {
+                    assert!(current.items.len() as u64 <= PAGE_SIZE);
+                    forward.extend(ids(&current));
+                    let Some(next) = current.next_cursor.clone() else {
+                        break;
+                    };
+                    current = page(&repo, &sort, Some(&next)).await;
+                }
+                assert_eq!(forward, expected, "{:?} {:?}", backend, sort);
+
+                // 再从最后一页向前翻回第一页
+                let mut backward = ids(&current);
+                while let Some(prev) = current.prev_cursor.clone() {
+                    current = page(&repo, &sort, Some(&prev)).await;
+                    assert!(current.next_cursor.is_some(), "{:?} {:?}", backend, sort);
+                    backward.splice(0..0, ids(&current));
+                }
+                assert_eq!(backward, expected, "{:?} {:?}", backend, sort);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn offset_pages_are_ordered_by_id_within_ties() {
+        for db in test_databases().await {
+            let backend = db.get_database_backend();
+            let models = seed(&db).await;
+            let repo = SeaOrmArticleReadRepository::new(ReadConnection::new(db, None));
+
+            for sort in sorts() {
+                let mut visited = Vec::new();
+                for number in 1.. {
+                    let page = repo
+                        .find_by_specification(
+                            ArticleSpec::published(),
+                            sort.clone(),
+                            QueryPagination::new(number, PAGE_SIZE),
+                            ArticleFieldSet::list_default(),
+                        )
+                        .await
+                        .unwrap();
+                    if page.items.is_empty() {
+                        break;
+                    }
+                    visited.extend(page.items.into_iter().map(|model| model.id));
+                }
+                assert_eq!(
+                    visited,
+                    expected_ids(&models, &sort),
+                    "{:?} {:?}",
+                    backend,
+                    sort
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn rows_inserted_while_paging_neither_repeat_nor_skip_rows() {
+        for db in test_databases().await {
+            let models = seed(&db).await;
+            let repo = SeaOrmArticleReadRepository::new(ReadConnection::new(db.clone(), None));
+            let sort = ArticleSortSpec::PublishedAtAsc;
+
+            let first = page(&repo, &sort, None).await;
+            let mut seen = ids(&first);
+
+            // 游标之前插入的行不应出现，之后插入的行应在后续页中出现
+            let before = insert(&db, "oldest", "oldest", Some(day(-10))).await;
+            let after = insert(&db, "newest", "newest", Some(day(10))).await;
+
+            let mut token = first.next_cursor;
+            while let Some(next) = token {
+                let current = page(&repo, &sort, Some(&next)).await;
+                seen.extend(ids(&current));
+                token = current.next_cursor;
+            }
+
+            let unique: HashSet<_> = seen.iter().collect();
+            assert_eq!(unique.len(), seen.len(), "{:?}", db.get_database_backend());
+            assert!(!seen.contains(&before.id));
+            assert!(seen.contains(&after.id));
+            assert!(models.iter().all(|model| seen.contains(&model.id)));
+        }
+    }
+
+    #[tokio::test]
+    async fn cursor_from_another_sort_is_rejected() {
+        for db in test_databases().await {
+            seed(&db).await;
+            let repo = SeaOrmArticleReadRepository::new(ReadConnection::new(db, None));
+
+            let first = page(&repo, &ArticleSortSpec::PublishedAtDesc, None).await;
+            let cursor = Cursor::decode(&first.next_cursor.unwrap()).unwrap();
+            let result = repo
+                .find_by_cursor(
+                    ArticleSpec::published(),
+                    ArticleSortSpec::TitleAsc,
+                    CursorPagination::new(Some(cursor), PAGE_SIZE),
+                    ArticleFieldSet::list_default(),
+                )
+                .await;
+
+            assert!(matches!(result, Err(RhyonError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn tags_carry_the_newest_update_among_matching_articles() {
+        for db in test_databases().await {
+            let rows = [
+                ("a", "published", day(1), vec!["rust", "web"]),
+                ("b", "published", day(3), vec!["rust"]),
+                ("c", "draft", day(5), vec!["rust", "draft-only"]),
+                ("d", "published", day(2), vec![]),
+            ];
+            for (slug, status, updated_at, tags) in rows {
+                let row = article_read_view::ActiveModel {
+                    status: Set(status.to_string()),
+                    tags: Set(serde_json::json!(tags)),
+                    updated_at: Set(updated_at.into()),
+                    ..read_view_row(slug)
+                };
+                Entity::insert(row).exec(&db).await.unwrap();
+            }
+            let repo = SeaOrmArticleReadRepository::new(ReadConnection::new(db, None));
+
+            let tags = repo.find_tags(ArticleSpec::published()).await.unwrap();
+            assert_eq!(
+                tags,
+                [
+                    TagQueryModel {
+                        tag: "rust".to_string(),
+                        updated_at: day(3),
+                    },
+                    TagQueryModel {
+                        tag: "web".to_string(),
+                        updated_at: day(1),
+                    },
+                ]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn unselected_columns_are_left_empty() {
+        for db in test_databases().await {
+            let row = article_read_view::ActiveModel {
+                content: Set("body".to_string()),
+                author_name: Set(Some("alice".to_string())),
+                tags: Set(serde_json::json!(["rust"])),
+                ..read_view_row("sparse")
+            };
+            Entity::insert(row).exec(&db).await.unwrap();
+            let repo = SeaOrmArticleReadRepository::new(ReadConnection::new(db, None));
+
+            let fields = ArticleFieldSet::only([ArticleField::Title]);
+            let model = repo.find_by_slug("sparse", fields).await.unwrap().unwrap();
+            assert_eq!(model.title, "sparse");
+            assert_eq!(model.content, None);
+            assert_eq!(model.author_name, None);
+            assert_eq!(model.tags, None);
+
+            let fields = ArticleFieldSet::all()
+                .with(ArticleField::AuthorName)
+                .with(ArticleField::Tags);
+            let model = repo.find_by_slug("sparse", fields).await.unwrap().unwrap();
+            assert_eq!(model.content.as_deref(), Some("body"));
+            assert_eq!(model.author_name.as_deref(), Some("alice"));
+            assert_eq!(model.tags, Some(vec!["rust".to_string()]));
+        }
+    }
+
+    #[tokio::test]
+    async fn unreadable_selected_column_is_an_error() {
+        // 只有SQLite允许在整数列中写入文本，用来构造无法解码的值
+        let db = sqlite_test_db().await;
+        Entity::insert(read_view_row("corrupt"))
+            .exec(&db)
+            .await
+            .unwrap();
+        db.execute_unprepared(
+            "UPDATE article_read_view SET reading_time = 'many' WHERE slug = 'corrupt'",
+        )
+        .await
+        .unwrap();
+        let repo = SeaOrmArticleReadRepository::new(ReadConnection::new(db, None));
+
+        let fields = ArticleFieldSet::only([ArticleField::ReadingTime]);
+        assert!(repo.find_by_slug("corrupt", fields).await.is_err());
+
+        // 未选择该列时不受影响
+        let fields = ArticleFieldSet::only([ArticleField::Title]);
+        assert!(repo.find_by_slug("corrupt", fields).await.is_ok());
     }
 }
@@ -0,0 +1,3 @@
+pub mod sea_orm_unit_of_work;
+
+pub use sea_orm_unit_of_work::*;
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use std::sync::Arc;
+
+use crate::adapters::outbound::persistence::config::{PersistenceConfig, PersistenceMode};
+use crate::adapters::outbound::persistence::write::{
+    EventSourcedArticleWriteRepository, SeaOrmArticleWriteRepository,
+};
+use crate::application::ports::{TransactionalRepositories, UnitOfWork, UnitOfWorkSession};
+use crate::domain::article::ports::article_write_repository::ArticleWriteRepository;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::EventRegistry;
+
+/// 基于SeaORM事务的工作单元
+pub struct SeaOrmUnitOfWork {
+    db: DatabaseConnection,
+    registry: Arc<EventRegistry>,
+    mode: PersistenceMode,
+    snapshot_every: u64,
+}
+
+impl SeaOrmUnitOfWork {
+    pub fn new(
+        db: DatabaseConnection,
+        registry: Arc<EventRegistry>,
+        config: &PersistenceConfig,
+    ) -> Self {
+        Self {
+            db,
+            registry,
+            mode: config.mode,
+            snapshot_every: config.snapshot_every,
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for SeaOrmUnitOfWork {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWorkSession>, RhyonError> {
+        let txn = Arc::new(self.db.begin().await?);
+
+        // 按持久化方式创建绑定到该事务的写仓储
+        let articles: Box<dyn ArticleWriteRepository> = match self.mode {
+            PersistenceMode::Crud => Box::new(SeaOrmArticleWriteRepository::new(txn.clone())),
+            PersistenceMode::EventSourced => Box::new(EventSourcedArticleWriteRepository::new(
+                txn.clone(),
+                self.registry.clone(),
+                self.snapshot_every,
+            )),
+        };
+
+        Ok(Box::new(SeaOrmUnitOfWorkSession { txn, articles }))
+    }
+}
+
+/// 进行中的数据库事务及绑定到它的仓储
+struct SeaOrmUnitOfWorkSession {
+    txn: Arc<DatabaseTransaction>,
+    articles: Box<dyn ArticleWriteRepository>,
+}
+
+impl SeaOrmUnitOfWorkSession {
+    /// 释放仓储持有的事务引用，取回事务的所有权
+    fn into_transaction(self) -> Result<DatabaseTransaction, RhyonError> {
+        let Self { txn, articles } = self;
+        drop(articles);
+
+        Arc::try_unwrap(txn)
+            .map_err(|_| RhyonError::ServerError("工作单元的事务仍被引用".to_string()))
+    }
+}
+
+impl TransactionalRepositories for SeaOrmUnitOfWorkSession {
+    fn articles(&self) -> &dyn ArticleWriteRepository {
+        self.articles.as_ref()
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkSession for SeaOrmUnitOfWorkSession {
+    async fn commit(self: Box<Self>) -> Result<(), RhyonError> {
+        self.into_transaction()?.commit().await?;
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), RhyonError> {
+        self.into_transaction()?.rollback().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod sea_orm_webhook_delivery_repository;
+pub mod sea_orm_webhook_subscription_repository;
+
+pub use sea_orm_webhook_delivery_repository::*;
+pub use sea_orm_webhook_subscription_repository::*;
@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use sea_orm::sea_query::{LockBehavior, LockType, OnConflict};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use uuid::Uuid;
+
+use crate::adapters::outbound::persistence::entities::webhook_delivery::{
+    ActiveModel, Column, Entity,
+};
+use crate::domain::webhook::delivery::{DeliveryStatus, WebhookDelivery};
+use crate::domain::webhook::ports::WebhookDeliveryRepository;
+use crate::shared::errors::RhyonError;
+
+pub struct SeaOrmWebhookDeliveryRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmWebhookDeliveryRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for SeaOrmWebhookDeliveryRepository {
+    async fn save_all(&self, deliveries: &[WebhookDelivery]) -> Result<(), RhyonError> {
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        // 同一事件重复分发时投递ID相同，忽略已存在的记录
+        Entity::insert_many(deliveries.iter().map(ActiveModel::from))
+            .on_conflict(OnConflict::column(Column::Id).do_nothing().to_owned())
+            .do_nothing()
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn update(&self, delivery: &WebhookDelivery) -> Result<(), RhyonError> {
+        ActiveModel::from(delivery).update(&self.db).await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookDelivery>, RhyonError> {
+        Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .map(WebhookDelivery::try_from)
+            .transpose()
+    }
+
+    async fn find_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, RhyonError> {
+        Entity::find()
+            .filter(Column::SubscriptionId.eq(subscription_id))
+            .order_by_desc(Column::CreatedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(WebhookDelivery::try_from)
+            .collect()
+    }
+
+    async fn claim_next(&self, lease: Duration) -> Result<Option<WebhookDelivery>, RhyonError> {
+        let txn = self.db.begin().await?;
+        let now = Utc::now();
+
+        // 推迟下次尝试时间作为租约，避免其他实例在投递期间重复领取
+        let Some(row) = Entity::find()
+            .filter(Column::Status.eq(DeliveryStatus::Pending.as_str()))
+            .filter(Column::NextAttemptAt.lte(now))
+            .order_by_asc(Column::NextAttemptAt)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .one(&txn)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let delivery = WebhookDelivery::try_from(row.clone())?;
+        let mut active = row.into_active_model();
+        active.next_attempt_at = Set((now + lease).into());
+        active.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok(Some(delivery))
+    }
+}
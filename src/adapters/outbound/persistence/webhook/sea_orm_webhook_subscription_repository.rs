@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use sea_orm::ColumnTrait;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+use crate::adapters::outbound::persistence::entities::webhook_subscription::{
+    ActiveModel, Column, Entity,
+};
+use crate::domain::webhook::ports::WebhookSubscriptionRepository;
+use crate::domain::webhook::subscription::WebhookSubscription;
+use crate::shared::errors::RhyonError;
+
+pub struct SeaOrmWebhookSubscriptionRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmWebhookSubscriptionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionRepository for SeaOrmWebhookSubscriptionRepository {
+    async fn save(&self, subscription: &WebhookSubscription) -> Result<(), RhyonError> {
+        Entity::insert(ActiveModel::from(subscription))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn update(&self, subscription: &WebhookSubscription) -> Result<(), RhyonError> {
+        ActiveModel::from(subscription).update(&self.db).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RhyonError> {
+        Entity::delete_by_id(id).exec(&self.db).await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookSubscription>, RhyonError> {
+        let subscription = Entity::find_by_id(id).one(&self.db).await?;
+        Ok(subscription.map(WebhookSubscription::from))
+    }
+
+    async fn find_all(&self) -> Result<Vec<WebhookSubscription>, RhyonError> {
+        let subscriptions = Entity::find()
+            .order_by_asc(Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+        Ok(subscriptions
+            .into_iter()
+            .map(WebhookSubscription::from)
+            .collect())
+    }
+
+    async fn find_active_for_event(
+        &self,
+        event_type: &str,
+    ) -> Result<Vec<WebhookSubscription>, RhyonError> {
+        // 订阅数量有限，事件类型在内存中匹配以避免依赖特定数据库的JSON运算符
+        let subscriptions = Entity::find()
+            .filter(Column::Active.eq(true))
+            .all(&self.db)
+            .await?;
+        Ok(subscriptions
+            .into_iter()
+            .map(WebhookSubscription::from)
+            .filter(|subscription| subscription.subscribes_to(event_type))
+            .collect())
+    }
+}
@@ -0,0 +1,202 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait, QueryFilter,
+    QuerySelect, Set,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::adapters::outbound::persistence::entities::{
+    ActiveModel, Column, Entity, article_snapshot,
+};
+use crate::adapters::outbound::persistence::event_store::SeaOrmEventStore;
+use crate::adapters::outbound::persistence::outbox::OutboxWriter;
+use crate::domain::article::aggregate::Article;
+use crate::domain::article::events::{ARTICLE_AGGREGATE_TYPE, ArticleCreatedEvent};
+use crate::domain::article::ports::article_write_repository::ArticleWriteRepository;
+use crate::domain::article::value_objects::Slug;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::{DomainEvent, EventEnvelope, EventRegistry, EventStore, StoredEvent};
+
+/// 事件溯源的文章写仓储
+///
+/// 聚合通过快照和其后的事件重建；`articles`表作为同步投影随事件在同一事务中更新，
+/// 供读模型和slug查找使用。仓储绑定到工作单元的事务
+pub struct EventSourcedArticleWriteRepository {
+    txn: Arc<DatabaseTransaction>,
+    event_store: Arc<dyn EventStore>,
+    registry: Arc<EventRegistry>,
+    snapshot_every: u64,
+}
+
+impl EventSourcedArticleWriteRepository {
+    pub fn new(
+        txn: Arc<DatabaseTransaction>,
+        registry: Arc<EventRegistry>,
+        snapshot_every: u64,
+    ) -> Self {
+        Self {
+            event_store: Arc::new(SeaOrmEventStore::in_transaction(txn.clone())),
+            txn,
+            registry,
+            snapshot_every,
+        }
+    }
+
+    /// 追加未提交的事件，并在同一事务中更新投影、发件箱和快照
+    async fn persist(&self, mut article: Article, is_new: bool) -> Result<Uuid, RhyonError> {
+        let id = *article.id().value();
+        let events = article.get_uncommitted_events();
+        if events.is_empty() {
+            return Ok(id);
+        }
+
+        let base_version = article.version();
+        let new_version = base_version + events.len() as u64;
+        // 序号由加载时的版本决定，并发修改会触发事件存储的唯一约束
+        let envelopes = EventEnvelope::wrap_all(&events, base_version + 1);
+
+        let txn = self.txn.as_ref();
+        self.event_store.append(&envelopes).await?;
+        OutboxWriter::append(txn, &envelopes).await?;
+
+        if self.should_snapshot(base_version, new_version) {
+            Self::save_snapshot(txn, &article, new_version).await?;
+        }
+
+        let projection: ActiveModel = article.into();
+        if is_new {
+            Entity::insert(projection).exec(txn).await?;
+        } else {
+            projection.update(txn).await?;
+        }
+
+        Ok(id)
+    }
+
+    fn should_snapshot(&self, base_version: u64, new_version: u64) -> bool {
+        self.snapshot_every > 0
+            && new_version / self.snapshot_every > base_version / self.snapshot_every
+    }
+
+    async fn save_snapshot<C: ConnectionTrait>(
+        conn: &C,
+        article: &Article,
+        version: u64,
+    ) -> Result<(), RhyonError> {
+        let state =
+            serde_json::to_value(article).map_err(|e| RhyonError::ServerError(e.to_string()))?;
+        let snapshot = article_snapshot::ActiveModel {
+            aggregate_id: Set(*article.id().value()),
+            version: Set(version as i64),
+            state: Set(state),
+            created_at: Set(Utc::now().into()),
+        };
+
+        article_snapshot::Entity::insert(snapshot)
+            .on_conflict(
+                OnConflict::column(article_snapshot::Column::AggregateId)
+                    .update_columns([
+                        article_snapshot::Column::Version,
+                        article_snapshot::Column::State,
+                        article_snapshot::Column::CreatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// 从快照和事件流重建文章
+    async fn load(&self, id: Uuid) -> Result<Option<Article>, RhyonError> {
+        let snapshot = article_snapshot::Entity::find_by_id(id)
+            .one(self.txn.as_ref())
+            .await?;
+        let from_sequence = snapshot.as_ref().map_or(0, |s| s.version as u64) + 1;
+
+        let stored = self
+            .event_store
+            .read_stream(ARTICLE_AGGREGATE_TYPE, &id.to_string(), from_sequence)
+            .await?;
+
+        match snapshot {
+            Some(snapshot) => {
+                let state: Article = serde_json::from_value(snapshot.state)
+                    .map_err(|e| RhyonError::ServerError(format!("无效的文章快照: {}", e)))?;
+                let events = self.decode(&stored)?;
+                Article::from_snapshot(state, snapshot.version as u64, &events).map(Some)
+            }
+            None if Self::is_replayable(&stored) => {
+                let events = self.decode(&stored)?;
+                Article::from_events(&events).map(Some)
+            }
+            None => self.bootstrap_from_row(id).await,
+        }
+    }
+
+    /// 事件流必须从完整的创建事件开始才能重放
+    fn is_replayable(stored: &[StoredEvent]) -> bool {
+        stored.first().is_some_and(|first| {
+            first.envelope.sequence == 1
+                && first.envelope.event_type == ArticleCreatedEvent::EVENT_TYPE
+                && first.envelope.schema_version >= ArticleCreatedEvent::SCHEMA_VERSION
+        })
+    }
+
+    /// 启用事件溯源之前创建的文章没有可重放的事件流：以当前行为基准写入快照
+    async fn bootstrap_from_row(&self, id: Uuid) -> Result<Option<Article>, RhyonError> {
+        let txn = self.txn.as_ref();
+        let Some(model) = Entity::find_by_id(id).one(txn).await? else {
+            return Ok(None);
+        };
+
+        let version = self
+            .event_store
+            .last_sequence(ARTICLE_AGGREGATE_TYPE, &id.to_string())
+            .await?;
+        let article: Article = model.try_into()?;
+        Self::save_snapshot(txn, &article, version).await?;
+
+        tracing::info!("📸 已为文章 {} 建立基准快照（版本 {}）", id, version);
+        Article::from_snapshot(article, version, &[]).map(Some)
+    }
+
+    fn decode(&self, stored: &[StoredEvent]) -> Result<Vec<Box<dyn DomainEvent>>, RhyonError> {
+        stored
+            .iter()
+            .map(|event| self.registry.deserialize(&event.envelope))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ArticleWriteRepository for EventSourcedArticleWriteRepository {
+    async fn save(&self, article: Article) -> Result<Uuid, RhyonError> {
+        self.persist(article, true).await
+    }
+
+    async fn update(&self, article: Article) -> Result<(), RhyonError> {
+        self.persist(article, false).await?;
+        Ok(())
+    }
+
+    async fn find_for_update(&self, slug: &Slug) -> Result<Option<Article>, RhyonError> {
+        // 锁定投影行直到工作单元结束，串行化同一文章的并发修改
+        let id: Option<Uuid> = Entity::find()
+            .select_only()
+            .column(Column::Id)
+            .filter(Column::Slug.eq(slug.value()))
+            .lock_exclusive()
+            .into_tuple()
+            .one(self.txn.as_ref())
+            .await?;
+
+        match id {
+            Some(id) => self.load(id).await,
+            None => Ok(None),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod event_sourced_article_write_repository;
 pub mod sea_orm_article_write_repository;
 
+pub use event_sourced_article_write_repository::*;
 pub use sea_orm_article_write_repository::*;
@@ -1,53 +1,88 @@
 use async_trait::async_trait;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, InsertResult, QueryFilter, Set,
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, InsertResult, QueryFilter,
+    QuerySelect,
 };
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::adapters::outbound::persistence::entities::{ActiveModel, Column, Entity};
+use crate::adapters::outbound::persistence::event_store::SeaOrmEventStore;
+use crate::adapters::outbound::persistence::outbox::OutboxWriter;
 use crate::domain::article::aggregate::Article;
+use crate::domain::article::events::ARTICLE_AGGREGATE_TYPE;
 use crate::domain::article::ports::article_write_repository::ArticleWriteRepository;
 use crate::domain::article::value_objects::Slug;
 use crate::shared::errors::RhyonError;
+use crate::shared::events::{DomainEvent, EventEnvelope, EventStore};
 
+/// 绑定到工作单元事务的文章写仓储，提交与回滚由工作单元负责
 pub struct SeaOrmArticleWriteRepository {
-    db: DatabaseConnection,
+    txn: Arc<DatabaseTransaction>,
+    event_store: Arc<dyn EventStore>,
 }
 
 impl SeaOrmArticleWriteRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(txn: Arc<DatabaseTransaction>) -> Self {
+        Self {
+            event_store: Arc::new(SeaOrmEventStore::in_transaction(txn.clone())),
+            txn,
+        }
+    }
+
+    /// 为事件分配序号后写入事件存储和发件箱
+    async fn record_events(
+        &self,
+        aggregate_id: &Uuid,
+        events: &[Box<dyn DomainEvent>],
+    ) -> Result<(), RhyonError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let last_sequence = self
+            .event_store
+            .last_sequence(ARTICLE_AGGREGATE_TYPE, &aggregate_id.to_string())
+            .await?;
+        let envelopes = EventEnvelope::wrap_all(events, last_sequence + 1);
+
+        self.event_store.append(&envelopes).await?;
+        OutboxWriter::append(self.txn.as_ref(), &envelopes).await
     }
 }
 
 #[async_trait]
 impl ArticleWriteRepository for SeaOrmArticleWriteRepository {
-    async fn save(&self, article: Article) -> Result<Uuid, RhyonError> {
+    async fn save(&self, mut article: Article) -> Result<Uuid, RhyonError> {
+        let events = article.get_uncommitted_events();
         let active_model: ActiveModel = article.into();
-        let result: InsertResult<ActiveModel> = Entity::insert(active_model).exec(&self.db).await?;
+
+        // 文章、事件存储与发件箱在同一事务中提交
+        let txn = self.txn.as_ref();
+        let result: InsertResult<ActiveModel> = Entity::insert(active_model).exec(txn).await?;
+        self.record_events(&result.last_insert_id, &events).await?;
+
         Ok(result.last_insert_id)
     }
 
-    async fn update(&self, article: Article) -> Result<(), RhyonError> {
-        if article.id().is_none() {
-            return Err(RhyonError::Domain("无法更新没有ID的文章".to_string()));
-        }
+    async fn update(&self, mut article: Article) -> Result<(), RhyonError> {
+        let id = *article.id().value();
+        let events = article.get_uncommitted_events();
+        let active_model: ActiveModel = article.into();
 
-        let id = *article
-            .id()
-            .ok_or_else(|| RhyonError::Domain("无法更新没有ID的文章".to_string()))?
-            .value();
-        let mut active_model: ActiveModel = article.into();
-        active_model.id = Set(id);
+        let txn = self.txn.as_ref();
+        active_model.update(txn).await?;
+        self.record_events(&id, &events).await?;
 
-        active_model.update(&self.db).await?;
         Ok(())
     }
 
     async fn find_for_update(&self, slug: &Slug) -> Result<Option<Article>, RhyonError> {
+        // 锁定该行直到工作单元结束，避免并发修改相互覆盖
         let article = Entity::find()
             .filter(Column::Slug.eq(slug.value()))
-            .one(&self.db)
+            .lock_exclusive()
+            .one(self.txn.as_ref())
             .await?
             .map(|model| {
                 let a: Result<Article, RhyonError> = model.into();
@@ -0,0 +1,22 @@
+use crate::load_config;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookConfig {
+    /// 没有待投递记录时的轮询间隔
+    pub poll_interval_ms: u64,
+    /// 每批最多投递的记录数
+    pub batch_size: u64,
+    /// 单次HTTP请求的超时时间
+    pub timeout_ms: u64,
+    /// 最大投递次数，超过后标记为失败
+    pub max_attempts: i32,
+    /// 重试退避的基础时长（按2的指数增长）
+    pub retry_backoff_ms: u64,
+    /// 重试退避的上限
+    pub max_backoff_ms: u64,
+}
+
+pub fn load_webhook_config() -> WebhookConfig {
+    load_config!(webhook, WebhookConfig)
+}
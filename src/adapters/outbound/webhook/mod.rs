@@ -0,0 +1,5 @@
+pub mod config;
+pub mod signature;
+pub mod webhook_delivery_worker;
+
+pub use webhook_delivery_worker::*;
@@ -0,0 +1,17 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// 携带请求体签名的请求头
+pub const SIGNATURE_HEADER: &str = "X-Rhyon-Signature";
+/// 事件类型请求头
+pub const EVENT_HEADER: &str = "X-Rhyon-Event";
+/// 投递ID请求头，重新投递时会变化
+pub const DELIVERY_HEADER: &str = "X-Rhyon-Delivery";
+
+/// 计算请求体的HMAC-SHA256签名，格式为`sha256=<hex>`
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
@@ -0,0 +1,348 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use reqwest::Client;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+
+use super::config::WebhookConfig;
+use super::signature::{DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER, sign};
+use crate::domain::webhook::delivery::WebhookDelivery;
+use crate::domain::webhook::ports::{WebhookDeliveryRepository, WebhookSubscriptionRepository};
+use crate::shared::errors::RhyonError;
+use crate::shared::tasks::{Backoff, BatchJob, spawn_poll_loop};
+
+/// 响应体在错误信息中最多保留的字符数
+const MAX_ERROR_BODY_CHARS: usize = 500;
+
+/// Webhook投递工作者：后台轮询待投递记录，签名后发送并记录结果
+pub struct WebhookDeliveryWorker {
+    subscriptions: Arc<dyn WebhookSubscriptionRepository>,
+    deliveries: Arc<dyn WebhookDeliveryRepository>,
+    client: Client,
+    config: WebhookConfig,
+}
+
+impl WebhookDeliveryWorker {
+    pub fn new(
+        subscriptions: Arc<dyn WebhookSubscriptionRepository>,
+        deliveries: Arc<dyn WebhookDeliveryRepository>,
+        config: WebhookConfig,
+    ) -> Result<Self, RhyonError> {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_millis(config.timeout_ms))
+            .user_agent(concat!("rhyon-webhook/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| RhyonError::ServerError(e.to_string()))?;
+
+        Ok(Self {
+            subscriptions,
+            deliveries,
+            client,
+            config,
+        })
+    }
+
+    /// 在后台任务中持续运行
+    pub fn spawn(self) -> JoinHandle<()> {
+        let interval = std::time::Duration::from_millis(self.config.poll_interval_ms);
+        let batch_size = self.config.batch_size;
+        spawn_poll_loop(self, interval, batch_size)
+    }
+
+    /// 逐条领取并投递到期的记录，最多处理一批，返回处理的记录数
+    pub async fn deliver_batch(&self) -> Result<usize, RhyonError> {
+        // 每条记录单独领取，租约只需覆盖一次请求的最长耗时
+        let lease = Duration::milliseconds(self.config.timeout_ms as i64 * 2);
+
+        let mut count = 0;
+        while (count as u64) < self.config.batch_size {
+            let Some(mut delivery) = self.deliveries.claim_next(lease).await? else {
+                break;
+            };
+            self.deliver(&mut delivery).await;
+            self.deliveries.update(&delivery).await?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    async fn deliver(&self, delivery: &mut WebhookDelivery) {
+        let subscription = match self
+            .subscriptions
+            .find_by_id(delivery.subscription_id)
+            .await
+        {
+            Ok(Some(subscription)) if subscription.is_active() => subscription,
+            Ok(_) => {
+                delivery.record_failure(None, "订阅已删除或已停用".to_string(), None);
+                return;
+            }
+            Err(e) => {
+                let retry = self.retry_after(delivery);
+                delivery.record_failure(None, e.to_string(), retry);
+                return;
+            }
+        };
+
+        let body = delivery.payload.to_string();
+        let result = self
+            .client
+            .post(subscription.url())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, &delivery.event_type)
+            .header(DELIVERY_HEADER, delivery.id.to_string())
+            .header(
+                SIGNATURE_HEADER,
+                sign(subscription.secret(), body.as_bytes()),
+            )
+            .body(body)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => {
+                delivery.record_success(response.status().as_u16());
+            }
+            Ok(response) => {
+                let status = response.status().as_u16();
+                let text = response.text().await.unwrap_or_default();
+                let error = format!(
+                    "HTTP {}: {}",
+                    status,
+                    text.chars().take(MAX_ERROR_BODY_CHARS).collect::<String>()
+                );
+                let retry = self.retry_after(delivery);
+                delivery.record_failure(Some(status), error, retry);
+            }
+            Err(e) => {
+                let retry = self.retry_after(delivery);
+                delivery.record_failure(None, e.to_string(), retry);
+            }
+        }
+
+        if let Some(error) = &delivery.last_error {
+            tracing::warn!(
+                "⚠️ Webhook投递失败（{}，第{}次）: {}",
+                subscription.url(),
+                delivery.attempts,
+                error
+            );
+        }
+    }
+
+    /// 还有重试机会时返回退避时长
+    fn retry_after(&self, delivery: &WebhookDelivery) -> Option<Duration> {
+        let attempts = delivery.attempts + 1;
+        if attempts >= self.config.max_attempts {
+            return None;
+        }
+
+        let backoff = Backoff::new(self.config.retry_backoff_ms, self.config.max_backoff_ms);
+        Duration::from_std(backoff.delay(attempts as u32)).ok()
+    }
+}
+
+#[async_trait]
+impl BatchJob for WebhookDeliveryWorker {
+    fn name(&self) -> &'static str {
+        "Webhook投递"
+    }
+
+    async fn run_batch(&self) -> Result<usize, RhyonError> {
+        self.deliver_batch().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::database::testing::sqlite_test_db;
+    use crate::adapters::outbound::persistence::webhook::{
+        SeaOrmWebhookDeliveryRepository, SeaOrmWebhookSubscriptionRepository,
+    };
+    use crate::application::services::WebhookDispatcher;
+    use crate::domain::article::events::ArticlePublishedEvent;
+    use crate::domain::webhook::delivery::DeliveryStatus;
+    use crate::domain::webhook::subscription::WebhookSubscription;
+    use crate::shared::events::EventHandler;
+    use axum::Router;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use chrono::Utc;
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    const SECRET: &str = "0123456789abcdef";
+    const RETRY_BACKOFF_MS: u64 = 100;
+
+    async fn wait_for_retry() {
+        tokio::time::sleep(std::time::Duration::from_millis(RETRY_BACKOFF_MS * 2)).await;
+    }
+
+    /// 本地Webhook接收方：按顺序返回预设的状态码（用完后返回200），并记录收到的请求
+    #[derive(Clone, Default)]
+    struct Receiver {
+        statuses: Arc<Mutex<VecDeque<StatusCode>>>,
+        requests: Arc<Mutex<Vec<(HeaderMap, String)>>>,
+    }
+
+    impl Receiver {
+        async fn start(statuses: impl IntoIterator<Item = StatusCode>) -> (Self, String) {
+            let receiver = Self {
+                statuses: Arc::new(Mutex::new(statuses.into_iter().collect())),
+                ..Self::default()
+            };
+            let state = receiver.clone();
+            let app = Router::new().route(
+                "/hook",
+                post(move |headers: HeaderMap, body: String| async move {
+                    state.requests.lock().unwrap().push((headers, body));
+                    let status = state.statuses.lock().unwrap().pop_front();
+                    status.unwrap_or(StatusCode::OK)
+                }),
+            );
+
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/hook", listener.local_addr().unwrap());
+            tokio::spawn(async move { axum::serve(listener, app).await });
+            (receiver, url)
+        }
+
+        fn requests(&self) -> Vec<(HeaderMap, String)> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    struct Fixture {
+        worker: WebhookDeliveryWorker,
+        deliveries: Arc<SeaOrmWebhookDeliveryRepository>,
+        subscription_id: Uuid,
+    }
+
+    /// 订阅接收方并分发一个事件，生成一条待投递记录
+    async fn fixture(url: String, max_attempts: i32) -> Fixture {
+        let db = sqlite_test_db().await;
+        let subscriptions = Arc::new(SeaOrmWebhookSubscriptionRepository::new(db.clone()));
+        let deliveries = Arc::new(SeaOrmWebhookDeliveryRepository::new(db));
+
+        let subscription = WebhookSubscription::create(
+            url,
+            vec![ArticlePublishedEvent::EVENT_TYPE.to_string()],
+            Some(SECRET.to_string()),
+        )
+        .unwrap();
+        subscriptions.save(&subscription).await.unwrap();
+
+        let event = ArticlePublishedEvent::new(Uuid::now_v7(), "hello".to_string(), Utc::now());
+        WebhookDispatcher::new(subscriptions.clone(), deliveries.clone())
+            .handle(&event)
+            .await
+            .unwrap();
+
+        let config = WebhookConfig {
+            poll_interval_ms: 10,
+            batch_size: 10,
+            timeout_ms: 2_000,
+            max_attempts,
+            retry_backoff_ms: RETRY_BACKOFF_MS,
+            max_backoff_ms: RETRY_BACKOFF_MS,
+        };
+        Fixture {
+            worker: WebhookDeliveryWorker::new(subscriptions, deliveries.clone(), config).unwrap(),
+            deliveries,
+            subscription_id: subscription.id(),
+        }
+    }
+
+    impl Fixture {
+        async fn delivery(&self) -> WebhookDelivery {
+            let mut deliveries = self
+                .deliveries
+                .find_by_subscription(self.subscription_id, 10)
+                .await
+                .unwrap();
+            assert_eq!(deliveries.len(), 1);
+            deliveries.remove(0)
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_delivery_is_retried_with_the_same_signed_payload() {
+        let (receiver, url) = Receiver::start([StatusCode::INTERNAL_SERVER_ERROR]).await;
+        let fixture = fixture(url, 5).await;
+
+        assert_eq!(fixture.worker.deliver_batch().await.unwrap(), 1);
+        let delivery = fixture.delivery().await;
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert_eq!(delivery.attempts, 1);
+        assert_eq!(delivery.response_status, Some(500));
+
+        // 退避期间不会再次投递
+        assert_eq!(fixture.worker.deliver_batch().await.unwrap(), 0);
+        wait_for_retry().await;
+        assert_eq!(fixture.worker.deliver_batch().await.unwrap(), 1);
+        let delivery = fixture.delivery().await;
+        assert_eq!(delivery.status, DeliveryStatus::Succeeded);
+        assert_eq!(delivery.attempts, 2);
+        assert_eq!(fixture.worker.deliver_batch().await.unwrap(), 0);
+
+        let requests = receiver.requests();
+        assert_eq!(requests.len(), 2);
+        for (headers, body) in &requests {
+            assert_eq!(headers[SIGNATURE_HEADER], sign(SECRET, body.as_bytes()));
+            assert_eq!(headers[DELIVERY_HEADER], delivery.id.to_string());
+            assert_eq!(headers[EVENT_HEADER], ArticlePublishedEvent::EVENT_TYPE);
+        }
+
+        // 重试时事件ID保持不变，接收方可据此去重
+        let event_ids: Vec<serde_json::Value> = requests
+            .iter()
+            .map(|(_, body)| {
+                serde_json::from_str::<serde_json::Value>(body).unwrap()["event_id"].clone()
+            })
+            .collect();
+        assert!(event_ids[0].is_string());
+        assert_eq!(event_ids[0], event_ids[1]);
+    }
+
+    #[tokio::test]
+    async fn delivery_fails_after_max_attempts() {
+        let (receiver, url) = Receiver::start([StatusCode::BAD_GATEWAY; 3]).await;
+        let fixture = fixture(url, 2).await;
+
+        fixture.worker.deliver_batch().await.unwrap();
+        wait_for_retry().await;
+        fixture.worker.deliver_batch().await.unwrap();
+        let delivery = fixture.delivery().await;
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert_eq!(delivery.attempts, 2);
+        assert!(delivery.last_error.unwrap().starts_with("HTTP 502"));
+
+        assert_eq!(fixture.worker.deliver_batch().await.unwrap(), 0);
+        assert_eq!(receiver.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn leased_delivery_is_not_claimed_again_until_the_lease_expires() {
+        let (_, url) = Receiver::start([]).await;
+        let fixture = fixture(url, 5).await;
+        let lease = Duration::milliseconds(200);
+
+        let claimed = fixture.deliveries.claim_next(lease).await.unwrap();
+        assert!(claimed.is_some());
+        assert!(
+            fixture
+                .deliveries
+                .claim_next(lease)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        let reclaimed = fixture.deliveries.claim_next(lease).await.unwrap();
+        assert_eq!(reclaimed.map(|d| d.id), claimed.map(|d| d.id));
+    }
+}
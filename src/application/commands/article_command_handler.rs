@@ -2,12 +2,11 @@ use async_trait::async_trait;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::application::ports::UnitOfWork;
 use crate::domain::article::aggregate::Article;
 use crate::domain::article::commands::{CreateArticleCommand, PublishArticleCommand};
-use crate::domain::article::ports::article_write_repository::ArticleWriteRepository;
-use crate::domain::article::value_objects::{Content, Slug, Summary, Title};
-use crate::shared::errors::RhyonError;
-use crate::shared::events::EventPublisher;
+use crate::domain::article::value_objects::{Author, Content, Slug, Summary, Tag, Title};
+use crate::shared::errors::{RhyonError, Violations};
 
 /// 文章命令处理器trait
 #[async_trait]
@@ -17,82 +16,257 @@ pub trait ArticleCommandHandler: Send + Sync {
 }
 
 /// 文章命令处理器实现
+///
+/// 每个命令在一个工作单元中执行，领域事件由写仓储随聚合一起写入发件箱，
+/// 处理器不直接发布事件
 pub struct ArticleCommandHandlerImpl {
-    write_repository: Arc<dyn ArticleWriteRepository>,
-    event_publisher: Arc<dyn EventPublisher>,
+    unit_of_work: Arc<dyn UnitOfWork>,
 }
 
 impl ArticleCommandHandlerImpl {
-    pub fn new(
-        write_repository: Arc<dyn ArticleWriteRepository>,
-        event_publisher: Arc<dyn EventPublisher>,
-    ) -> Self {
-        Self {
-            write_repository,
-            event_publisher,
-        }
+    pub fn new(unit_of_work: Arc<dyn UnitOfWork>) -> Self {
+        Self { unit_of_work }
     }
 }
 
 #[async_trait]
 impl ArticleCommandHandler for ArticleCommandHandlerImpl {
     async fn handle_create(&self, command: CreateArticleCommand) -> Result<Uuid, RhyonError> {
-        // 创建值对象
-        let title = Title::new(command.title)?;
+        // 创建值对象，一次收集全部字段的校验错误
+        let mut violations = Violations::new();
+        let title = violations.check(Title::new(command.title));
         let content = Content::new(command.content);
 
         // 处理可选摘要
-        let summary = match command.summary {
-            Some(summary_text) => Some(Summary::new(summary_text)?),
-            None => None,
-        };
+        let summary = violations.check(command.summary.map(Summary::new).transpose());
+        let author = violations.check(command.author.map(Author::new).transpose());
+        let tags = violations.check(Tag::parse_all(command.tags));
 
-        // 处理slug（如果未提供则从标题生成）
+        // 处理slug（如果未提供则从标题生成，标题无效时不再生成）
         let slug = match command.slug {
-            Some(slug_str) => Slug::new(slug_str)?,
-            None => Slug::from_title(title.value())?,
+            Some(slug_str) => violations.check(Slug::new(slug_str)),
+            None => title
+                .as_ref()
+                .and_then(|title| violations.check(Slug::from_title(title.value()))),
         };
 
-        // 创建文章聚合根
-        let mut article = Article::create(title, slug, summary, content)?;
+        let (title, summary, slug, author, tags) =
+            violations.finish(|| Some((title?, summary?, slug?, author?, tags?)))?;
 
-        // 保存到仓储（会设置数据库ID）
-        let id = self.write_repository.save(article.clone()).await?;
-        article.set_id(id);
-
-        // 发布领域事件
-        let events = article.get_uncommitted_events();
-        for event in events {
-            self.event_publisher.publish(event).await?;
-        }
-        article.mark_events_as_committed();
+        // 创建文章聚合根
+        let article = Article::create(title, slug, summary, content, author, tags)?;
 
-        Ok(id)
+        // 保存到仓储（文章与领域事件在同一事务中持久化）
+        self.unit_of_work
+            .run(move |repositories| {
+                Box::pin(async move { repositories.articles().save(article).await })
+            })
+            .await
     }
 
     async fn handle_publish(&self, command: PublishArticleCommand) -> Result<(), RhyonError> {
         // 创建slug值对象
         let slug_obj = Slug::new(command.slug)?;
 
-        // 查找文章
-        let mut article = self
-            .write_repository
-            .find_for_update(&slug_obj)
-            .await?
-            .ok_or_else(|| RhyonError::NotFound)?;
+        // 查找、发布和保存在同一工作单元中完成
+        self.unit_of_work
+            .run(move |repositories| {
+                Box::pin(async move {
+                    let articles = repositories.articles();
+
+                    // 查找文章
+                    let mut article = articles
+                        .find_for_update(&slug_obj)
+                        .await?
+                        .ok_or_else(|| RhyonError::NotFound)?;
+
+                    // 执行发布操作
+                    article.publish()?;
+
+                    // 更新文章状态（连同发布事件一起持久化）
+                    articles.update(article).await
+                })
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::config::{
+        PersistenceBackend, PersistenceConfig, PersistenceMode,
+    };
+    use crate::adapters::outbound::persistence::database::testing::{
+        sqlite_test_db, test_databases,
+    };
+    use crate::adapters::outbound::persistence::entities::{
+        article, article_snapshot, domain_event, outbox,
+    };
+    use crate::adapters::outbound::persistence::unit_of_work::SeaOrmUnitOfWork;
+    use crate::domain::article::events::register_article_events;
+    use crate::shared::events::{EventEnvelope, EventRegistry};
+    use sea_orm::{DatabaseConnection, EntityTrait, QueryOrder};
+
+    fn handler(db: &DatabaseConnection, mode: PersistenceMode) -> ArticleCommandHandlerImpl {
+        handler_with_snapshots(db, mode, 0)
+    }
+
+    fn handler_with_snapshots(
+        db: &DatabaseConnection,
+        mode: PersistenceMode,
+        snapshot_every: u64,
+    ) -> ArticleCommandHandlerImpl {
+        let config = PersistenceConfig {
+            backend: PersistenceBackend::Database,
+            mode,
+            snapshot_every,
+        };
+        let registry = Arc::new(register_article_events(EventRegistry::new()));
+        let unit_of_work = Arc::new(SeaOrmUnitOfWork::new(db.clone(), registry, &config));
+        ArticleCommandHandlerImpl::new(unit_of_work)
+    }
+
+    fn command(title: &str, slug: Option<&str>) -> CreateArticleCommand {
+        CreateArticleCommand {
+            title: title.to_string(),
+            slug: slug.map(str::to_string),
+            summary: None,
+            content: "body".to_string(),
+            author: None,
+            tags: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn events_carry_the_id_of_the_persisted_article() {
+        for mode in [PersistenceMode::Crud, PersistenceMode::EventSourced] {
+            for db in test_databases().await {
+                let handler = handler(&db, mode);
+                let id = handler.handle_create(command("Hello", None)).await.unwrap();
+
+                let row = article::Entity::find().one(&db).await.unwrap().unwrap();
+                assert_eq!(row.id, id, "{:?}", mode);
+
+                let outbox = outbox::Entity::find().all(&db).await.unwrap();
+                assert_eq!(outbox.len(), 1, "{:?}", mode);
+                assert_eq!(outbox[0].aggregate_id, id.to_string());
+                let envelope: EventEnvelope =
+                    serde_json::from_value(outbox[0].envelope.clone()).unwrap();
+                assert_eq!(envelope.payload["article_id"], id.to_string());
+
+                let stored = domain_event::Entity::find().all(&db).await.unwrap();
+                assert_eq!(stored.len(), 1, "{:?}", mode);
+                assert_eq!(stored[0].aggregate_id, id.to_string());
+                assert_eq!(stored[0].event_id, outbox[0].id);
+                // 命令产生的事件以命令为起因，并以命令开启关联链
+                assert!(stored[0].causation_id.is_some());
+                assert_eq!(stored[0].causation_id, stored[0].correlation_id);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn all_field_violations_are_reported_together() {
+        let db = sqlite_test_db().await;
+        let handler = handler(&db, PersistenceMode::Crud);
+        let command = CreateArticleCommand {
+            summary: Some("s".repeat(1_000)),
+            tags: vec!["rust".to_string(), String::new(), "标".repeat(51)],
+            ..command("", Some("  "))
+        };
+
+        let Err(RhyonError::Invalid(violations)) = handler.handle_create(command).await else {
+            panic!("expected field violations");
+        };
+        let mut fields: Vec<_> = violations
+            .iter()
+            .map(|violation| (violation.field.as_str(), violation.code))
+            .collect();
+        fields.sort();
+        assert_eq!(
+            fields,
+            [
+                ("slug", "required"),
+                ("summary", "too_long"),
+                ("tags[1]", "required"),
+                ("tags[2]", "too_long"),
+                ("title", "required")
+            ]
+        );
+        assert!(article::Entity::find().one(&db).await.unwrap().is_none());
+    }
+
+    async fn event_types(db: &DatabaseConnection) -> Vec<String> {
+        domain_event::Entity::find()
+            .order_by_asc(domain_event::Column::Position)
+            .all(db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|event| event.event_type)
+            .collect()
+    }
+
+    async fn publish(handler: &ArticleCommandHandlerImpl, slug: &str) -> Result<(), RhyonError> {
+        handler
+            .handle_publish(PublishArticleCommand::new(slug.to_string()))
+            .await
+    }
+
+    #[tokio::test]
+    async fn publishing_loads_the_article_in_every_mode() {
+        let modes = [
+            (PersistenceMode::Crud, 0),
+            (PersistenceMode::EventSourced, 0),
+            // 创建时保存快照，发布时从快照加载
+            (PersistenceMode::EventSourced, 1),
+        ];
+        for (mode, snapshot_every) in modes {
+            for db in test_databases().await {
+                let handler = handler_with_snapshots(&db, mode, snapshot_every);
+                handler.handle_create(command("Hello", None)).await.unwrap();
 
-        // 执行发布操作
-        article.publish()?;
+                publish(&handler, "hello").await.unwrap();
 
-        // 更新文章状态
-        self.write_repository.update(article.clone()).await?;
+                let row = article::Entity::find().one(&db).await.unwrap().unwrap();
+                assert_eq!(row.status, "published", "{:?}", mode);
+                assert!(row.published_at.is_some());
+                assert_eq!(
+                    event_types(&db).await,
+                    ["article.created", "article.published"],
+                    "{:?}",
+                    mode
+                );
 
-        // 发布领域事件
-        let events = article.get_uncommitted_events();
-        for event in events {
-            self.event_publisher.publish(event).await?;
+                // 重复发布违反领域规则
+                assert!(matches!(
+                    publish(&handler, "hello").await,
+                    Err(RhyonError::Conflict(_))
+                ));
+                assert!(matches!(
+                    publish(&handler, "missing").await,
+                    Err(RhyonError::NotFound)
+                ));
+            }
         }
+    }
+
+    #[tokio::test]
+    async fn article_without_event_stream_is_bootstrapped_from_its_row() {
+        for db in test_databases().await {
+            let handler = handler(&db, PersistenceMode::EventSourced);
+            handler.handle_create(command("Hello", None)).await.unwrap();
+            // 模拟启用事件溯源之前创建的文章
+            domain_event::Entity::delete_many().exec(&db).await.unwrap();
 
-        Ok(())
+            publish(&handler, "hello").await.unwrap();
+
+            let row = article::Entity::find().one(&db).await.unwrap().unwrap();
+            assert_eq!(row.status, "published");
+            assert_eq!(event_types(&db).await, ["article.published"]);
+            let snapshot = article_snapshot::Entity::find().one(&db).await.unwrap();
+            assert_eq!(snapshot.map(|snapshot| snapshot.version), Some(0));
+        }
     }
 }
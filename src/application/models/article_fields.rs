@@ -0,0 +1,146 @@
+use crate::shared::errors::RhyonError;
+use crate::shared::i18n::Message;
+
+/// 文章可选择的字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArticleField {
+    Id,
+    Title,
+    Slug,
+    Summary,
+    Content,
+    Status,
+    PublishedAt,
+    CreatedAt,
+    UpdatedAt,
+    ReadingTime,
+    CommentCount,
+    /// 关联数据，需通过`include=author`显式请求
+    AuthorName,
+    /// 关联数据，需通过`include=tags`显式请求
+    Tags,
+}
+
+impl ArticleField {
+    pub const ALL: [ArticleField; 13] = [
+        ArticleField::Id,
+        ArticleField::Title,
+        ArticleField::Slug,
+        ArticleField::Summary,
+        ArticleField::Content,
+        ArticleField::Status,
+        ArticleField::PublishedAt,
+        ArticleField::CreatedAt,
+        ArticleField::UpdatedAt,
+        ArticleField::ReadingTime,
+        ArticleField::CommentCount,
+        ArticleField::AuthorName,
+        ArticleField::Tags,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArticleField::Id => "id",
+            ArticleField::Title => "title",
+            ArticleField::Slug => "slug",
+            ArticleField::Summary => "summary",
+            ArticleField::Content => "content",
+            ArticleField::Status => "status",
+            ArticleField::PublishedAt => "published_at",
+            ArticleField::CreatedAt => "created_at",
+            ArticleField::UpdatedAt => "updated_at",
+            ArticleField::ReadingTime => "reading_time",
+            ArticleField::CommentCount => "comment_count",
+            ArticleField::AuthorName => "author_name",
+            ArticleField::Tags => "tags",
+        }
+    }
+
+    /// 是否为需要显式请求的关联数据
+    pub fn is_include(&self) -> bool {
+        matches!(self, ArticleField::AuthorName | ArticleField::Tags)
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.as_str() == name.trim())
+    }
+}
+
+/// 稀疏字段集：查询时只读取并返回其中的字段
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArticleFieldSet {
+    fields: Vec<ArticleField>,
+}
+
+impl ArticleFieldSet {
+    /// 全部基本字段（详情查询默认，不包含关联数据）
+    pub fn all() -> Self {
+        Self {
+            fields: ArticleField::ALL
+                .into_iter()
+                .filter(|field| !field.is_include())
+                .collect(),
+        }
+    }
+
+    /// 列表查询默认字段（不包含content和关联数据）
+    pub fn list_default() -> Self {
+        Self {
+            fields: ArticleField::ALL
+                .into_iter()
+                .filter(|field| *field != ArticleField::Content && !field.is_include())
+                .collect(),
+        }
+    }
+
+    /// 只包含指定字段
+    pub fn only(fields: impl IntoIterator<Item = ArticleField>) -> Self {
+        fields
+            .into_iter()
+            .fold(Self { fields: Vec::new() }, Self::with)
+    }
+
+    /// 解析逗号分隔的字段列表，如 "title,slug,published_at"
+    pub fn parse(fields: &str) -> Result<Self, RhyonError> {
+        let mut set = Self { fields: Vec::new() };
+
+        for name in fields.split(',').filter(|name| !name.trim().is_empty()) {
+            let field = ArticleField::parse(name).ok_or_else(|| {
+                RhyonError::Validation(
+                    Message::new("unsupported-field").with_arg("name", name.trim()),
+                )
+            })?;
+            set = set.with(field);
+        }
+
+        if set.fields.is_empty() {
+            return Err(RhyonError::Validation(Message::new("empty-field-list")));
+        }
+
+        Ok(set)
+    }
+
+    /// 追加字段（已存在时忽略）
+    pub fn with(mut self, field: ArticleField) -> Self {
+        if !self.contains(field) {
+            self.fields.push(field);
+        }
+        self
+    }
+
+    pub fn contains(&self, field: ArticleField) -> bool {
+        self.fields.contains(&field)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ArticleField> + '_ {
+        self.fields.iter().copied()
+    }
+}
+
+impl Default for ArticleFieldSet {
+    fn default() -> Self {
+        Self::all()
+    }
+}
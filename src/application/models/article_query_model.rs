@@ -1,8 +1,9 @@
+use crate::shared::query::{FieldAccessor, QueryValue};
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// 文章查询模型（专门用于读操作优化）
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArticleQueryModel {
     pub id: String,
     pub title: String,
@@ -13,6 +14,12 @@ pub struct ArticleQueryModel {
     pub published_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// 预计阅读时长（分钟）
+    pub reading_time: u32,
+    pub comment_count: u32,
+    pub author_name: Option<String>,
+    /// 未请求`include=tags`时为None
+    pub tags: Option<Vec<String>>,
 }
 
 impl ArticleQueryModel {
@@ -20,53 +27,44 @@ impl ArticleQueryModel {
     pub fn builder() -> ArticleQueryModelBuilder {
         ArticleQueryModelBuilder::new()
     }
+}
+
+/// 按字段名暴露查询模型属性（用于生成分页游标等场景）
+impl FieldAccessor for ArticleQueryModel {
+    fn field_value(&self, field: &str) -> Option<QueryValue> {
+        let value = match field {
+            "id" => self.id.as_str().into(),
+            "title" => self.title.as_str().into(),
+            "slug" => self.slug.as_str().into(),
+            "summary" => self.summary.as_str().into(),
+            "content" => self
+                .content
+                .as_deref()
+                .map_or(QueryValue::Null, QueryValue::from),
+            "status" => self.status.as_str().into(),
+            "created_at" => self.created_at.into(),
+            "updated_at" => self.updated_at.into(),
+            "published_at" => self.published_at.map_or(QueryValue::Null, QueryValue::from),
+            "reading_time" => QueryValue::Integer(self.reading_time as i64),
+            "comment_count" => QueryValue::Integer(self.comment_count as i64),
+            "author_name" => self
+                .author_name
+                .as_deref()
+                .map_or(QueryValue::Null, QueryValue::from),
+            _ => return None,
+        };
+        Some(value)
+    }
 
-    /// 创建列表视图模型（不包含内容）
-    pub fn for_list(
-        id: String,
-        title: String,
-        slug: String,
-        summary: String,
-        status: String,
-        published_at: Option<DateTime<Utc>>,
-        created_at: DateTime<Utc>,
-        updated_at: DateTime<Utc>,
-    ) -> Self {
-        Self::builder()
-            .id(id)
-            .title(title)
-            .slug(slug)
-            .summary(summary)
-            .status(status)
-            .published_at(published_at)
-            .created_at(created_at)
-            .updated_at(updated_at)
-            .build()
-    }
-
-    /// 创建详情视图模型（包含完整内容）
-    pub fn for_detail(
-        id: String,
-        title: String,
-        slug: String,
-        summary: String,
-        content: String,
-        status: String,
-        published_at: Option<DateTime<Utc>>,
-        created_at: DateTime<Utc>,
-        updated_at: DateTime<Utc>,
-    ) -> Self {
-        Self::builder()
-            .id(id)
-            .title(title)
-            .slug(slug)
-            .summary(summary)
-            .content(content)
-            .status(status)
-            .published_at(published_at)
-            .created_at(created_at)
-            .updated_at(updated_at)
-            .build()
+    fn field_elements(&self, field: &str) -> Option<Option<Vec<QueryValue>>> {
+        match field {
+            "tags" => Some(
+                self.tags
+                    .as_ref()
+                    .map(|tags| tags.iter().map(|tag| tag.as_str().into()).collect()),
+            ),
+            _ => None,
+        }
     }
 }
 
@@ -82,6 +80,10 @@ pub struct ArticleQueryModelBuilder {
     published_at: Option<Option<DateTime<Utc>>>,
     created_at: Option<DateTime<Utc>>,
     updated_at: Option<DateTime<Utc>>,
+    reading_time: Option<u32>,
+    comment_count: Option<u32>,
+    author_name: Option<String>,
+    tags: Option<Vec<String>>,
 }
 
 impl ArticleQueryModelBuilder {
@@ -134,6 +136,26 @@ impl ArticleQueryModelBuilder {
         self
     }
 
+    pub fn reading_time(mut self, reading_time: u32) -> Self {
+        self.reading_time = Some(reading_time);
+        self
+    }
+
+    pub fn comment_count(mut self, comment_count: u32) -> Self {
+        self.comment_count = Some(comment_count);
+        self
+    }
+
+    pub fn author_name(mut self, author_name: Option<String>) -> Self {
+        self.author_name = author_name;
+        self
+    }
+
+    pub fn tags(mut self, tags: Option<Vec<String>>) -> Self {
+        self.tags = tags;
+        self
+    }
+
     pub fn build(self) -> ArticleQueryModel {
         ArticleQueryModel {
             id: self.id.expect("id is required"),
@@ -145,6 +167,10 @@ impl ArticleQueryModelBuilder {
             published_at: self.published_at.unwrap_or(None),
             created_at: self.created_at.expect("created_at is required"),
             updated_at: self.updated_at.expect("updated_at is required"),
+            reading_time: self.reading_time.unwrap_or_default(),
+            comment_count: self.comment_count.unwrap_or_default(),
+            author_name: self.author_name,
+            tags: self.tags,
         }
     }
 }
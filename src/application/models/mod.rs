@@ -1,3 +1,9 @@
+pub mod article_fields;
 pub mod article_query_model;
+pub mod reading_time;
+pub mod tag_query_model;
 
+pub use article_fields::*;
 pub use article_query_model::*;
+pub use reading_time::*;
+pub use tag_query_model::*;
@@ -0,0 +1,31 @@
+/// 每分钟阅读的词数（中日韩文字按单字计）
+const WORDS_PER_MINUTE: usize = 250;
+
+/// 估算阅读时长（分钟），至少为1分钟
+pub fn estimate_reading_time(content: &str) -> u32 {
+    let mut words: usize = 0;
+    let mut in_word = false;
+
+    for c in content.chars() {
+        if is_cjk(c) {
+            words += 1;
+            in_word = false;
+        } else if c.is_alphanumeric() {
+            if !in_word {
+                words += 1;
+            }
+            in_word = true;
+        } else {
+            in_word = false;
+        }
+    }
+
+    words.div_ceil(WORDS_PER_MINUTE).max(1) as u32
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF
+    )
+}
@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 标签查询模型：用于生成标签页的链接
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagQueryModel {
+    pub tag: String,
+    /// 带有该标签的文章中最近的更新时间
+    pub updated_at: DateTime<Utc>,
+}
@@ -1,10 +1,12 @@
-use crate::application::models::ArticleQueryModel;
+use crate::application::models::{ArticleFieldSet, ArticleQueryModel, TagQueryModel};
 use crate::domain::article::specifications::{ArticleSortSpec, ArticleSpec};
 use crate::shared::errors::RhyonError;
-use crate::shared::pagination::{QueryPage, QueryPagination};
+use crate::shared::pagination::{CursorPage, CursorPagination, QueryPage, QueryPagination};
 use async_trait::async_trait;
 
 /// 文章读仓储端口
+///
+/// 查询方法均接收稀疏字段集，实现应只读取所需的列
 #[async_trait]
 pub trait ArticleReadRepository: Send + Sync {
     /// 根据规约查找文章（支持复杂查询和分页）
@@ -13,8 +15,29 @@ pub trait ArticleReadRepository: Send + Sync {
         specification: ArticleSpec,
         sort: ArticleSortSpec,
         pagination: QueryPagination,
+        fields: ArticleFieldSet,
     ) -> Result<QueryPage<ArticleQueryModel>, RhyonError>;
 
+    /// 根据规约进行键集（游标）分页查询，避免深分页时的OFFSET与COUNT开销
+    async fn find_by_cursor(
+        &self,
+        specification: ArticleSpec,
+        sort: ArticleSortSpec,
+        pagination: CursorPagination,
+        fields: ArticleFieldSet,
+    ) -> Result<CursorPage<ArticleQueryModel>, RhyonError>;
+
+    /// 统计满足规约的文章数量
+    async fn count_by_specification(&self, specification: ArticleSpec) -> Result<u64, RhyonError>;
+
+    /// 满足规约的文章使用的全部标签及其最近更新时间，按标签名排序
+    async fn find_tags(&self, specification: ArticleSpec)
+    -> Result<Vec<TagQueryModel>, RhyonError>;
+
     /// 简单的slug查询（无需分页）
-    async fn find_by_slug(&self, slug: &str) -> Result<Option<ArticleQueryModel>, RhyonError>;
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+        fields: ArticleFieldSet,
+    ) -> Result<Option<ArticleQueryModel>, RhyonError>;
 }
@@ -1,3 +1,5 @@
 pub mod article_read_repository;
+pub mod unit_of_work;
 
 pub use article_read_repository::*;
+pub use unit_of_work::*;
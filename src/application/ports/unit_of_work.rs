@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::domain::article::ports::article_write_repository::ArticleWriteRepository;
+use crate::shared::errors::RhyonError;
+
+/// 工作单元中执行的异步操作
+pub type UnitOfWorkFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, RhyonError>> + Send + 'a>>;
+
+/// 工作单元内可用的仓储，全部绑定到同一个事务
+pub trait TransactionalRepositories: Send + Sync {
+    fn articles(&self) -> &dyn ArticleWriteRepository;
+}
+
+/// 一次进行中的工作单元
+#[async_trait]
+pub trait UnitOfWorkSession: TransactionalRepositories {
+    /// 提交工作单元中的全部修改
+    async fn commit(self: Box<Self>) -> Result<(), RhyonError>;
+
+    /// 放弃工作单元中的全部修改
+    async fn rollback(self: Box<Self>) -> Result<(), RhyonError>;
+}
+
+/// 工作单元端口：开启事务并提供绑定到该事务的仓储
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWorkSession>, RhyonError>;
+}
+
+impl dyn UnitOfWork {
+    /// 在一个工作单元中执行操作：成功时提交，失败时回滚
+    pub async fn run<T, F>(&self, work: F) -> Result<T, RhyonError>
+    where
+        T: Send,
+        F: for<'a> FnOnce(&'a dyn TransactionalRepositories) -> UnitOfWorkFuture<'a, T> + Send,
+    {
+        let session = self.begin().await?;
+
+        match work(session.as_ref()).await {
+            Ok(value) => {
+                session.commit().await?;
+                Ok(value)
+            }
+            Err(err) => {
+                if let Err(rollback_err) = session.rollback().await {
+                    tracing::warn!("⚠️ 工作单元回滚失败: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+}
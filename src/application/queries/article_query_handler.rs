@@ -1,11 +1,14 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 
-use crate::application::models::ArticleQueryModel;
+use crate::application::models::{ArticleQueryModel, TagQueryModel};
 use crate::application::ports::ArticleReadRepository;
-use crate::application::queries::{GetArticleBySlugQuery, GetArticlesQuery};
+use crate::application::queries::{
+    CountArticlesQuery, GetArticleBySlugQuery, GetArticlesByCursorQuery, GetArticlesQuery,
+    GetTagsQuery,
+};
 use crate::shared::errors::RhyonError;
-use crate::shared::pagination::QueryPage;
+use crate::shared::pagination::{CursorPage, QueryPage};
 
 /// 文章查询处理器trait
 #[async_trait]
@@ -15,6 +18,15 @@ pub trait ArticleQueryHandler: Send + Sync {
         query: GetArticlesQuery,
     ) -> Result<QueryPage<ArticleQueryModel>, RhyonError>;
 
+    async fn handle_get_articles_by_cursor(
+        &self,
+        query: GetArticlesByCursorQuery,
+    ) -> Result<CursorPage<ArticleQueryModel>, RhyonError>;
+
+    async fn handle_count_articles(&self, query: CountArticlesQuery) -> Result<u64, RhyonError>;
+
+    async fn handle_get_tags(&self, query: GetTagsQuery) -> Result<Vec<TagQueryModel>, RhyonError>;
+
     async fn handle_get_by_slug(
         &self,
         query: GetArticleBySlugQuery,
@@ -40,14 +52,45 @@ impl ArticleQueryHandler for ArticleQueryHandlerImpl {
     ) -> Result<QueryPage<ArticleQueryModel>, RhyonError> {
         // 使用新的规约系统执行查询
         self.read_repository
-            .find_by_specification(query.specification, query.sort, query.pagination)
+            .find_by_specification(
+                query.specification,
+                query.sort,
+                query.pagination,
+                query.fields,
+            )
+            .await
+    }
+
+    async fn handle_get_articles_by_cursor(
+        &self,
+        query: GetArticlesByCursorQuery,
+    ) -> Result<CursorPage<ArticleQueryModel>, RhyonError> {
+        self.read_repository
+            .find_by_cursor(
+                query.specification,
+                query.sort,
+                query.pagination,
+                query.fields,
+            )
             .await
     }
 
+    async fn handle_count_articles(&self, query: CountArticlesQuery) -> Result<u64, RhyonError> {
+        self.read_repository
+            .count_by_specification(query.specification)
+            .await
+    }
+
+    async fn handle_get_tags(&self, query: GetTagsQuery) -> Result<Vec<TagQueryModel>, RhyonError> {
+        self.read_repository.find_tags(query.specification).await
+    }
+
     async fn handle_get_by_slug(
         &self,
         query: GetArticleBySlugQuery,
     ) -> Result<Option<ArticleQueryModel>, RhyonError> {
-        self.read_repository.find_by_slug(&query.slug).await
+        self.read_repository
+            .find_by_slug(&query.slug, query.fields)
+            .await
     }
 }
@@ -0,0 +1,15 @@
+use crate::domain::article::specifications::ArticleSpec;
+
+/// 统计满足规约的文章数量，不读取文章内容
+#[derive(Debug)]
+pub struct CountArticlesQuery {
+    pub specification: ArticleSpec,
+}
+
+impl CountArticlesQuery {
+    pub fn new() -> Self {
+        Self {
+            specification: ArticleSpec::published(), // 默认只统计已发布的文章
+        }
+    }
+}
@@ -1,10 +1,21 @@
+use crate::application::models::ArticleFieldSet;
+
 #[derive(Debug)]
 pub struct GetArticleBySlugQuery {
     pub slug: String,
+    pub fields: ArticleFieldSet,
 }
 
 impl GetArticleBySlugQuery {
     pub fn new(slug: String) -> Self {
-        Self { slug }
+        Self {
+            slug,
+            fields: ArticleFieldSet::all(),
+        }
+    }
+
+    pub fn with_fields(mut self, fields: ArticleFieldSet) -> Self {
+        self.fields = fields;
+        self
     }
 }
@@ -0,0 +1,32 @@
+use crate::application::models::ArticleFieldSet;
+use crate::domain::article::specifications::{ArticleSortSpec, ArticleSpec};
+use crate::shared::pagination::CursorPagination;
+
+#[derive(Debug)]
+pub struct GetArticlesByCursorQuery {
+    pub specification: ArticleSpec,
+    pub sort: ArticleSortSpec,
+    pub pagination: CursorPagination,
+    pub fields: ArticleFieldSet,
+}
+
+impl GetArticlesByCursorQuery {
+    pub fn new(pagination: CursorPagination) -> Self {
+        Self {
+            specification: ArticleSpec::published(), // 默认只查询已发布的文章
+            sort: ArticleSortSpec::default(),
+            pagination,
+            fields: ArticleFieldSet::list_default(),
+        }
+    }
+
+    pub fn with_sort(mut self, sort: ArticleSortSpec) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn with_fields(mut self, fields: ArticleFieldSet) -> Self {
+        self.fields = fields;
+        self
+    }
+}
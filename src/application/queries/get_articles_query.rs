@@ -1,3 +1,4 @@
+use crate::application::models::ArticleFieldSet;
 use crate::domain::article::specifications::{ArticleSortSpec, ArticleSpec};
 use crate::shared::pagination::QueryPagination;
 
@@ -6,6 +7,7 @@ pub struct GetArticlesQuery {
     pub specification: ArticleSpec,
     pub sort: ArticleSortSpec,
     pub pagination: QueryPagination,
+    pub fields: ArticleFieldSet,
 }
 
 impl GetArticlesQuery {
@@ -14,6 +16,7 @@ impl GetArticlesQuery {
             specification: ArticleSpec::published(), // 默认只查询已发布的文章
             sort: ArticleSortSpec::default(),
             pagination,
+            fields: ArticleFieldSet::list_default(),
         }
     }
 
@@ -27,12 +30,8 @@ impl GetArticlesQuery {
         self
     }
 
-    pub fn with_status(self, status: Option<String>) -> Self {
-        let spec = match status.as_deref() {
-            Some("published") => ArticleSpec::published(),
-            Some("draft") => ArticleSpec::draft(),
-            _ => ArticleSpec::published(), // 默认已发布
-        };
-        self.with_specification(spec)
+    pub fn with_fields(mut self, fields: ArticleFieldSet) -> Self {
+        self.fields = fields;
+        self
     }
 }
@@ -0,0 +1,15 @@
+use crate::domain::article::specifications::ArticleSpec;
+
+/// 查询满足规约的文章使用的全部标签，按标签名排序
+#[derive(Debug)]
+pub struct GetTagsQuery {
+    pub specification: ArticleSpec,
+}
+
+impl GetTagsQuery {
+    pub fn new() -> Self {
+        Self {
+            specification: ArticleSpec::published(), // 默认只统计已发布的文章
+        }
+    }
+}
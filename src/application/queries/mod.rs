@@ -1,7 +1,13 @@
 pub mod article_query_handler;
+pub mod count_articles_query;
 pub mod get_article_by_slug_query;
+pub mod get_articles_by_cursor_query;
 pub mod get_articles_query;
+pub mod get_tags_query;
 
 pub use article_query_handler::*;
+pub use count_articles_query::*;
 pub use get_article_by_slug_query::*;
+pub use get_articles_by_cursor_query::*;
 pub use get_articles_query::*;
+pub use get_tags_query::*;
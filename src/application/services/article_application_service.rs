@@ -3,11 +3,14 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::application::commands::ArticleCommandHandler;
-use crate::application::models::ArticleQueryModel;
-use crate::application::queries::{ArticleQueryHandler, GetArticleBySlugQuery, GetArticlesQuery};
+use crate::application::models::{ArticleQueryModel, TagQueryModel};
+use crate::application::queries::{
+    ArticleQueryHandler, CountArticlesQuery, GetArticleBySlugQuery, GetArticlesByCursorQuery,
+    GetArticlesQuery, GetTagsQuery,
+};
 use crate::domain::article::commands::{CreateArticleCommand, PublishArticleCommand};
 use crate::shared::errors::RhyonError;
-use crate::shared::pagination::QueryPage;
+use crate::shared::pagination::{CursorPage, QueryPage};
 
 /// 文章应用服务（门面模式）
 #[async_trait]
@@ -21,6 +24,12 @@ pub trait ArticleApplicationService: Send + Sync {
         &self,
         query: GetArticlesQuery,
     ) -> Result<QueryPage<ArticleQueryModel>, RhyonError>;
+    async fn get_articles_by_cursor(
+        &self,
+        query: GetArticlesByCursorQuery,
+    ) -> Result<CursorPage<ArticleQueryModel>, RhyonError>;
+    async fn count_articles(&self, query: CountArticlesQuery) -> Result<u64, RhyonError>;
+    async fn get_tags(&self, query: GetTagsQuery) -> Result<Vec<TagQueryModel>, RhyonError>;
     async fn get_article_by_slug(
         &self,
         query: GetArticleBySlugQuery,
@@ -62,6 +71,23 @@ impl ArticleApplicationService for ArticleApplicationServiceImpl {
         self.query_handler.handle_get_articles(query).await
     }
 
+    async fn get_articles_by_cursor(
+        &self,
+        query: GetArticlesByCursorQuery,
+    ) -> Result<CursorPage<ArticleQueryModel>, RhyonError> {
+        self.query_handler
+            .handle_get_articles_by_cursor(query)
+            .await
+    }
+
+    async fn count_articles(&self, query: CountArticlesQuery) -> Result<u64, RhyonError> {
+        self.query_handler.handle_count_articles(query).await
+    }
+
+    async fn get_tags(&self, query: GetTagsQuery) -> Result<Vec<TagQueryModel>, RhyonError> {
+        self.query_handler.handle_get_tags(query).await
+    }
+
     async fn get_article_by_slug(
         &self,
         query: GetArticleBySlugQuery,
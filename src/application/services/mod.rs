@@ -1,3 +1,7 @@
 pub mod article_application_service;
+pub mod webhook_application_service;
+pub mod webhook_dispatcher;
 
 pub use article_application_service::*;
+pub use webhook_application_service::*;
+pub use webhook_dispatcher::*;
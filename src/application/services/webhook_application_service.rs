@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::webhook::commands::{CreateWebhookCommand, UpdateWebhookCommand};
+use crate::domain::webhook::delivery::WebhookDelivery;
+use crate::domain::webhook::ports::{WebhookDeliveryRepository, WebhookSubscriptionRepository};
+use crate::domain::webhook::subscription::WebhookSubscription;
+use crate::shared::errors::RhyonError;
+use crate::shared::events::{ALL_EVENTS, EventRegistry};
+use crate::shared::i18n::Message;
+
+/// Webhook订阅管理服务
+#[async_trait]
+pub trait WebhookApplicationService: Send + Sync {
+    async fn create_webhook(
+        &self,
+        command: CreateWebhookCommand,
+    ) -> Result<WebhookSubscription, RhyonError>;
+    async fn update_webhook(
+        &self,
+        command: UpdateWebhookCommand,
+    ) -> Result<WebhookSubscription, RhyonError>;
+    async fn delete_webhook(&self, id: Uuid) -> Result<(), RhyonError>;
+    async fn get_webhook(&self, id: Uuid) -> Result<WebhookSubscription, RhyonError>;
+    async fn list_webhooks(&self) -> Result<Vec<WebhookSubscription>, RhyonError>;
+
+    /// 某个订阅最近的投递记录
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, RhyonError>;
+
+    /// 以原内容重新投递，返回新的投递记录
+    async fn redeliver(
+        &self,
+        subscription_id: Uuid,
+        delivery_id: Uuid,
+    ) -> Result<WebhookDelivery, RhyonError>;
+}
+
+/// Webhook订阅管理服务实现
+pub struct WebhookApplicationServiceImpl {
+    subscriptions: Arc<dyn WebhookSubscriptionRepository>,
+    deliveries: Arc<dyn WebhookDeliveryRepository>,
+    registry: Arc<EventRegistry>,
+}
+
+impl WebhookApplicationServiceImpl {
+    pub fn new(
+        subscriptions: Arc<dyn WebhookSubscriptionRepository>,
+        deliveries: Arc<dyn WebhookDeliveryRepository>,
+        registry: Arc<EventRegistry>,
+    ) -> Self {
+        Self {
+            subscriptions,
+            deliveries,
+            registry,
+        }
+    }
+
+    /// 只允许订阅已注册的事件类型
+    fn ensure_known_event_types(&self, event_types: &[String]) -> Result<(), RhyonError> {
+        match event_types
+            .iter()
+            .map(|t| t.trim())
+            .find(|t| !t.is_empty() && *t != ALL_EVENTS && !self.registry.contains(t))
+        {
+            Some(unknown) => Err(RhyonError::Validation(
+                Message::new("unknown-event-type").with_arg("name", unknown),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookApplicationService for WebhookApplicationServiceImpl {
+    async fn create_webhook(
+        &self,
+        command: CreateWebhookCommand,
+    ) -> Result<WebhookSubscription, RhyonError> {
+        self.ensure_known_event_types(&command.event_types)?;
+
+        let subscription =
+            WebhookSubscription::create(command.url, command.event_types, command.secret)?;
+        self.subscriptions.save(&subscription).await?;
+
+        Ok(subscription)
+    }
+
+    async fn update_webhook(
+        &self,
+        command: UpdateWebhookCommand,
+    ) -> Result<WebhookSubscription, RhyonError> {
+        let mut subscription = self.get_webhook(command.id).await?;
+
+        if let Some(url) = command.url {
+            subscription.change_url(url)?;
+        }
+        if let Some(event_types) = command.event_types {
+            self.ensure_known_event_types(&event_types)?;
+            subscription.change_event_types(event_types)?;
+        }
+        if let Some(secret) = command.secret {
+            subscription.rotate_secret(secret)?;
+        }
+        if let Some(active) = command.active {
+            subscription.set_active(active);
+        }
+
+        self.subscriptions.update(&subscription).await?;
+        Ok(subscription)
+    }
+
+    async fn delete_webhook(&self, id: Uuid) -> Result<(), RhyonError> {
+        self.get_webhook(id).await?;
+        self.subscriptions.delete(id).await
+    }
+
+    async fn get_webhook(&self, id: Uuid) -> Result<WebhookSubscription, RhyonError> {
+        self.subscriptions
+            .find_by_id(id)
+            .await?
+            .ok_or(RhyonError::NotFound)
+    }
+
+    async fn list_webhooks(&self) -> Result<Vec<WebhookSubscription>, RhyonError> {
+        self.subscriptions.find_all().await
+    }
+
+    async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, RhyonError> {
+        self.get_webhook(subscription_id).await?;
+        self.deliveries
+            .find_by_subscription(subscription_id, limit)
+            .await
+    }
+
+    async fn redeliver(
+        &self,
+        subscription_id: Uuid,
+        delivery_id: Uuid,
+    ) -> Result<WebhookDelivery, RhyonError> {
+        let original = self
+            .deliveries
+            .find_by_id(delivery_id)
+            .await?
+            .filter(|delivery| delivery.subscription_id == subscription_id)
+            .ok_or(RhyonError::NotFound)?;
+
+        let delivery = original.redeliver();
+        self.deliveries
+            .save_all(std::slice::from_ref(&delivery))
+            .await?;
+
+        Ok(delivery)
+    }
+}
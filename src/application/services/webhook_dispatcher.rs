@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use chrono::SecondsFormat;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::{Builder, Uuid};
+
+use crate::domain::webhook::delivery::WebhookDelivery;
+use crate::domain::webhook::ports::{WebhookDeliveryRepository, WebhookSubscriptionRepository};
+use crate::shared::errors::RhyonError;
+use crate::shared::events::{DomainEvent, EventHandler};
+
+/// 领域事件订阅者：为匹配的Webhook订阅生成待投递记录
+///
+/// 实际的HTTP投递和重试由投递工作者异步完成。同一事件可能被重复处理，
+/// 投递记录的ID由事件和订阅派生，重复处理不会生成重复的投递
+pub struct WebhookDispatcher {
+    subscriptions: Arc<dyn WebhookSubscriptionRepository>,
+    deliveries: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl WebhookDispatcher {
+    pub fn new(
+        subscriptions: Arc<dyn WebhookSubscriptionRepository>,
+        deliveries: Arc<dyn WebhookDeliveryRepository>,
+    ) -> Self {
+        Self {
+            subscriptions,
+            deliveries,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for WebhookDispatcher {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    async fn handle(&self, event: &dyn DomainEvent) -> Result<(), RhyonError> {
+        let subscriptions = self
+            .subscriptions
+            .find_active_for_event(event.event_type())
+            .await?;
+        if subscriptions.is_empty() {
+            return Ok(());
+        }
+
+        let event_id = event_id(event);
+        let body = json!({
+            "event_id": event_id,
+            "event_type": event.event_type(),
+            "aggregate_type": event.aggregate_type(),
+            "aggregate_id": event.aggregate_id(),
+            "occurred_on": event.occurred_on(),
+            "version": event.version(),
+            "data": event.payload(),
+        });
+
+        let deliveries: Vec<WebhookDelivery> = subscriptions
+            .iter()
+            .map(|subscription| WebhookDelivery {
+                id: stable_id(&[event_id.as_bytes(), subscription.id().as_bytes()]),
+                ..WebhookDelivery::pending(
+                    subscription.id(),
+                    event.event_type().to_string(),
+                    event.aggregate_id(),
+                    body.clone(),
+                )
+            })
+            .collect();
+
+        self.deliveries.save_all(&deliveries).await
+    }
+}
+
+/// 由事件内容派生的事件ID，同一事件重复投递时保持不变，接收方可据此去重
+fn event_id(event: &dyn DomainEvent) -> Uuid {
+    let occurred_on = event
+        .occurred_on()
+        .to_rfc3339_opts(SecondsFormat::Nanos, true);
+    stable_id(&[
+        event.event_type().as_bytes(),
+        event.aggregate_type().as_bytes(),
+        event.aggregate_id().as_bytes(),
+        occurred_on.as_bytes(),
+    ])
+}
+
+/// 对各部分取SHA-256摘要，生成确定性的UUID
+fn stable_id(parts: &[&[u8]]) -> Uuid {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part);
+    }
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    Builder::from_custom_bytes(bytes).into_uuid()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::persistence::database::testing::sqlite_test_db;
+    use crate::adapters::outbound::persistence::webhook::{
+        SeaOrmWebhookDeliveryRepository, SeaOrmWebhookSubscriptionRepository,
+    };
+    use crate::domain::article::events::ArticlePublishedEvent;
+    use crate::domain::webhook::subscription::WebhookSubscription;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn dispatching_the_same_event_again_creates_no_duplicate_deliveries() {
+        let db = sqlite_test_db().await;
+        let subscriptions = Arc::new(SeaOrmWebhookSubscriptionRepository::new(db.clone()));
+        let deliveries = Arc::new(SeaOrmWebhookDeliveryRepository::new(db));
+        let subscription = WebhookSubscription::create(
+            "http://127.0.0.1:9/hook".to_string(),
+            vec![ArticlePublishedEvent::EVENT_TYPE.to_string()],
+            None,
+        )
+        .unwrap();
+        subscriptions.save(&subscription).await.unwrap();
+        let dispatcher = WebhookDispatcher::new(subscriptions, deliveries.clone());
+
+        // 发件箱重新投递时，事件从同一信封还原
+        let event = ArticlePublishedEvent::new(Uuid::now_v7(), "hello".to_string(), Utc::now());
+        let redelivered: ArticlePublishedEvent =
+            serde_json::from_value(serde_json::to_value(&event).unwrap()).unwrap();
+        dispatcher.handle(&event).await.unwrap();
+        dispatcher.handle(&redelivered).await.unwrap();
+
+        let saved = deliveries
+            .find_by_subscription(subscription.id(), 10)
+            .await
+            .unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].payload["event_id"], json!(event_id(&event)));
+
+        // 同一篇文章的另一个事件是新的投递
+        let other = ArticlePublishedEvent::new(event.article_id, "hello".to_string(), Utc::now());
+        dispatcher.handle(&other).await.unwrap();
+        let saved = deliveries
+            .find_by_subscription(subscription.id(), 10)
+            .await
+            .unwrap();
+        assert_eq!(saved.len(), 2);
+    }
+}
@@ -2,12 +2,22 @@ use axum::Json;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Serialize)]
+use crate::shared::errors::{ErrorCode, FieldViolation};
+
+/// 统一响应信封
+#[derive(Serialize, ToSchema)]
 pub struct R<T: Serialize> {
     code: u16,
     #[serde(skip_serializing_if = "Option::is_none")]
     message: Option<String>,
+    /// 机器可读的错误码，仅错误响应携带
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ErrorCode>,
+    /// 字段级校验失败明细
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    violations: Vec<FieldViolation>,
     #[serde(skip_serializing_if = "Option::is_none")]
     data: Option<T>,
 }
@@ -17,6 +27,8 @@ impl<T: Serialize> R<T> {
         Self {
             code: 200,
             message: None,
+            error: None,
+            violations: Vec::new(),
             data: Some(data),
         }
     }
@@ -25,6 +37,8 @@ impl<T: Serialize> R<T> {
         Self {
             code: 200,
             message: None,
+            error: None,
+            violations: Vec::new(),
             data: None,
         }
     }
@@ -33,18 +47,33 @@ impl<T: Serialize> R<T> {
         Self {
             code,
             message: Some(message.to_string()),
+            error: None,
+            violations: Vec::new(),
             data: None,
         }
     }
+
+    pub fn with_error_code(mut self, error: ErrorCode) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    pub fn with_violations(mut self, violations: Vec<FieldViolation>) -> Self {
+        self.violations = violations;
+        self
+    }
+
+    /// 附带数据，例如错误响应中的诊断信息
+    pub fn with_data(mut self, data: T) -> Self {
+        self.data = Some(data);
+        self
+    }
 }
 
 impl<T: Serialize> IntoResponse for R<T> {
     fn into_response(self) -> Response {
-        let status = if self.code < 300 {
-            StatusCode::OK
-        } else {
-            StatusCode::BAD_REQUEST
-        };
+        // 响应状态与code一致，code不是有效的HTTP状态码时视为服务器错误
+        let status = StatusCode::from_u16(self.code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
 
         (status, Json(self)).into_response()
     }
@@ -55,6 +84,8 @@ impl<T: Serialize> From<T> for R<T> {
         Self {
             code: 200,
             message: None,
+            error: None,
+            violations: Vec::new(),
             data: Some(data),
         }
     }
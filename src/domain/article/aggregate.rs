@@ -1,30 +1,58 @@
-use crate::domain::article::events::{ArticleCreatedEvent, ArticlePublishedEvent};
-use crate::domain::article::value_objects::{Content, Id, Slug, Status, Summary, Title};
+use crate::domain::article::events::{
+    ArticleCreatedEvent, ArticlePublishedEvent, ArticleUnpublishedEvent, ArticleUpdatedEvent,
+};
+use crate::domain::article::value_objects::{
+    Author, Content, Id, Slug, Status, Summary, Tag, Title,
+};
 use crate::shared::errors::RhyonError;
 use crate::shared::events::DomainEvent;
+use crate::shared::i18n::Message;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 /// 文章聚合根
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Article {
-    // 内部技术ID（从数据库获得）
-    pub id: Option<Id>,
+    // 内部技术ID（创建时在领域内生成）
+    pub id: Id,
     // 自然键 - 业务标识符
     pub slug: Slug,
     pub title: Title,
     pub summary: Summary,
     pub content: Content,
+    // 作者与标签晚于快照格式加入，旧快照中缺省为空
+    #[serde(default)]
+    pub author: Option<Author>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
     pub status: Status,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub published_at: Option<DateTime<Utc>>,
+    // 已持久化的最后一个事件的序号（事件溯源模式下用于乐观并发控制）
+    #[serde(skip)]
+    version: u64,
     // 未提交的领域事件
     #[serde(skip)]
     uncommitted_events: Vec<Box<dyn DomainEvent>>,
 }
 
+/// 重建文章所需的持久化状态
+#[derive(Debug, Clone)]
+pub struct ArticleState {
+    pub id: Id,
+    pub slug: Slug,
+    pub title: Title,
+    pub summary: Summary,
+    pub content: Content,
+    pub author: Option<Author>,
+    pub tags: Vec<Tag>,
+    pub status: Status,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
 // 手动实现Clone，跳过events字段
 impl Clone for Article {
     fn clone(&self) -> Self {
@@ -34,10 +62,13 @@ impl Clone for Article {
             title: self.title.clone(),
             summary: self.summary.clone(),
             content: self.content.clone(),
+            author: self.author.clone(),
+            tags: self.tags.clone(),
             status: self.status,
             created_at: self.created_at,
             updated_at: self.updated_at,
             published_at: self.published_at,
+            version: self.version,
             uncommitted_events: Vec::new(), // 克隆时不包含事件
         }
     }
@@ -50,148 +81,244 @@ impl Article {
         slug: Slug,
         summary: Option<Summary>,
         content: Content,
+        author: Option<Author>,
+        tags: Vec<Tag>,
     ) -> Result<Self, RhyonError> {
-        let now = Utc::now();
-
         // 如果未提供摘要，则自动从内容生成
         let summary = match summary {
             Some(s) => s,
             None => Summary::generate_from_content(&content),
         };
 
-        let mut article = Self {
-            id: None, // 数据库插入时会分配ID
-            title: title.clone(),
-            slug: slug.clone(),
-            summary,
-            content,
-            status: Status::Draft,
-            created_at: now,
-            updated_at: now,
-            published_at: None,
-            uncommitted_events: Vec::new(),
-        };
-
-        // 记录创建事件（暂时使用临时ID，保存后会更新）
-        let temp_id = Uuid::new_v4();
-        let event =
-            ArticleCreatedEvent::new(temp_id, title.value().to_string(), slug.value().to_string());
+        let event = ArticleCreatedEvent::new(
+            *Id::generate().value(),
+            title.value().to_string(),
+            slug.value().to_string(),
+            summary.value().to_string(),
+            content.value().to_string(),
+            author.map(|author| author.value().to_string()),
+            tags.iter().map(|tag| tag.value().to_string()).collect(),
+        );
+        let mut article = Self::from_created(&event)?;
         article.add_event(Box::new(event));
 
         Ok(article)
     }
 
     /// 从已有数据重建文章实体（通常用于从存储中加载）
-    pub fn reconstitute(
-        id: Option<Id>,
-        slug: Slug,
-        title: Title,
-        summary: Summary,
-        content: Content,
-        status: Status,
-        created_at: DateTime<Utc>,
-        updated_at: DateTime<Utc>,
-        published_at: Option<DateTime<Utc>>,
-    ) -> Self {
+    pub fn reconstitute(state: ArticleState) -> Self {
+        let ArticleState {
+            id,
+            slug,
+            title,
+            summary,
+            content,
+            author,
+            tags,
+            status,
+            created_at,
+            updated_at,
+            published_at,
+        } = state;
+
         Self {
             id,
             slug,
             title,
             summary,
             content,
+            author,
+            tags,
             status,
             created_at,
             updated_at,
             published_at,
+            version: 0,
             uncommitted_events: Vec::new(), // 从存储重建时不包含事件
         }
     }
 
-    /// 获取构建器用于重建文章
-    pub fn builder() -> ArticleBuilder {
-        ArticleBuilder::new()
+    /// 通过重放事件流重建文章，第一个事件必须是创建事件
+    pub fn from_events(events: &[Box<dyn DomainEvent>]) -> Result<Self, RhyonError> {
+        let (first, rest) = events
+            .split_first()
+            .ok_or_else(|| RhyonError::Domain(Message::new("event-stream-empty")))?;
+        let created = first
+            .as_any()
+            .downcast_ref::<ArticleCreatedEvent>()
+            .ok_or_else(|| RhyonError::Domain(Message::new("event-stream-not-created")))?;
+
+        let mut article = Self::from_created(created)?;
+        article.version = 1;
+        for event in rest {
+            article.apply(event.as_ref())?;
+            article.version += 1;
+        }
+
+        Ok(article)
     }
 
-    /// 设置技术ID（仅在持久化层调用）
-    pub fn set_id(&mut self, id: Uuid) {
-        if self.id.is_none() {
-            self.id = Some(Id::from(id));
+    /// 从快照及其之后的事件重建文章
+    pub fn from_snapshot(
+        mut snapshot: Article,
+        snapshot_version: u64,
+        events: &[Box<dyn DomainEvent>],
+    ) -> Result<Self, RhyonError> {
+        snapshot.version = snapshot_version;
+        for event in events {
+            snapshot.apply(event.as_ref())?;
+            snapshot.version += 1;
+        }
 
-            // 更新创建事件中的ID
-            for event in &mut self.uncommitted_events {
-                if event.event_type() == "article.created" {
-                    // 这里需要重新创建事件，因为我们不能修改Box<dyn DomainEvent>
-                    // 在实际实现中，可以考虑使用其他方式来处理这个问题
-                }
-            }
+        Ok(snapshot)
+    }
+
+    /// 将事件应用到当前状态（创建事件只能用于构造，见`from_events`）
+    pub fn apply(&mut self, event: &dyn DomainEvent) -> Result<(), RhyonError> {
+        let any = event.as_any();
+
+        if let Some(e) = any.downcast_ref::<ArticleUpdatedEvent>() {
+            self.title = Title::new(e.title.clone())?;
+            self.slug = Slug::new(e.slug.clone())?;
+            self.summary = Summary::new(e.summary.clone())?;
+            self.content = Content::new(e.content.clone());
+            self.author = e.author.clone().map(Author::new).transpose()?;
+            self.tags = Tag::parse_all(e.tags.clone())?;
+            self.updated_at = e.occurred_on;
+        } else if let Some(e) = any.downcast_ref::<ArticlePublishedEvent>() {
+            self.status = Status::Published;
+            self.published_at = Some(e.published_at);
+            self.updated_at = e.occurred_on;
+        } else if let Some(e) = any.downcast_ref::<ArticleUnpublishedEvent>() {
+            self.status = Status::Draft;
+            self.updated_at = e.occurred_on;
+        } else {
+            return Err(RhyonError::Domain(
+                Message::new("event-not-applicable").with_arg("event_type", event.event_type()),
+            ));
         }
+
+        Ok(())
+    }
+
+    fn from_created(event: &ArticleCreatedEvent) -> Result<Self, RhyonError> {
+        Ok(Self::reconstitute(ArticleState {
+            id: Id::from(event.article_id),
+            slug: Slug::new(event.slug.clone())?,
+            title: Title::new(event.title.clone())?,
+            summary: Summary::new(event.summary.clone())?,
+            content: Content::new(event.content.clone()),
+            author: event.author.clone().map(Author::new).transpose()?,
+            tags: Tag::parse_all(event.tags.clone())?,
+            status: Status::Draft,
+            created_at: event.occurred_on,
+            updated_at: event.occurred_on,
+            published_at: None,
+        }))
+    }
+
+    /// 获取构建器用于重建文章
+    pub fn builder() -> ArticleBuilder {
+        ArticleBuilder::new()
     }
 
     /// 发布文章
     pub fn publish(&mut self) -> Result<(), RhyonError> {
         if self.content.is_empty() {
-            return Err(RhyonError::Domain("无法发布空内容的文章".to_string()));
+            return Err(RhyonError::Domain(Message::new("article-empty-content")));
         }
 
         if self.status == Status::Published {
-            return Err(RhyonError::Domain("文章已经是发布状态".to_string()));
-        }
-
-        self.status = Status::Published;
-        let now = Utc::now();
-        self.published_at = Some(now);
-        self.updated_at = now;
-
-        // 记录发布事件
-        if let Some(id) = &self.id {
-            let event = ArticlePublishedEvent::new(*id.value(), self.slug.value().to_string(), now);
-            self.add_event(Box::new(event));
+            return Err(RhyonError::Conflict(Message::new(
+                "article-already-published",
+            )));
         }
 
-        Ok(())
+        let event =
+            ArticlePublishedEvent::new(*self.id.value(), self.slug.value().to_string(), Utc::now());
+        self.record(Box::new(event))
     }
 
     /// 将已发布文章设为草稿
     pub fn unpublish(&mut self) -> Result<(), RhyonError> {
         if self.status != Status::Published {
-            return Err(RhyonError::Domain(
-                "只有已发布的文章可以设为草稿".to_string(),
-            ));
+            return Err(RhyonError::Conflict(Message::new("article-not-published")));
         }
 
-        self.status = Status::Draft;
-        self.updated_at = Utc::now();
-        Ok(())
+        let event = ArticleUnpublishedEvent::new(*self.id.value(), self.slug.value().to_string());
+        self.record(Box::new(event))
     }
 
     /// 更新文章标题
-    pub fn update_title(&mut self, title: Title) {
-        self.title = title;
-        self.updated_at = Utc::now();
+    pub fn update_title(&mut self, title: Title) -> Result<(), RhyonError> {
+        self.record_update(
+            title,
+            self.slug.clone(),
+            self.summary.clone(),
+            self.content.clone(),
+        )
     }
 
     /// 更新文章内容
-    pub fn update_content(&mut self, content: Content) {
-        self.content = content;
-        self.updated_at = Utc::now();
+    pub fn update_content(&mut self, content: Content) -> Result<(), RhyonError> {
+        self.record_update(
+            self.title.clone(),
+            self.slug.clone(),
+            self.summary.clone(),
+            content,
+        )
     }
 
     /// 更新文章摘要
-    pub fn update_summary(&mut self, summary: Option<Summary>) {
+    pub fn update_summary(&mut self, summary: Option<Summary>) -> Result<(), RhyonError> {
         // 如果提供了新摘要，则使用新摘要；否则从内容自动生成
-        self.summary = summary.unwrap_or_else(|| Summary::generate_from_content(&self.content));
-        self.updated_at = Utc::now();
+        let summary = summary.unwrap_or_else(|| Summary::generate_from_content(&self.content));
+        self.record_update(
+            self.title.clone(),
+            self.slug.clone(),
+            summary,
+            self.content.clone(),
+        )
     }
 
     /// 更新文章slug（仅在草稿状态可以修改）
     pub fn update_slug(&mut self, slug: Slug) -> Result<(), RhyonError> {
         if self.status == Status::Published {
-            return Err(RhyonError::Domain("已发布文章不能修改slug".to_string()));
+            return Err(RhyonError::Domain(Message::new(
+                "article-published-slug-immutable",
+            )));
         }
 
-        self.slug = slug;
-        self.updated_at = Utc::now();
-        Ok(())
+        self.record_update(
+            self.title.clone(),
+            slug,
+            self.summary.clone(),
+            self.content.clone(),
+        )
+    }
+
+    fn record_update(
+        &mut self,
+        title: Title,
+        slug: Slug,
+        summary: Summary,
+        content: Content,
+    ) -> Result<(), RhyonError> {
+        let event = ArticleUpdatedEvent::new(
+            *self.id.value(),
+            title.value().to_string(),
+            slug.value().to_string(),
+            summary.value().to_string(),
+            content.value().to_string(),
+            self.author
+                .as_ref()
+                .map(|author| author.value().to_string()),
+            self.tags
+                .iter()
+                .map(|tag| tag.value().to_string())
+                .collect(),
+        );
+        self.record(Box::new(event))
     }
 
     // 事件管理方法
@@ -199,6 +326,13 @@ impl Article {
         self.uncommitted_events.push(event);
     }
 
+    /// 应用事件并记录为未提交事件
+    fn record(&mut self, event: Box<dyn DomainEvent>) -> Result<(), RhyonError> {
+        self.apply(event.as_ref())?;
+        self.add_event(event);
+        Ok(())
+    }
+
     /// 获取未提交的事件
     pub fn get_uncommitted_events(&mut self) -> Vec<Box<dyn DomainEvent>> {
         std::mem::take(&mut self.uncommitted_events)
@@ -210,8 +344,8 @@ impl Article {
     }
 
     // 只读访问器
-    pub fn id(&self) -> Option<&Id> {
-        self.id.as_ref()
+    pub fn id(&self) -> &Id {
+        &self.id
     }
 
     pub fn slug(&self) -> &Slug {
@@ -230,6 +364,14 @@ impl Article {
         &self.content
     }
 
+    pub fn author(&self) -> Option<&Author> {
+        self.author.as_ref()
+    }
+
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
     pub fn status(&self) -> &Status {
         &self.status
     }
@@ -245,6 +387,10 @@ impl Article {
     pub fn published_at(&self) -> Option<&DateTime<Utc>> {
         self.published_at.as_ref()
     }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
 }
 
 /// 文章构建器（用于测试和复杂构建场景）
@@ -255,6 +401,8 @@ pub struct ArticleBuilder {
     title: Option<Title>,
     summary: Option<Summary>,
     content: Option<Content>,
+    author: Option<Author>,
+    tags: Vec<Tag>,
     status: Option<Status>,
     created_at: Option<DateTime<Utc>>,
     updated_at: Option<DateTime<Utc>>,
@@ -266,8 +414,8 @@ impl ArticleBuilder {
         Self::default()
     }
 
-    pub fn id(mut self, id: Option<Id>) -> Self {
-        self.id = id;
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
         self
     }
 
@@ -291,6 +439,16 @@ impl ArticleBuilder {
         self
     }
 
+    pub fn author(mut self, author: Option<Author>) -> Self {
+        self.author = author;
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<Tag>) -> Self {
+        self.tags = tags;
+        self
+    }
+
     pub fn status(mut self, status: Status) -> Self {
         self.status = Some(status);
         self
@@ -311,17 +469,130 @@ impl ArticleBuilder {
         self
     }
 
-    pub fn build(self) -> Article {
-        Article::reconstitute(
-            self.id,
-            self.slug.expect("slug is required"),
-            self.title.expect("title is required"),
-            self.summary.expect("summary is required"),
-            self.content.expect("content is required"),
-            self.status.expect("status is required"),
-            self.created_at.expect("created_at is required"),
-            self.updated_at.expect("updated_at is required"),
-            self.published_at.unwrap_or(None),
+    /// 缺少必填字段时返回错误
+    pub fn build(self) -> Result<Article, RhyonError> {
+        Ok(Article::reconstitute(ArticleState {
+            id: required("id", self.id)?,
+            slug: required("slug", self.slug)?,
+            title: required("title", self.title)?,
+            summary: required("summary", self.summary)?,
+            content: required("content", self.content)?,
+            author: self.author,
+            tags: self.tags,
+            status: required("status", self.status)?,
+            created_at: required("created_at", self.created_at)?,
+            updated_at: required("updated_at", self.updated_at)?,
+            published_at: self.published_at.unwrap_or(None),
+        }))
+    }
+}
+
+/// 构建器中的必填字段
+fn required<T>(field: &str, value: Option<T>) -> Result<T, RhyonError> {
+    value.ok_or_else(|| {
+        RhyonError::Domain(Message::new("article-field-required").with_arg("name", field))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complete_builder() -> ArticleBuilder {
+        let now = Utc::now();
+        Article::builder()
+            .id(Id::generate())
+            .slug(Slug::new("hello".to_string()).unwrap())
+            .title(Title::new("Hello".to_string()).unwrap())
+            .summary(Summary::new("summary".to_string()).unwrap())
+            .content(Content::new("body".to_string()))
+            .status(Status::Draft)
+            .created_at(now)
+            .updated_at(now)
+    }
+
+    #[test]
+    fn builder_builds_a_complete_article() {
+        let article = complete_builder().build().unwrap();
+        assert_eq!(article.slug().value(), "hello");
+        assert_eq!(article.published_at, None);
+    }
+
+    #[test]
+    fn builder_keeps_author_and_tags() {
+        let tags = Tag::parse_all(vec!["rust".to_string(), "web".to_string()]).unwrap();
+        let article = complete_builder()
+            .author(Some(Author::new("Ada".to_string()).unwrap()))
+            .tags(tags.clone())
+            .build()
+            .unwrap();
+        assert_eq!(article.author().map(Author::value), Some("Ada"));
+        assert_eq!(article.tags(), tags);
+    }
+
+    #[test]
+    fn builder_reports_the_missing_field() {
+        let builder = ArticleBuilder {
+            created_at: None,
+            ..complete_builder()
+        };
+        let expected = Message::new("article-field-required").with_arg("name", "created_at");
+        assert!(matches!(builder.build(), Err(RhyonError::Domain(message)) if message == expected));
+
+        let expected = Message::new("article-field-required").with_arg("name", "id");
+        assert!(matches!(
+            Article::builder().build(),
+            Err(RhyonError::Domain(message)) if message == expected
+        ));
+    }
+
+    #[test]
+    fn created_event_carries_the_article_id() {
+        let mut article = Article::create(
+            Title::new("Hello".to_string()).unwrap(),
+            Slug::new("hello".to_string()).unwrap(),
+            None,
+            Content::new("body".to_string()),
+            None,
+            Vec::new(),
+        )
+        .unwrap();
+        let id = *article.id().value();
+
+        let events = article.get_uncommitted_events();
+        let created = events[0]
+            .as_any()
+            .downcast_ref::<ArticleCreatedEvent>()
+            .unwrap();
+        assert_eq!(created.article_id, id);
+        assert_eq!(created.aggregate_id(), id.to_string());
+    }
+
+    #[test]
+    fn replaying_events_restores_author_and_tags() {
+        let tags = Tag::parse_all(vec![
+            "Rust".to_string(),
+            "rust".to_string(),
+            "web".to_string(),
+        ]);
+        let mut article = Article::create(
+            Title::new("Hello".to_string()).unwrap(),
+            Slug::new("hello".to_string()).unwrap(),
+            None,
+            Content::new("body".to_string()),
+            Some(Author::new("Ada".to_string()).unwrap()),
+            tags.unwrap(),
         )
+        .unwrap();
+        // 修改其他字段时作者和标签随更新事件一起保留
+        article
+            .update_title(Title::new("Hello again".to_string()).unwrap())
+            .unwrap();
+
+        let replayed = Article::from_events(&article.get_uncommitted_events()).unwrap();
+        assert_eq!(replayed.title().value(), "Hello again");
+        assert_eq!(replayed.author().map(Author::value), Some("Ada"));
+        let tags: Vec<_> = replayed.tags().iter().map(Tag::value).collect();
+        assert_eq!(tags, ["rust", "web"]);
     }
 }
@@ -4,6 +4,8 @@ pub struct CreateArticleCommand {
     pub slug: Option<String>,
     pub summary: Option<String>,
     pub content: String,
+    pub author: Option<String>,
+    pub tags: Vec<String>,
 }
 
 impl CreateArticleCommand {
@@ -13,16 +15,8 @@ impl CreateArticleCommand {
             slug: None,
             summary: None,
             content,
+            author: None,
+            tags: Vec::new(),
         }
     }
-
-    pub fn with_slug(mut self, slug: String) -> Self {
-        self.slug = Some(slug);
-        self
-    }
-
-    pub fn with_summary(mut self, summary: String) -> Self {
-        self.summary = Some(summary);
-        self
-    }
 }
@@ -1,31 +1,63 @@
+use super::ARTICLE_AGGREGATE_TYPE;
 use crate::shared::events::DomainEvent;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
 use uuid::Uuid;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArticleCreatedEvent {
     pub article_id: Uuid,
     pub title: String,
     pub slug: String,
+    /// v2新增：重建聚合所需的完整初始状态
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub content: String,
+    /// 作者和标签在v2之后加入，旧事件缺省为无作者、无标签
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
     pub occurred_on: DateTime<Utc>,
     pub version: u32,
 }
 
 impl ArticleCreatedEvent {
-    pub fn new(article_id: Uuid, title: String, slug: String) -> Self {
+    pub const EVENT_TYPE: &'static str = "article.created";
+    pub const SCHEMA_VERSION: u32 = 2;
+
+    pub fn new(
+        article_id: Uuid,
+        title: String,
+        slug: String,
+        summary: String,
+        content: String,
+        author: Option<String>,
+        tags: Vec<String>,
+    ) -> Self {
         Self {
             article_id,
             title,
             slug,
+            summary,
+            content,
+            author,
+            tags,
             occurred_on: Utc::now(),
-            version: 1,
+            version: Self::SCHEMA_VERSION,
         }
     }
 }
 
 impl DomainEvent for ArticleCreatedEvent {
-    fn event_type(&self) -> &'static str {
-        "article.created"
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_type(&self) -> &str {
+        ARTICLE_AGGREGATE_TYPE
     }
 
     fn aggregate_id(&self) -> String {
@@ -39,4 +71,12 @@ impl DomainEvent for ArticleCreatedEvent {
     fn version(&self) -> u32 {
         self.version
     }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("event should be serializable")
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
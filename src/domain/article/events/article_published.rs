@@ -1,8 +1,11 @@
+use super::ARTICLE_AGGREGATE_TYPE;
 use crate::shared::events::DomainEvent;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
 use uuid::Uuid;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArticlePublishedEvent {
     pub article_id: Uuid,
     pub slug: String,
@@ -12,20 +15,27 @@ pub struct ArticlePublishedEvent {
 }
 
 impl ArticlePublishedEvent {
+    pub const EVENT_TYPE: &'static str = "article.published";
+    pub const SCHEMA_VERSION: u32 = 1;
+
     pub fn new(article_id: Uuid, slug: String, published_at: DateTime<Utc>) -> Self {
         Self {
             article_id,
             slug,
             published_at,
             occurred_on: Utc::now(),
-            version: 1,
+            version: Self::SCHEMA_VERSION,
         }
     }
 }
 
 impl DomainEvent for ArticlePublishedEvent {
-    fn event_type(&self) -> &'static str {
-        "article.published"
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_type(&self) -> &str {
+        ARTICLE_AGGREGATE_TYPE
     }
 
     fn aggregate_id(&self) -> String {
@@ -39,4 +49,12 @@ impl DomainEvent for ArticlePublishedEvent {
     fn version(&self) -> u32 {
         self.version
     }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("event should be serializable")
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
@@ -0,0 +1,59 @@
+use super::ARTICLE_AGGREGATE_TYPE;
+use crate::shared::events::DomainEvent;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use uuid::Uuid;
+
+/// 已发布的文章被撤回为草稿
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleUnpublishedEvent {
+    pub article_id: Uuid,
+    pub slug: String,
+    pub occurred_on: DateTime<Utc>,
+    pub version: u32,
+}
+
+impl ArticleUnpublishedEvent {
+    pub const EVENT_TYPE: &'static str = "article.unpublished";
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    pub fn new(article_id: Uuid, slug: String) -> Self {
+        Self {
+            article_id,
+            slug,
+            occurred_on: Utc::now(),
+            version: Self::SCHEMA_VERSION,
+        }
+    }
+}
+
+impl DomainEvent for ArticleUnpublishedEvent {
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_type(&self) -> &str {
+        ARTICLE_AGGREGATE_TYPE
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.article_id.to_string()
+    }
+
+    fn occurred_on(&self) -> DateTime<Utc> {
+        self.occurred_on
+    }
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("event should be serializable")
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,80 @@
+use super::ARTICLE_AGGREGATE_TYPE;
+use crate::shared::events::DomainEvent;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use uuid::Uuid;
+
+/// 文章内容被修改，携带修改后的完整可编辑字段
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleUpdatedEvent {
+    pub article_id: Uuid,
+    pub title: String,
+    pub slug: String,
+    pub summary: String,
+    pub content: String,
+    /// 作者和标签在v1之后加入，旧事件缺省为无作者、无标签
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub occurred_on: DateTime<Utc>,
+    pub version: u32,
+}
+
+impl ArticleUpdatedEvent {
+    pub const EVENT_TYPE: &'static str = "article.updated";
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    pub fn new(
+        article_id: Uuid,
+        title: String,
+        slug: String,
+        summary: String,
+        content: String,
+        author: Option<String>,
+        tags: Vec<String>,
+    ) -> Self {
+        Self {
+            article_id,
+            title,
+            slug,
+            summary,
+            content,
+            author,
+            tags,
+            occurred_on: Utc::now(),
+            version: Self::SCHEMA_VERSION,
+        }
+    }
+}
+
+impl DomainEvent for ArticleUpdatedEvent {
+    fn event_type(&self) -> &str {
+        Self::EVENT_TYPE
+    }
+
+    fn aggregate_type(&self) -> &str {
+        ARTICLE_AGGREGATE_TYPE
+    }
+
+    fn aggregate_id(&self) -> String {
+        self.article_id.to_string()
+    }
+
+    fn occurred_on(&self) -> DateTime<Utc> {
+        self.occurred_on
+    }
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn payload(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("event should be serializable")
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,5 +1,35 @@
 pub mod article_created;
 pub mod article_published;
+pub mod article_unpublished;
+pub mod article_updated;
 
 pub use article_created::*;
 pub use article_published::*;
+pub use article_unpublished::*;
+pub use article_updated::*;
+
+use crate::shared::events::EventRegistry;
+
+/// 文章聚合类型标识
+pub const ARTICLE_AGGREGATE_TYPE: &str = "article";
+
+/// 注册文章领域的所有事件类型
+pub fn register_article_events(registry: EventRegistry) -> EventRegistry {
+    registry
+        .register::<ArticleCreatedEvent>(
+            ArticleCreatedEvent::EVENT_TYPE,
+            ArticleCreatedEvent::SCHEMA_VERSION,
+        )
+        .register::<ArticlePublishedEvent>(
+            ArticlePublishedEvent::EVENT_TYPE,
+            ArticlePublishedEvent::SCHEMA_VERSION,
+        )
+        .register::<ArticleUpdatedEvent>(
+            ArticleUpdatedEvent::EVENT_TYPE,
+            ArticleUpdatedEvent::SCHEMA_VERSION,
+        )
+        .register::<ArticleUnpublishedEvent>(
+            ArticleUnpublishedEvent::EVENT_TYPE,
+            ArticleUnpublishedEvent::SCHEMA_VERSION,
+        )
+}
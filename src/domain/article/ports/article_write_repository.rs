@@ -5,6 +5,9 @@ use async_trait::async_trait;
 use uuid::Uuid;
 
 /// 文章写仓储端口
+///
+/// 实现需要在持久化聚合的同一事务中保存其未提交的领域事件，
+/// 由发件箱中继负责后续投递。仓储实例由工作单元提供，绑定到其事务
 #[async_trait]
 pub trait ArticleWriteRepository: Send + Sync {
     /// 保存新文章
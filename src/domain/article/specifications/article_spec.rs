@@ -1,32 +1,11 @@
 use crate::domain::article::aggregate::Article;
-use crate::shared::query::{QueryCriteria, Specification};
-use chrono::{DateTime, Utc};
+use crate::shared::query::{FieldAccessor, QueryCriteria, QueryValue, Specification};
 
 /// Article领域的查询规约
 #[derive(Debug, Clone)]
 pub enum ArticleSpec {
     /// 已发布状态
     Published,
-    /// 草稿状态
-    Draft,
-    /// 标题等于
-    TitleEquals(String),
-    /// 标题包含
-    TitleContains(String),
-    /// slug等于
-    SlugEquals(String),
-    /// 摘要包含
-    SummaryContains(String),
-    /// 内容包含
-    ContentContains(String),
-    /// 创建时间晚于
-    CreatedAfter(DateTime<Utc>),
-    /// 创建时间早于
-    CreatedBefore(DateTime<Utc>),
-    /// 发布时间晚于
-    PublishedAfter(DateTime<Utc>),
-    /// 发布时间早于
-    PublishedBefore(DateTime<Utc>),
     /// 组合查询
     Complex(QueryCriteria),
 }
@@ -37,46 +16,6 @@ impl ArticleSpec {
         ArticleSpec::Published
     }
 
-    pub fn draft() -> Self {
-        ArticleSpec::Draft
-    }
-
-    pub fn title_eq(title: impl Into<String>) -> Self {
-        ArticleSpec::TitleEquals(title.into())
-    }
-
-    pub fn title_contains(title: impl Into<String>) -> Self {
-        ArticleSpec::TitleContains(title.into())
-    }
-
-    pub fn slug_eq(slug: impl Into<String>) -> Self {
-        ArticleSpec::SlugEquals(slug.into())
-    }
-
-    pub fn summary_contains(summary: impl Into<String>) -> Self {
-        ArticleSpec::SummaryContains(summary.into())
-    }
-
-    pub fn content_contains(content: impl Into<String>) -> Self {
-        ArticleSpec::ContentContains(content.into())
-    }
-
-    pub fn created_after(date: DateTime<Utc>) -> Self {
-        ArticleSpec::CreatedAfter(date)
-    }
-
-    pub fn created_before(date: DateTime<Utc>) -> Self {
-        ArticleSpec::CreatedBefore(date)
-    }
-
-    pub fn published_after(date: DateTime<Utc>) -> Self {
-        ArticleSpec::PublishedAfter(date)
-    }
-
-    pub fn published_before(date: DateTime<Utc>) -> Self {
-        ArticleSpec::PublishedBefore(date)
-    }
-
     /// 创建复杂查询
     pub fn complex() -> ArticleQueryBuilder {
         ArticleQueryBuilder::new()
@@ -86,24 +25,6 @@ impl ArticleSpec {
     pub fn to_query_criteria(&self) -> QueryCriteria {
         match self {
             ArticleSpec::Published => QueryCriteria::eq("status", "published"),
-            ArticleSpec::Draft => QueryCriteria::eq("status", "draft"),
-            ArticleSpec::TitleEquals(title) => QueryCriteria::eq("title", title.clone()),
-            ArticleSpec::TitleContains(title) => QueryCriteria::contains("title", title.clone()),
-            ArticleSpec::SlugEquals(slug) => QueryCriteria::eq("slug", slug.clone()),
-            ArticleSpec::SummaryContains(summary) => {
-                QueryCriteria::contains("summary", summary.clone())
-            }
-            ArticleSpec::ContentContains(content) => {
-                QueryCriteria::contains("content", content.clone())
-            }
-            ArticleSpec::CreatedAfter(date) => QueryCriteria::gt("created_at", date.timestamp()),
-            ArticleSpec::CreatedBefore(date) => QueryCriteria::lt("created_at", date.timestamp()),
-            ArticleSpec::PublishedAfter(date) => {
-                QueryCriteria::gt("published_at", date.timestamp())
-            }
-            ArticleSpec::PublishedBefore(date) => {
-                QueryCriteria::lt("published_at", date.timestamp())
-            }
             ArticleSpec::Complex(criteria) => criteria.clone(),
         }
     }
@@ -113,24 +34,38 @@ impl Specification<Article> for ArticleSpec {
     fn is_satisfied_by(&self, article: &Article) -> bool {
         match self {
             ArticleSpec::Published => article.status.to_string() == "published",
-            ArticleSpec::Draft => article.status.to_string() == "draft",
-            ArticleSpec::TitleEquals(title) => article.title.value() == title,
-            ArticleSpec::TitleContains(title) => article.title.value().contains(title),
-            ArticleSpec::SlugEquals(slug) => article.slug.value() == slug,
-            ArticleSpec::SummaryContains(summary) => article.summary.value().contains(summary),
-            ArticleSpec::ContentContains(content) => article.content.value().contains(content),
-            ArticleSpec::CreatedAfter(date) => article.created_at > *date,
-            ArticleSpec::CreatedBefore(date) => article.created_at < *date,
-            ArticleSpec::PublishedAfter(date) => article
-                .published_at
-                .is_some_and(|pub_date| pub_date > *date),
-            ArticleSpec::PublishedBefore(date) => article
-                .published_at
-                .is_some_and(|pub_date| pub_date < *date),
-            ArticleSpec::Complex(_) => {
-                // 复杂查询需要在数据库层面处理，这里返回true
-                true
-            }
+            ArticleSpec::Complex(criteria) => criteria.is_satisfied_by(article),
+        }
+    }
+}
+
+/// 按字段名暴露文章属性，字段名和取值与数据库列保持一致
+impl FieldAccessor for Article {
+    fn field_value(&self, field: &str) -> Option<QueryValue> {
+        let value = match field {
+            "id" => self.id.to_string().into(),
+            "title" => self.title.value().into(),
+            "slug" => self.slug.value().into(),
+            "summary" => self.summary.value().into(),
+            "content" => self.content.value().into(),
+            "status" => self.status.as_str().into(),
+            "created_at" => self.created_at.into(),
+            "updated_at" => self.updated_at.into(),
+            "published_at" => self.published_at.map_or(QueryValue::Null, QueryValue::from),
+            "author_name" => self
+                .author()
+                .map_or(QueryValue::Null, |author| author.value().into()),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn field_elements(&self, field: &str) -> Option<Option<Vec<QueryValue>>> {
+        match field {
+            "tags" => Some(Some(
+                self.tags().iter().map(|tag| tag.value().into()).collect(),
+            )),
+            _ => None,
         }
     }
 }
@@ -150,56 +85,12 @@ impl ArticleQueryBuilder {
         self.add_criteria(QueryCriteria::eq("status", "published"))
     }
 
-    pub fn draft(self) -> Self {
-        self.add_criteria(QueryCriteria::eq("status", "draft"))
-    }
-
-    pub fn title_eq(self, title: impl Into<String>) -> Self {
-        self.add_criteria(QueryCriteria::eq("title", title.into()))
-    }
-
-    pub fn title_contains(self, title: impl Into<String>) -> Self {
-        self.add_criteria(QueryCriteria::contains("title", title.into()))
-    }
-
-    pub fn slug_eq(self, slug: impl Into<String>) -> Self {
-        self.add_criteria(QueryCriteria::eq("slug", slug.into()))
-    }
-
-    pub fn content_contains(self, content: impl Into<String>) -> Self {
-        self.add_criteria(QueryCriteria::contains("content", content.into()))
+    pub fn author_eq(self, author: impl Into<String>) -> Self {
+        self.add_criteria(QueryCriteria::eq("author_name", author.into()))
     }
 
-    pub fn created_after(self, date: DateTime<Utc>) -> Self {
-        self.add_criteria(QueryCriteria::gt("created_at", date.timestamp()))
-    }
-
-    pub fn created_before(self, date: DateTime<Utc>) -> Self {
-        self.add_criteria(QueryCriteria::lt("created_at", date.timestamp()))
-    }
-
-    pub fn and(self, other: ArticleQueryBuilder) -> Self {
-        match (self.criteria, other.criteria) {
-            (Some(left), Some(right)) => Self {
-                criteria: Some(left.and(right)),
-            },
-            (Some(criteria), None) | (None, Some(criteria)) => Self {
-                criteria: Some(criteria),
-            },
-            (None, None) => Self { criteria: None },
-        }
-    }
-
-    pub fn or(self, other: ArticleQueryBuilder) -> Self {
-        match (self.criteria, other.criteria) {
-            (Some(left), Some(right)) => Self {
-                criteria: Some(left.or(right)),
-            },
-            (Some(criteria), None) | (None, Some(criteria)) => Self {
-                criteria: Some(criteria),
-            },
-            (None, None) => Self { criteria: None },
-        }
+    pub fn has_tag(self, tag: impl Into<String>) -> Self {
+        self.add_criteria(QueryCriteria::array_contains("tags", tag.into()))
     }
 
     pub fn build(self) -> ArticleSpec {
@@ -1,4 +1,4 @@
-use crate::shared::query::{SortCriteria, SortDirection};
+use crate::shared::query::SortCriteria;
 
 /// Article领域的排序规约
 #[derive(Debug, Clone, Default)]
@@ -30,39 +30,6 @@ impl ArticleSortSpec {
         ArticleSortSpec::PublishedAtDesc
     }
 
-    pub fn published_at_asc() -> Self {
-        ArticleSortSpec::PublishedAtAsc
-    }
-
-    pub fn title_asc() -> Self {
-        ArticleSortSpec::TitleAsc
-    }
-
-    pub fn title_desc() -> Self {
-        ArticleSortSpec::TitleDesc
-    }
-
-    pub fn created_at_desc() -> Self {
-        ArticleSortSpec::CreatedAtDesc
-    }
-
-    pub fn created_at_asc() -> Self {
-        ArticleSortSpec::CreatedAtAsc
-    }
-
-    pub fn updated_at_desc() -> Self {
-        ArticleSortSpec::UpdatedAtDesc
-    }
-
-    pub fn updated_at_asc() -> Self {
-        ArticleSortSpec::UpdatedAtAsc
-    }
-
-    /// 创建自定义排序
-    pub fn custom() -> ArticleSortBuilder {
-        ArticleSortBuilder::new()
-    }
-
     /// 转换为通用排序条件
     pub fn to_sort_criteria(&self) -> SortCriteria {
         match self {
@@ -102,83 +69,3 @@ impl From<String> for ArticleSortSpec {
         }
     }
 }
-
-/// 用于构建复杂排序的Builder模式
-#[derive(Debug)]
-pub struct ArticleSortBuilder {
-    criteria: SortCriteria,
-}
-
-impl ArticleSortBuilder {
-    pub fn new() -> Self {
-        Self {
-            criteria: SortCriteria::new(),
-        }
-    }
-
-    pub fn published_at(self, direction: SortDirection) -> Self {
-        Self {
-            criteria: self.criteria.add("published_at", direction),
-        }
-    }
-
-    pub fn published_at_desc(self) -> Self {
-        self.published_at(SortDirection::Desc)
-    }
-
-    pub fn published_at_asc(self) -> Self {
-        self.published_at(SortDirection::Asc)
-    }
-
-    pub fn title(self, direction: SortDirection) -> Self {
-        Self {
-            criteria: self.criteria.add("title", direction),
-        }
-    }
-
-    pub fn title_asc(self) -> Self {
-        self.title(SortDirection::Asc)
-    }
-
-    pub fn title_desc(self) -> Self {
-        self.title(SortDirection::Desc)
-    }
-
-    pub fn created_at(self, direction: SortDirection) -> Self {
-        Self {
-            criteria: self.criteria.add("created_at", direction),
-        }
-    }
-
-    pub fn created_at_desc(self) -> Self {
-        self.created_at(SortDirection::Desc)
-    }
-
-    pub fn created_at_asc(self) -> Self {
-        self.created_at(SortDirection::Asc)
-    }
-
-    pub fn updated_at(self, direction: SortDirection) -> Self {
-        Self {
-            criteria: self.criteria.add("updated_at", direction),
-        }
-    }
-
-    pub fn updated_at_desc(self) -> Self {
-        self.updated_at(SortDirection::Desc)
-    }
-
-    pub fn updated_at_asc(self) -> Self {
-        self.updated_at(SortDirection::Asc)
-    }
-
-    pub fn build(self) -> ArticleSortSpec {
-        ArticleSortSpec::Custom(self.criteria)
-    }
-}
-
-impl Default for ArticleSortBuilder {
-    fn default() -> Self {
-        Self::new()
-    }
-}
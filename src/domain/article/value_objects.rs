@@ -1,4 +1,5 @@
-use crate::shared::errors::RhyonError;
+use crate::shared::errors::{FieldViolation, RhyonError, Violations};
+use crate::shared::i18n::Message;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use uuid::Uuid;
@@ -8,6 +9,11 @@ use uuid::Uuid;
 pub struct Id(Uuid);
 
 impl Id {
+    /// 在领域内生成新ID（UUIDv7，按时间有序）
+    pub fn generate() -> Self {
+        Self(Uuid::now_v7())
+    }
+
     pub fn from(id: Uuid) -> Self {
         Self(id)
     }
@@ -23,24 +29,26 @@ impl fmt::Display for Id {
     }
 }
 
-/// 文章标题
+/// 文章标题（长度按字符计算）
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Title {
     value: String,
 }
 
 impl Title {
+    pub const MAX_LENGTH: usize = 200;
+
     pub fn new(title: String) -> Result<Self, RhyonError> {
         let title = title.trim().to_string();
 
         if title.is_empty() {
-            return Err(RhyonError::Validation("文章标题不能为空".to_string()));
+            return Err(FieldViolation::new("title", "required").into());
         }
 
-        if title.len() > 200 {
-            return Err(RhyonError::Validation(
-                "文章标题过长（最多200个字符）".to_string(),
-            ));
+        if title.chars().count() > Self::MAX_LENGTH {
+            let violation =
+                FieldViolation::new("title", "too_long").with_param("max", Self::MAX_LENGTH);
+            return Err(violation.into());
         }
 
         Ok(Self { value: title })
@@ -68,7 +76,7 @@ impl Slug {
         let slug = slug.trim().to_lowercase();
 
         if slug.is_empty() {
-            return Err(RhyonError::Validation("文章Slug不能为空".to_string()));
+            return Err(FieldViolation::new("slug", "required").into());
         }
 
         Ok(Self { value: slug })
@@ -107,20 +115,22 @@ impl fmt::Display for Slug {
     }
 }
 
-/// 文章摘要
+/// 文章摘要（长度按字符计算）
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Summary {
     value: String,
 }
 
 impl Summary {
+    pub const MAX_LENGTH: usize = 500;
+
     pub fn new(summary: String) -> Result<Self, RhyonError> {
         let summary = summary.trim().to_string();
 
-        if summary.len() > 500 {
-            return Err(RhyonError::Validation(
-                "摘要过长（最多500个字符）".to_string(),
-            ));
+        if summary.chars().count() > Self::MAX_LENGTH {
+            let violation =
+                FieldViolation::new("summary", "too_long").with_param("max", Self::MAX_LENGTH);
+            return Err(violation.into());
         }
 
         Ok(Self { value: summary })
@@ -131,27 +141,20 @@ impl Summary {
         let content_text = content.value();
         let max_length = 200;
 
-        let summary = if content_text.len() <= max_length {
-            content_text.to_string()
-        } else {
-            // 尝试在句号或段落处截断
-            let mut end_pos = max_length;
-
-            // 尝试找到最后一个句号或换行符位置
-            for (i, c) in content_text
-                .char_indices()
-                .take(max_length)
-                .collect::<Vec<_>>()
-                .iter()
-                .rev()
-            {
-                if *c == '.' || *c == '\n' {
-                    end_pos = i + 1; // 包含句号或换行符
-                    break;
-                }
+        // 第max_length个字符之后的字节位置，不足max_length个字符时使用全文
+        let summary = match content_text.char_indices().nth(max_length) {
+            None => content_text.to_string(),
+            Some((limit, _)) => {
+                // 尝试在最后一个句号或换行符处截断（包含该字符）
+                let head = &content_text[..limit];
+                let end_pos = head
+                    .char_indices()
+                    .rev()
+                    .find(|(_, c)| matches!(c, '.' | '。' | '\n'))
+                    .map_or(limit, |(i, c)| i + c.len_utf8());
+
+                format!("{}...", content_text[..end_pos].trim())
             }
-
-            format!("{}...", content_text[..end_pos].trim())
         };
 
         Self { value: summary }
@@ -198,6 +201,94 @@ impl fmt::Display for Content {
     }
 }
 
+/// 文章作者署名（长度按字符计算）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Author {
+    value: String,
+}
+
+impl Author {
+    pub const MAX_LENGTH: usize = 100;
+
+    pub fn new(author: String) -> Result<Self, RhyonError> {
+        let author = author.trim().to_string();
+
+        if author.is_empty() {
+            return Err(FieldViolation::new("author", "required").into());
+        }
+
+        if author.chars().count() > Self::MAX_LENGTH {
+            let violation =
+                FieldViolation::new("author", "too_long").with_param("max", Self::MAX_LENGTH);
+            return Err(violation.into());
+        }
+
+        Ok(Self { value: author })
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for Author {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// 文章标签（统一为小写，标签页和订阅源按原值匹配，长度按字符计算）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tag {
+    value: String,
+}
+
+impl Tag {
+    pub const MAX_LENGTH: usize = 50;
+
+    pub fn new(tag: String) -> Result<Self, RhyonError> {
+        let tag = tag.trim().to_lowercase();
+
+        if tag.is_empty() {
+            return Err(FieldViolation::new("tags", "required").into());
+        }
+
+        if tag.chars().count() > Self::MAX_LENGTH {
+            let violation =
+                FieldViolation::new("tags", "too_long").with_param("max", Self::MAX_LENGTH);
+            return Err(violation.into());
+        }
+
+        Ok(Self { value: tag })
+    }
+
+    /// 校验标签列表，去除重复项并保持原有顺序
+    ///
+    /// 一次返回全部无效标签的违规，字段名带有标签的下标，如`tags[2]`
+    pub fn parse_all(tags: Vec<String>) -> Result<Vec<Self>, RhyonError> {
+        let mut violations = Violations::new();
+        let mut parsed: Vec<Self> = Vec::with_capacity(tags.len());
+        for (index, tag) in tags.into_iter().enumerate() {
+            if let Some(tag) = violations.check_item(index, Self::new(tag))
+                && !parsed.contains(&tag)
+            {
+                parsed.push(tag);
+            }
+        }
+        violations.finish(|| Some(parsed))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
 /// 文章状态
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Status {
@@ -217,7 +308,9 @@ impl Status {
         match s.to_lowercase().as_str() {
             "draft" => Ok(Status::Draft),
             "published" => Ok(Status::Published),
-            _ => Err(RhyonError::Validation("无效的文章状态".to_string())),
+            _ => Err(RhyonError::Validation(Message::new(
+                "invalid-article-status",
+            ))),
         }
     }
 }
@@ -227,3 +320,78 @@ impl fmt::Display for Status {
         write!(f, "{}", self.as_str())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violations(result: Result<Vec<Tag>, RhyonError>) -> Vec<(String, &'static str)> {
+        let Err(RhyonError::Invalid(violations)) = result else {
+            panic!("expected field violations");
+        };
+        violations
+            .into_iter()
+            .map(|violation| (violation.field, violation.code))
+            .collect()
+    }
+
+    #[test]
+    fn lengths_are_counted_in_characters() {
+        let cjk = |n: usize| "文".repeat(n);
+
+        assert!(Title::new(cjk(Title::MAX_LENGTH)).is_ok());
+        assert!(Title::new(cjk(Title::MAX_LENGTH + 1)).is_err());
+        assert!(Summary::new(cjk(Summary::MAX_LENGTH)).is_ok());
+        assert!(Summary::new(cjk(Summary::MAX_LENGTH + 1)).is_err());
+        assert!(Author::new(cjk(Author::MAX_LENGTH)).is_ok());
+        assert!(Author::new(cjk(Author::MAX_LENGTH + 1)).is_err());
+        assert!(Tag::new(cjk(Tag::MAX_LENGTH)).is_ok());
+        assert!(Tag::new(cjk(Tag::MAX_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn generated_summary_is_cut_on_a_character_boundary() {
+        let summary = Summary::generate_from_content(&Content::new("文".repeat(300)));
+        assert_eq!(summary.value(), format!("{}...", "文".repeat(200)));
+
+        let content = format!("{}。{}", "文".repeat(150), "字".repeat(150));
+        let summary = Summary::generate_from_content(&Content::new(content));
+        assert_eq!(summary.value(), format!("{}。...", "文".repeat(150)));
+    }
+
+    #[test]
+    fn every_invalid_tag_is_reported_with_its_index() {
+        let tags = vec![
+            "rust".to_string(),
+            " ".to_string(),
+            "标".repeat(Tag::MAX_LENGTH + 1),
+            "Rust".to_string(),
+        ];
+        assert_eq!(
+            violations(Tag::parse_all(tags)),
+            [
+                ("tags[1]".to_string(), "required"),
+                ("tags[2]".to_string(), "too_long")
+            ]
+        );
+
+        let violation = FieldViolation::new("tags", "too_long");
+        let indexed = FieldViolation {
+            field: "tags[2]".to_string(),
+            ..violation.clone()
+        };
+        assert_eq!(indexed.message_key(), violation.message_key());
+    }
+
+    #[test]
+    fn valid_tags_are_normalized_and_deduplicated() {
+        let tags = Tag::parse_all(vec![
+            " Rust ".to_string(),
+            "标签".to_string(),
+            "rust".to_string(),
+        ])
+        .unwrap();
+        let tags: Vec<_> = tags.iter().map(Tag::value).collect();
+        assert_eq!(tags, ["rust", "标签"]);
+    }
+}
@@ -0,0 +1,17 @@
+/// 创建Webhook订阅命令
+#[derive(Debug, Clone)]
+pub struct CreateWebhookCommand {
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub secret: Option<String>,
+}
+
+/// 修改Webhook订阅命令（未提供的字段保持不变）
+#[derive(Debug, Clone)]
+pub struct UpdateWebhookCommand {
+    pub id: uuid::Uuid,
+    pub url: Option<String>,
+    pub event_types: Option<Vec<String>>,
+    pub secret: Option<String>,
+    pub active: Option<bool>,
+}
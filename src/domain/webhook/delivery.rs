@@ -0,0 +1,108 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// 投递状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl DeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Succeeded => "succeeded",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(DeliveryStatus::Pending),
+            "succeeded" => Some(DeliveryStatus::Succeeded),
+            "failed" => Some(DeliveryStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// 一次Webhook投递及其结果
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_type: String,
+    pub aggregate_id: String,
+    /// 发送给接收方的请求体
+    pub payload: serde_json::Value,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDelivery {
+    /// 创建待投递记录
+    pub fn pending(
+        subscription_id: Uuid,
+        event_type: String,
+        aggregate_id: String,
+        payload: serde_json::Value,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            subscription_id,
+            event_type,
+            aggregate_id,
+            payload,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            response_status: None,
+            last_error: None,
+            next_attempt_at: now,
+            created_at: now,
+            delivered_at: None,
+        }
+    }
+
+    /// 以相同内容重新投递（生成新的投递记录，保留原记录作为日志）
+    pub fn redeliver(&self) -> Self {
+        Self::pending(
+            self.subscription_id,
+            self.event_type.clone(),
+            self.aggregate_id.clone(),
+            self.payload.clone(),
+        )
+    }
+
+    pub fn record_success(&mut self, response_status: u16) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Succeeded;
+        self.response_status = Some(response_status as i32);
+        self.last_error = None;
+        self.delivered_at = Some(Utc::now());
+    }
+
+    /// 记录失败；尚有重试机会时在`retry_after`之后重试，否则标记为失败
+    pub fn record_failure(
+        &mut self,
+        response_status: Option<u16>,
+        error: String,
+        retry_after: Option<Duration>,
+    ) {
+        self.attempts += 1;
+        self.response_status = response_status.map(|s| s as i32);
+        self.last_error = Some(error);
+
+        match retry_after {
+            Some(delay) => self.next_attempt_at = Utc::now() + delay,
+            None => self.status = DeliveryStatus::Failed,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod commands;
+pub mod delivery;
+pub mod ports;
+pub mod subscription;
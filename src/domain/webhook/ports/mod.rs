@@ -0,0 +1,5 @@
+pub mod webhook_delivery_repository;
+pub mod webhook_subscription_repository;
+
+pub use webhook_delivery_repository::*;
+pub use webhook_subscription_repository::*;
@@ -0,0 +1,27 @@
+use crate::domain::webhook::delivery::WebhookDelivery;
+use crate::shared::errors::RhyonError;
+use async_trait::async_trait;
+use chrono::Duration;
+use uuid::Uuid;
+
+/// Webhook投递记录仓储端口
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    async fn save_all(&self, deliveries: &[WebhookDelivery]) -> Result<(), RhyonError>;
+
+    async fn update(&self, delivery: &WebhookDelivery) -> Result<(), RhyonError>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookDelivery>, RhyonError>;
+
+    /// 按创建时间倒序列出某个订阅的投递记录
+    async fn find_by_subscription(
+        &self,
+        subscription_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, RhyonError>;
+
+    /// 领取一条到期待投递的记录，实现应保证同一记录不会被并发领取
+    ///
+    /// 被领取的记录在`lease`时长内不会再次被领取，因此租约只需覆盖单次投递的耗时
+    async fn claim_next(&self, lease: Duration) -> Result<Option<WebhookDelivery>, RhyonError>;
+}
@@ -0,0 +1,24 @@
+use crate::domain::webhook::subscription::WebhookSubscription;
+use crate::shared::errors::RhyonError;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Webhook订阅仓储端口
+#[async_trait]
+pub trait WebhookSubscriptionRepository: Send + Sync {
+    async fn save(&self, subscription: &WebhookSubscription) -> Result<(), RhyonError>;
+
+    async fn update(&self, subscription: &WebhookSubscription) -> Result<(), RhyonError>;
+
+    async fn delete(&self, id: Uuid) -> Result<(), RhyonError>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<WebhookSubscription>, RhyonError>;
+
+    async fn find_all(&self) -> Result<Vec<WebhookSubscription>, RhyonError>;
+
+    /// 查找订阅了指定事件类型的启用中的订阅
+    async fn find_active_for_event(
+        &self,
+        event_type: &str,
+    ) -> Result<Vec<WebhookSubscription>, RhyonError>;
+}
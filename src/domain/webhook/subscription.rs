@@ -0,0 +1,169 @@
+use crate::shared::errors::{FieldViolation, RhyonError, Violations};
+use crate::shared::events::ALL_EVENTS;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Webhook订阅：将指定类型的领域事件推送到外部URL
+#[derive(Debug, Clone)]
+pub struct WebhookSubscription {
+    id: Uuid,
+    url: String,
+    event_types: Vec<String>,
+    secret: String,
+    active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    pub const MIN_SECRET_LENGTH: usize = 16;
+
+    /// 创建新订阅，未提供密钥时自动生成
+    pub fn create(
+        url: String,
+        event_types: Vec<String>,
+        secret: Option<String>,
+    ) -> Result<Self, RhyonError> {
+        let now = Utc::now();
+        let mut violations = Violations::new();
+        let url = violations.check(Self::validate_url(url));
+        let event_types = violations.check(Self::validate_event_types(event_types));
+        let secret = match secret {
+            Some(secret) => violations.check(Self::validate_secret(secret)),
+            None => Some(Self::generate_secret()),
+        };
+        let (url, event_types, secret) =
+            violations.finish(|| Some((url?, event_types?, secret?)))?;
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            url,
+            event_types,
+            secret,
+            active: true,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 从已有数据重建订阅
+    pub fn reconstitute(
+        id: Uuid,
+        url: String,
+        event_types: Vec<String>,
+        secret: String,
+        active: bool,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            url,
+            event_types,
+            secret,
+            active,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn change_url(&mut self, url: String) -> Result<(), RhyonError> {
+        self.url = Self::validate_url(url)?;
+        self.touch();
+        Ok(())
+    }
+
+    pub fn change_event_types(&mut self, event_types: Vec<String>) -> Result<(), RhyonError> {
+        self.event_types = Self::validate_event_types(event_types)?;
+        self.touch();
+        Ok(())
+    }
+
+    pub fn rotate_secret(&mut self, secret: String) -> Result<(), RhyonError> {
+        self.secret = Self::validate_secret(secret)?;
+        self.touch();
+        Ok(())
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        self.touch();
+    }
+
+    /// 是否应接收指定类型的事件
+    pub fn subscribes_to(&self, event_type: &str) -> bool {
+        self.active
+            && self
+                .event_types
+                .iter()
+                .any(|t| t == ALL_EVENTS || t == event_type)
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn event_types(&self) -> &[String] {
+        &self.event_types
+    }
+
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    fn touch(&mut self) {
+        self.updated_at = Utc::now();
+    }
+
+    fn validate_url(url: String) -> Result<String, RhyonError> {
+        let url = url.trim().to_string();
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(FieldViolation::new("url", "invalid_scheme").into());
+        }
+        Ok(url)
+    }
+
+    fn validate_event_types(event_types: Vec<String>) -> Result<Vec<String>, RhyonError> {
+        let mut types: Vec<String> = event_types
+            .into_iter()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+        types.sort();
+        types.dedup();
+
+        if types.is_empty() {
+            return Err(FieldViolation::new("event_types", "required").into());
+        }
+        Ok(types)
+    }
+
+    fn validate_secret(secret: String) -> Result<String, RhyonError> {
+        if secret.len() < Self::MIN_SECRET_LENGTH {
+            let violation = FieldViolation::new("secret", "too_short")
+                .with_param("min", Self::MIN_SECRET_LENGTH);
+            return Err(violation.into());
+        }
+        Ok(secret)
+    }
+
+    fn generate_secret() -> String {
+        format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+    }
+}
@@ -1,87 +1,433 @@
-use axum::Router;
-use axum::routing::get;
-use std::error::Error;
-use std::sync::Arc;
-use tokio::net::TcpListener;
-use tower_http::trace::TraceLayer;
-use tracing_subscriber::{filter, fmt};
-
-// 新架构的导入
-use crate::adapters::inbound::http::{controllers::ArticleController, routes::article_routes};
-use crate::adapters::outbound::{
-    events::InMemoryEventPublisher,
-    persistence::{
-        database::connection::connect, read::SeaOrmArticleReadRepository,
-        write::SeaOrmArticleWriteRepository,
-    },
-};
-use crate::application::{
-    commands::ArticleCommandHandlerImpl, queries::ArticleQueryHandlerImpl,
-    services::ArticleApplicationServiceImpl,
-};
-use crate::shared::events::EventPublisher;
-
-mod adapters;
-mod application;
-mod core;
-mod domain;
-mod shared;
-
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    // 初始化日志记录器，设置日志级别为DEBUG
-    fmt().with_max_level(filter::LevelFilter::DEBUG).init();
-
-    tracing::info!("🚀 启动Rhyon博客服务 - CQRS+六边形架构");
-
-    // 1. 基础设施层 - 数据库连接
-    let db = connect().await?;
-    tracing::info!("✅ 数据库连接已建立");
-
-    // 2. 出站适配器 - 仓储实现
-    let write_repository = Arc::new(SeaOrmArticleWriteRepository::new(db.clone()));
-    let read_repository = Arc::new(SeaOrmArticleReadRepository::new(db));
-    let event_publisher: Arc<dyn EventPublisher> = Arc::new(InMemoryEventPublisher::new());
-
-    tracing::info!("✅ 仓储适配器已创建");
-
-    // 3. 应用层 - 命令和查询处理器
-    let command_handler = Arc::new(ArticleCommandHandlerImpl::new(
-        write_repository,
-        event_publisher,
-    ));
-    let query_handler = Arc::new(ArticleQueryHandlerImpl::new(read_repository));
-
-    tracing::info!("✅ 命令和查询处理器已创建");
-
-    // 4. 应用服务 - 门面模式
-    let application_service = Arc::new(ArticleApplicationServiceImpl::new(
-        command_handler,
-        query_handler,
-    ));
-
-    tracing::info!("✅ 应用服务已创建");
-
-    // 5. 入站适配器 - HTTP控制器
-    let article_controller = Arc::new(ArticleController::new(application_service));
-
-    tracing::info!("✅ HTTP控制器已创建");
-
-    // 6. 路由配置
-    let app = Router::new()
-        .route("/", get(hello))
-        .nest("/articles", article_routes())
-        .layer(TraceLayer::new_for_http())
-        .with_state(article_controller);
-
-    let listener = TcpListener::bind("0.0.0.0:8080").await?;
-    tracing::info!("🌐 Rhyon服务正在监听: {}", listener.local_addr()?);
-    tracing::info!("📖 API文档: http://localhost:8080/articles");
-
-    axum::serve(listener, app.into_make_service()).await?;
-    Ok(())
-}
-
-async fn hello() -> &'static str {
-    "🎉 Rhyon博客服务 - CQRS+六边形架构已启动！"
-}
+use axum::Router;
+use axum::middleware::{from_fn, from_fn_with_state};
+use axum::routing::get;
+use clap::Parser;
+use sea_orm::DatabaseConnection;
+use std::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tower_http::trace::TraceLayer;
+use tracing_subscriber::{filter, fmt};
+
+// 新架构的导入
+use crate::adapters::inbound::cli::Cli;
+use crate::adapters::inbound::http::{
+    controllers::{
+        ArticleController, DeadLetterController, EventStreamController, FeedController,
+        HealthController, MetricsController, SiteController, ThemeController, WebhookController,
+    },
+    middleware::{
+        ReadYourWritesConfig, load_admin_config, load_http_cache_config, negotiate_locale,
+        read_your_writes, require_admin_token,
+    },
+    routes::{
+        article_routes, dead_letter_routes, docs_routes, event_stream_routes, feed_routes,
+        health_routes, metrics_routes, site_routes, theme_routes, webhook_routes,
+    },
+    site::{
+        Theme,
+        config::{load_feed_config, load_robots_config, load_site_config, load_theme_config},
+    },
+    sse::{EventStreamFeed, EventStreamHub, config::load_event_stream_config},
+};
+use crate::adapters::outbound::{
+    cache::{
+        ArticleCache, ArticleCacheInvalidator, CacheStore, CachedArticleReadRepository,
+        LruCacheStore, RedisCacheStore,
+        config::{CacheBackend, CacheConfig, load_cache_config},
+    },
+    events::{InProcessEventBus, LoggingEventHandler, config::load_event_bus_config},
+    persistence::{
+        config::{PersistenceBackend, PersistenceConfig, load_persistence_config},
+        database::{
+            config::load_db_config,
+            connection::{connect, connect_replica},
+            migration::{load_migration_config, run_pending_migrations},
+            read_connection::ReadConnection,
+        },
+        event_store::SeaOrmEventStore,
+        memory::{InMemoryArticleReadRepository, InMemoryArticleStore, InMemoryUnitOfWork},
+        outbox::{OutboxRelay, config::load_outbox_config},
+        projection::{
+            ArticleReadViewProgress, ArticleReadViewProjector, ArticleReadViewWatcher,
+            config::load_projection_config,
+        },
+        read::SeaOrmArticleReadRepository,
+        unit_of_work::SeaOrmUnitOfWork,
+        webhook::{SeaOrmWebhookDeliveryRepository, SeaOrmWebhookSubscriptionRepository},
+    },
+    webhook::{WebhookDeliveryWorker, config::load_webhook_config},
+};
+use crate::application::{
+    commands::ArticleCommandHandlerImpl,
+    ports::{ArticleReadRepository, UnitOfWork},
+    queries::ArticleQueryHandlerImpl,
+    services::{ArticleApplicationServiceImpl, WebhookApplicationServiceImpl, WebhookDispatcher},
+};
+use crate::domain::article::events::{
+    ArticlePublishedEvent, ArticleUnpublishedEvent, ArticleUpdatedEvent, register_article_events,
+};
+use crate::shared::events::{EventPublisher, EventRegistry};
+use crate::shared::i18n::MessageCatalog;
+
+mod adapters;
+mod application;
+mod core;
+mod domain;
+mod shared;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+
+    // 初始化日志记录器，设置日志级别为DEBUG
+    fmt().with_max_level(filter::LevelFilter::DEBUG).init();
+
+    tracing::info!("🚀 启动Rhyon博客服务 - CQRS+六边形架构");
+
+    // 消息目录 - 缺少任何语言的翻译时拒绝启动
+    MessageCatalog::global().verify()?;
+
+    // 事件注册表 - 用于从信封还原领域事件
+    let event_registry = Arc::new(register_article_events(EventRegistry::new()));
+
+    // 运维子命令 - 执行完成后直接退出，不启动HTTP服务
+    if let Some(command) = cli.command {
+        let db = connect().await?;
+        return command.run(db, event_registry).await;
+    }
+
+    // 事件流中心 - 向SSE客户端推送领域事件
+    let event_stream_hub = Arc::new(EventStreamHub::new(load_event_stream_config()));
+
+    // 事件总线 - 在此注册领域事件的订阅者
+    let event_bus =
+        InProcessEventBus::new(load_event_bus_config()).subscribe_all(LoggingEventHandler);
+    let dead_letters = event_bus.dead_letters();
+
+    // 查询缓存 - 缓存文章详情，文章变更后使其失效
+    let article_cache = article_cache(load_cache_config()).await;
+    let cache_invalidator = article_cache.clone().map(ArticleCacheInvalidator::new);
+
+    // 1~2. 基础设施层与出站适配器 - 按配置选择存储后端
+    let persistence_config = load_persistence_config();
+    let repositories = match persistence_config.backend {
+        PersistenceBackend::Database => {
+            database_repositories(
+                event_bus,
+                event_registry,
+                &persistence_config,
+                cache_invalidator,
+                event_stream_hub.clone(),
+            )
+            .await?
+        }
+        PersistenceBackend::Memory => {
+            // 内存后端只有一个进程，事件流直接订阅事件总线
+            let event_bus = event_bus.subscribe_all(event_stream_hub.clone());
+            // 内存后端保存时同步更新读取的数据，事件发布后即可使缓存失效
+            let event_bus = match cache_invalidator {
+                Some(invalidator) => event_bus
+                    .on::<ArticlePublishedEvent, _>(
+                        ArticlePublishedEvent::EVENT_TYPE,
+                        invalidator.clone(),
+                    )
+                    .on::<ArticleUpdatedEvent, _>(
+                        ArticleUpdatedEvent::EVENT_TYPE,
+                        invalidator.clone(),
+                    )
+                    .on::<ArticleUnpublishedEvent, _>(
+                        ArticleUnpublishedEvent::EVENT_TYPE,
+                        invalidator,
+                    ),
+                None => event_bus,
+            };
+            in_memory_repositories(event_bus)
+        }
+    };
+
+    // 3. 应用层 - 命令和查询处理器
+    let command_handler = Arc::new(ArticleCommandHandlerImpl::new(repositories.unit_of_work));
+    let read_repository: Arc<dyn ArticleReadRepository> = match &article_cache {
+        Some(cache) => Arc::new(CachedArticleReadRepository::new(
+            repositories.read,
+            cache.clone(),
+        )),
+        None => repositories.read,
+    };
+    let query_handler = Arc::new(ArticleQueryHandlerImpl::new(read_repository));
+
+    tracing::info!("✅ 命令和查询处理器已创建");
+
+    // 4. 应用服务 - 门面模式
+    let application_service = Arc::new(ArticleApplicationServiceImpl::new(
+        command_handler,
+        query_handler,
+    ));
+
+    tracing::info!("✅ 应用服务已创建");
+
+    // 5. 入站适配器 - HTTP控制器
+    let site_config = load_site_config();
+    let feed_controller = Arc::new(FeedController::new(
+        application_service.clone(),
+        site_config.clone(),
+        load_feed_config(),
+    ));
+    let site_controller = Arc::new(SiteController::new(
+        application_service.clone(),
+        site_config.clone(),
+        load_robots_config(),
+    ));
+    // 主题 - 启动时编译全部页面模板，模板有误时拒绝启动
+    let theme_config = load_theme_config();
+    let theme_controller = if theme_config.enabled {
+        let theme = Theme::load(&theme_config)?;
+        Some(Arc::new(ThemeController::new(
+            application_service.clone(),
+            theme,
+            site_config,
+            theme_config,
+        )))
+    } else {
+        None
+    };
+    let article_controller = Arc::new(ArticleController::new(application_service));
+    let event_stream_controller = Arc::new(EventStreamController::new(event_stream_hub));
+
+    tracing::info!("✅ HTTP控制器已创建");
+
+    // 6. 路由配置 - 管理接口与文章写操作需要管理令牌
+    let admin_config = Arc::new(load_admin_config());
+    let dead_letter_controller = Arc::new(DeadLetterController::new(dead_letters));
+    let mut admin_routes = Router::new().nest(
+        "/dead-letters",
+        dead_letter_routes().with_state(dead_letter_controller),
+    );
+    if let Some(webhook_service) = repositories.webhook_service {
+        let webhook_controller = Arc::new(WebhookController::new(webhook_service));
+        admin_routes =
+            admin_routes.nest("/webhooks", webhook_routes().with_state(webhook_controller));
+    }
+    let admin_routes = admin_routes.layer(from_fn_with_state(
+        admin_config.clone(),
+        require_admin_token,
+    ));
+
+    // 健康检查 - 仅数据库后端提供数据库探针
+    let mut health = Router::new();
+    if let Some(db) = repositories.db {
+        let health_controller = Arc::new(HealthController::new(db));
+        health = health.merge(health_routes().with_state(health_controller));
+    }
+
+    // 指标 - 启用查询缓存时提供缓存命中统计
+    let mut metrics = Router::new();
+    if let Some(cache) = article_cache {
+        let metrics_controller = Arc::new(MetricsController::new(cache));
+        metrics = metrics.merge(metrics_routes().with_state(metrics_controller));
+    }
+
+    let http_cache = load_http_cache_config();
+
+    // HTML页面 - 启用主题时首页由主题渲染
+    let pages = match theme_controller {
+        Some(controller) => theme_routes(&http_cache).with_state(controller),
+        None => Router::new().route("/", get(hello)),
+    };
+
+    let mut app = Router::new()
+        .merge(pages)
+        .merge(docs_routes())
+        .merge(feed_routes(&http_cache).with_state(feed_controller))
+        .merge(site_routes(&http_cache).with_state(site_controller))
+        .nest("/health", health)
+        .nest("/metrics", metrics)
+        .nest("/articles", article_routes(&http_cache, admin_config))
+        .nest(
+            "/events",
+            event_stream_routes().with_state(event_stream_controller),
+        )
+        .nest("/admin", admin_routes)
+        .layer(from_fn(negotiate_locale))
+        .layer(TraceLayer::new_for_http())
+        .with_state(article_controller);
+
+    // 读己之写 - 数据库后端的读模型由投影异步维护，写入后的读取需等待投影
+    if let Some(config) = repositories.read_your_writes {
+        app = app.layer(from_fn_with_state(Arc::new(config), read_your_writes));
+    }
+
+    let listener = TcpListener::bind("0.0.0.0:8080").await?;
+    tracing::info!("🌐 Rhyon服务正在监听: {}", listener.local_addr()?);
+    tracing::info!("📖 API文档: http://localhost:8080/docs");
+
+    axum::serve(listener, app.into_make_service()).await?;
+    Ok(())
+}
+
+/// 存储后端提供的仓储及依赖数据库的可选服务
+struct Repositories {
+    unit_of_work: Arc<dyn UnitOfWork>,
+    read: Arc<dyn ArticleReadRepository>,
+    /// Webhook需要持久化订阅与投递记录，仅数据库后端可用
+    webhook_service: Option<Arc<WebhookApplicationServiceImpl>>,
+    /// 数据库连接，用于健康检查，内存后端为空
+    db: Option<DatabaseConnection>,
+    /// 客户端写入后短时间内的读取等待投影追上写入并回到主库，内存后端为空
+    read_your_writes: Option<ReadYourWritesConfig>,
+}
+
+/// 数据库后端：连接数据库并启动发件箱中继、读模型投影等后台任务
+async fn database_repositories(
+    event_bus: InProcessEventBus,
+    event_registry: Arc<EventRegistry>,
+    persistence_config: &PersistenceConfig,
+    cache_invalidator: Option<ArticleCacheInvalidator>,
+    event_stream_hub: Arc<EventStreamHub>,
+) -> Result<Repositories, Box<dyn Error>> {
+    let db = connect().await?;
+    tracing::info!("✅ 数据库连接已建立");
+
+    // 数据库迁移 - 按配置在启动时执行待执行的迁移
+    if load_migration_config().auto {
+        let count = run_pending_migrations(&db).await?;
+        tracing::info!("✅ 已执行{}个数据库迁移", count);
+    }
+
+    let unit_of_work = Arc::new(SeaOrmUnitOfWork::new(
+        db.clone(),
+        event_registry.clone(),
+        persistence_config,
+    ));
+    tracing::info!("✅ 文章写模型持久化方式: {:?}", persistence_config.mode);
+
+    // 只读副本 - 查询端读取副本，写入仍使用主库
+    let replica = connect_replica().await?;
+    let replica_config = load_db_config().replica;
+    let read_your_writes = (replica_config.read_your_writes_ms > 0).then(|| ReadYourWritesConfig {
+        window_ms: replica_config.read_your_writes_ms,
+        wait_ms: replica_config.read_your_writes_wait_ms,
+        progress: Arc::new(ArticleReadViewProgress::new(db.clone())),
+    });
+
+    let read = Arc::new(SeaOrmArticleReadRepository::new(ReadConnection::new(
+        db.clone(),
+        replica,
+    )));
+    let webhook_subscriptions = Arc::new(SeaOrmWebhookSubscriptionRepository::new(db.clone()));
+    let webhook_deliveries = Arc::new(SeaOrmWebhookDeliveryRepository::new(db.clone()));
+
+    tracing::info!("✅ 仓储适配器已创建");
+
+    let event_publisher: Arc<dyn EventPublisher> = Arc::new(event_bus.subscribe_all(
+        WebhookDispatcher::new(webhook_subscriptions.clone(), webhook_deliveries.clone()),
+    ));
+
+    tracing::info!("✅ 事件总线已创建");
+
+    // 读模型投影 - 后台将事件存储投影到文章读模型
+    let projection_config = load_projection_config();
+    ArticleReadViewProjector::new(
+        db.clone(),
+        event_registry.clone(),
+        projection_config.clone(),
+    )
+    .spawn();
+
+    // 查询缓存失效 - 每个实例跟踪投影检查点，读模型更新后使本地及共享缓存失效
+    if let Some(invalidator) = cache_invalidator {
+        ArticleReadViewWatcher::new(db.clone(), Arc::new(invalidator), projection_config).spawn();
+    }
+
+    // 事件流推送 - 每个实例跟踪事件存储，向本实例的SSE客户端推送已投影的事件
+    EventStreamFeed::new(
+        event_stream_hub,
+        Arc::new(SeaOrmEventStore::new(db.clone())),
+        Arc::new(ArticleReadViewProgress::new(db.clone())),
+    )
+    .spawn();
+
+    // 发件箱中继 - 后台投递已提交的领域事件
+    OutboxRelay::new(
+        db.clone(),
+        event_publisher,
+        event_registry.clone(),
+        load_outbox_config(),
+    )
+    .spawn();
+
+    // Webhook投递工作者 - 后台发送并重试Webhook请求
+    WebhookDeliveryWorker::new(
+        webhook_subscriptions.clone(),
+        webhook_deliveries.clone(),
+        load_webhook_config(),
+    )?
+    .spawn();
+
+    tracing::info!("✅ 发件箱中继与读模型投影已启动");
+
+    let webhook_service = Arc::new(WebhookApplicationServiceImpl::new(
+        webhook_subscriptions,
+        webhook_deliveries,
+        event_registry,
+    ));
+
+    Ok(Repositories {
+        unit_of_work,
+        read,
+        webhook_service: Some(webhook_service),
+        db: Some(db),
+        read_your_writes,
+    })
+}
+
+/// 内存后端（演示模式）：无需数据库，事件保存后直接发布到事件总线
+fn in_memory_repositories(event_bus: InProcessEventBus) -> Repositories {
+    tracing::warn!("⚠️ 使用内存存储（演示模式），重启后数据丢失，Webhook不可用");
+
+    let store = InMemoryArticleStore::new();
+    let event_publisher: Arc<dyn EventPublisher> = Arc::new(event_bus);
+
+    Repositories {
+        unit_of_work: Arc::new(InMemoryUnitOfWork::new(store.clone(), event_publisher)),
+        read: Arc::new(InMemoryArticleReadRepository::new(store)),
+        webhook_service: None,
+        db: None,
+        read_your_writes: None,
+    }
+}
+
+/// 文章查询缓存，Redis不可用时回退到进程内LRU
+async fn article_cache(config: CacheConfig) -> Option<Arc<ArticleCache>> {
+    if !config.enabled {
+        return None;
+    }
+
+    let ttl = Duration::from_secs(config.ttl_secs);
+    let lru = || -> (Arc<dyn CacheStore>, &'static str) {
+        (Arc::new(LruCacheStore::new(config.capacity, ttl)), "memory")
+    };
+    let (store, backend) = match config.backend {
+        CacheBackend::Memory => lru(),
+        CacheBackend::Redis => match RedisCacheStore::connect(&config.redis, ttl).await {
+            Ok(store) => (Arc::new(store) as Arc<dyn CacheStore>, "redis"),
+            Err(err) => {
+                tracing::warn!("⚠️ 无法连接Redis缓存，改用进程内缓存: {}", err);
+                lru()
+            }
+        },
+    };
+
+    tracing::info!("✅ 文章查询缓存已启用: {}", backend);
+    Some(Arc::new(ArticleCache::new(
+        store,
+        backend,
+        Duration::from_millis(config.invalidation_hold_ms),
+    )))
+}
+
+async fn hello() -> &'static str {
+    "🎉 Rhyon博客服务 - CQRS+六边形架构已启动！"
+}
@@ -0,0 +1,7 @@
+pub mod projection_listener;
+pub mod projection_progress;
+pub mod read_consistency;
+
+pub use projection_listener::*;
+pub use projection_progress::*;
+pub use read_consistency::*;
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::shared::errors::RhyonError;
+
+/// 读模型投影推进后的通知，例如使相关的查询缓存失效
+#[async_trait]
+pub trait ProjectionListener: Send + Sync {
+    /// 这些聚合的事件已提交到读模型
+    async fn projected(&self, aggregate_ids: &[String]) -> Result<(), RhyonError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::shared::errors::RhyonError;
+
+/// 读模型投影的进度，用于判断一次写入是否已对查询端可见
+#[async_trait]
+pub trait ProjectionProgress: Send + Sync {
+    /// 事件存储中已分配的最新位置
+    async fn head_position(&self) -> Result<u64, RhyonError>;
+
+    /// 投影已处理到的位置
+    async fn projected_position(&self) -> Result<u64, RhyonError>;
+}
@@ -0,0 +1,27 @@
+use std::future::Future;
+
+tokio::task_local! {
+    static READ_CONSISTENCY: ReadConsistency;
+}
+
+/// 当前请求对读取一致性的要求
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReadConsistency {
+    /// 允许读取可能落后的只读副本
+    #[default]
+    Eventual,
+    /// 客户端刚写入过，需要读取主库才能看到自己的修改
+    ReadYourWrites,
+}
+
+impl ReadConsistency {
+    /// 当前任务的一致性要求，未设置时为最终一致
+    pub fn current() -> Self {
+        READ_CONSISTENCY.try_with(|c| *c).unwrap_or_default()
+    }
+
+    /// 在给定一致性要求下执行异步操作
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        READ_CONSISTENCY.scope(self, future).await
+    }
+}
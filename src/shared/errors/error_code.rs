@@ -0,0 +1,44 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// 机器可读的错误码，随错误响应返回，客户端据此区分错误类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// 请求格式或参数无效（如分页游标、字段列表）
+    BadRequest,
+    /// 一个或多个字段未通过校验，详见violations
+    ValidationFailed,
+    /// 违反业务规则
+    RuleViolation,
+    /// 与资源当前状态冲突（如slug已被使用、并发修改）
+    Conflict,
+    NotFound,
+    Unauthorized,
+    InternalError,
+}
+
+impl ErrorCode {
+    pub const ALL: [ErrorCode; 7] = [
+        ErrorCode::BadRequest,
+        ErrorCode::ValidationFailed,
+        ErrorCode::RuleViolation,
+        ErrorCode::Conflict,
+        ErrorCode::NotFound,
+        ErrorCode::Unauthorized,
+        ErrorCode::InternalError,
+    ];
+
+    /// 消息目录中该错误码的通用消息
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            ErrorCode::BadRequest => "error-bad-request",
+            ErrorCode::ValidationFailed => "error-validation-failed",
+            ErrorCode::RuleViolation => "error-rule-violation",
+            ErrorCode::Conflict => "error-conflict",
+            ErrorCode::NotFound => "error-not-found",
+            ErrorCode::Unauthorized => "error-unauthorized",
+            ErrorCode::InternalError => "error-internal-error",
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use utoipa::ToSchema;
+
+use super::RhyonError;
+use crate::shared::i18n::{Locale, MessageCatalog};
+
+/// 单个字段的校验失败信息
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct FieldViolation {
+    /// 字段名，列表中的元素带有下标，如`tags[2]`
+    pub field: String,
+    /// 违规类型，如 required、too_long
+    pub code: &'static str,
+    /// 本地化后的说明，由`localize`按请求语言填充
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    /// 违规相关的参数，如长度上限
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    #[schema(value_type = Object)]
+    pub params: Map<String, Value>,
+}
+
+impl FieldViolation {
+    pub fn new(field: &str, code: &'static str) -> Self {
+        Self {
+            field: field.to_string(),
+            code,
+            message: String::new(),
+            params: Map::new(),
+        }
+    }
+
+    pub fn with_param(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.params.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// 消息目录中的键：violation-<字段>-<违规类型>，列表元素使用列表字段的消息
+    pub fn message_key(&self) -> String {
+        let field = self.field.split('[').next().unwrap_or_default();
+        format!("violation-{}-{}", field, self.code).replace('_', "-")
+    }
+
+    pub fn localize(mut self, locale: Locale) -> Self {
+        self.message = MessageCatalog::global().format(
+            locale,
+            &self.message_key(),
+            self.params
+                .iter()
+                .map(|(name, value)| (name.as_str(), value)),
+        );
+        self
+    }
+}
+
+impl From<FieldViolation> for RhyonError {
+    fn from(violation: FieldViolation) -> Self {
+        RhyonError::Invalid(vec![violation])
+    }
+}
+
+/// 收集多个字段的校验结果，一次返回全部违规而不是在第一个错误处停止
+#[derive(Debug, Default)]
+pub struct Violations {
+    violations: Vec<FieldViolation>,
+    /// 非字段校验错误，优先返回
+    error: Option<RhyonError>,
+}
+
+impl Violations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录一个校验结果，失败时返回None
+    pub fn check<T>(&mut self, result: Result<T, RhyonError>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(RhyonError::Invalid(violations)) => {
+                self.violations.extend(violations);
+                None
+            }
+            Err(err) => {
+                self.error.get_or_insert(err);
+                None
+            }
+        }
+    }
+
+    /// 记录列表中第`index`个元素的校验结果，违规的字段名带上下标
+    pub fn check_item<T>(&mut self, index: usize, result: Result<T, RhyonError>) -> Option<T> {
+        self.check(result.map_err(|err| {
+            match err {
+                RhyonError::Invalid(violations) => RhyonError::Invalid(
+                    violations
+                        .into_iter()
+                        .map(|violation| FieldViolation {
+                            field: format!("{}[{}]", violation.field, index),
+                            ..violation
+                        })
+                        .collect(),
+                ),
+                err => err,
+            }
+        }))
+    }
+
+    /// 没有违规时由`build`组装校验通过的值
+    pub fn finish<T>(self, build: impl FnOnce() -> Option<T>) -> Result<T, RhyonError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        if !self.violations.is_empty() {
+            return Err(RhyonError::Invalid(self.violations));
+        }
+        build().ok_or_else(|| RhyonError::ServerError("校验结果不完整".to_string()))
+    }
+}
@@ -1,7 +1,14 @@
+pub mod error_code;
+pub mod field_violation;
+
+pub use error_code::*;
+pub use field_violation::*;
+
 use crate::core::response::R;
+use crate::shared::i18n::{Locale, Message};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
-use sea_orm::DbErr;
+use sea_orm::{DbErr, SqlErr};
 use std::error::Error;
 use thiserror::Error;
 
@@ -13,14 +20,23 @@ pub enum RhyonError {
     #[error("资源未找到")]
     NotFound,
 
+    #[error("未授权的访问")]
+    Unauthorized,
+
     #[error("验证错误: {0}")]
-    Validation(String),
+    Validation(Message),
+
+    #[error("字段校验失败: {}", summarize(.0))]
+    Invalid(Vec<FieldViolation>),
+
+    #[error("冲突: {0}")]
+    Conflict(Message),
 
     #[error("服务器错误: {0}")]
     ServerError(String),
 
     #[error("领域错误: {0}")]
-    Domain(String),
+    Domain(Message),
 }
 
 impl From<Box<dyn Error>> for RhyonError {
@@ -29,18 +45,81 @@ impl From<Box<dyn Error>> for RhyonError {
     }
 }
 
+impl RhyonError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            RhyonError::Database(err) if is_unique_violation(err) => StatusCode::CONFLICT,
+            RhyonError::Database(_) | RhyonError::ServerError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            RhyonError::NotFound => StatusCode::NOT_FOUND,
+            RhyonError::Unauthorized => StatusCode::UNAUTHORIZED,
+            RhyonError::Validation(_) => StatusCode::BAD_REQUEST,
+            RhyonError::Invalid(_) | RhyonError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            RhyonError::Conflict(_) => StatusCode::CONFLICT,
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            RhyonError::Database(err) if is_unique_violation(err) => ErrorCode::Conflict,
+            RhyonError::Database(_) | RhyonError::ServerError(_) => ErrorCode::InternalError,
+            RhyonError::NotFound => ErrorCode::NotFound,
+            RhyonError::Unauthorized => ErrorCode::Unauthorized,
+            RhyonError::Validation(_) => ErrorCode::BadRequest,
+            RhyonError::Invalid(_) => ErrorCode::ValidationFailed,
+            RhyonError::Domain(_) => ErrorCode::RuleViolation,
+            RhyonError::Conflict(_) => ErrorCode::Conflict,
+        }
+    }
+
+    /// 返回给客户端的消息，服务端错误不暴露内部细节
+    fn public_message(&self, locale: Locale) -> String {
+        let message = match self {
+            RhyonError::Database(err) if is_unique_violation(err) => {
+                Message::new("resource-exists")
+            }
+            RhyonError::Database(_) => Message::new("database-error"),
+            RhyonError::Validation(message)
+            | RhyonError::Domain(message)
+            | RhyonError::Conflict(message) => message.clone(),
+            _ => Message::new(self.code().message_key()),
+        };
+        message.localize(locale)
+    }
+}
+
 impl IntoResponse for RhyonError {
     fn into_response(self) -> Response {
-        let (status, message) = match &self {
-            RhyonError::Database(_) => (StatusCode::INTERNAL_SERVER_ERROR, "数据库操作失败"),
-            RhyonError::NotFound => (StatusCode::NOT_FOUND, "请求的资源不存在"),
-            RhyonError::Validation(_) => (StatusCode::BAD_REQUEST, "请求参数验证失败"),
-            RhyonError::ServerError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "服务器内部错误"),
-            RhyonError::Domain(_) => (StatusCode::BAD_REQUEST, "业务规则验证失败"),
-        };
+        let status = self.status();
+        if status.is_server_error() {
+            tracing::error!("❌ 请求处理失败: {}", self);
+        }
 
-        let body = R::<()>::error(status.as_u16(), message);
+        let locale = Locale::current();
+        let mut body = R::<()>::error(status.as_u16(), &self.public_message(locale))
+            .with_error_code(self.code());
+        if let RhyonError::Invalid(violations) = self {
+            let violations = violations
+                .into_iter()
+                .map(|violation| violation.localize(locale))
+                .collect();
+            body = body.with_violations(violations);
+        }
 
-        (status, body).into_response()
+        body.into_response()
     }
 }
+
+/// 唯一约束冲突（如slug重复）说明请求与已有资源冲突
+fn is_unique_violation(err: &DbErr) -> bool {
+    matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_)))
+}
+
+fn summarize(violations: &[FieldViolation]) -> String {
+    violations
+        .iter()
+        .map(|violation| format!("{}: {}", violation.field, violation.code))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
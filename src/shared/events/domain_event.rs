@@ -1,13 +1,19 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use std::any::Any;
 use std::fmt::Debug;
 
 /// 领域事件trait
 pub trait DomainEvent: Debug + Send + Sync {
-    fn event_type(&self) -> &'static str;
+    fn event_type(&self) -> &str;
+    fn aggregate_type(&self) -> &str;
     fn aggregate_id(&self) -> String;
     fn occurred_on(&self) -> DateTime<Utc>;
     fn version(&self) -> u32;
+    /// 事件内容的JSON表示（用于持久化和跨进程传递）
+    fn payload(&self) -> serde_json::Value;
+    /// 用于向下转型为具体事件类型
+    fn as_any(&self) -> &dyn Any;
 }
 
 /// 事件发布器trait
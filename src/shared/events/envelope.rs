@@ -0,0 +1,68 @@
+use super::DomainEvent;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 领域事件信封：可序列化的事件及其元数据，用于存储、传输和重放
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub aggregate_type: String,
+    pub aggregate_id: String,
+    /// 事件在所属聚合事件流中的序号（从1开始）
+    pub sequence: u64,
+    /// 事件载荷的结构版本
+    pub schema_version: u32,
+    /// 直接导致该事件的事件ID
+    pub causation_id: Option<Uuid>,
+    /// 同一业务操作产生的事件共享的关联ID
+    pub correlation_id: Option<Uuid>,
+    pub occurred_on: DateTime<Utc>,
+    pub payload: serde_json::Value,
+}
+
+impl EventEnvelope {
+    /// 封装单个领域事件
+    pub fn wrap(event: &dyn DomainEvent, sequence: u64) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            event_type: event.event_type().to_string(),
+            aggregate_type: event.aggregate_type().to_string(),
+            aggregate_id: event.aggregate_id(),
+            sequence,
+            schema_version: event.version(),
+            causation_id: None,
+            correlation_id: None,
+            occurred_on: event.occurred_on(),
+            payload: event.payload(),
+        }
+    }
+
+    /// 封装同一次操作产生的一批事件，序号从`first_sequence`开始连续递增
+    ///
+    /// 这批事件由同一个命令直接引起：命令ID同时作为关联ID与起因ID
+    pub fn wrap_all(events: &[Box<dyn DomainEvent>], first_sequence: u64) -> Vec<Self> {
+        let command_id = Uuid::new_v4();
+
+        events
+            .iter()
+            .zip(first_sequence..)
+            .map(|(event, sequence)| {
+                Self::wrap(event.as_ref(), sequence)
+                    .with_correlation_id(command_id)
+                    .with_causation_id(command_id)
+            })
+            .collect()
+    }
+
+    pub fn with_causation_id(mut self, causation_id: Uuid) -> Self {
+        self.causation_id = Some(causation_id);
+        self
+    }
+
+    pub fn with_correlation_id(mut self, correlation_id: Uuid) -> Self {
+        self.correlation_id = Some(correlation_id);
+        self
+    }
+}
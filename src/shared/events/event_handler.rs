@@ -0,0 +1,73 @@
+use super::DomainEvent;
+use crate::shared::errors::RhyonError;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// 订阅所有事件类型时使用的通配符
+pub const ALL_EVENTS: &str = "*";
+
+/// 领域事件处理器
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    /// 处理器名称，用于日志和死信记录
+    fn name(&self) -> &str;
+
+    async fn handle(&self, event: &dyn DomainEvent) -> Result<(), RhyonError>;
+}
+
+/// 共享的处理器（例如同时被HTTP层使用的订阅者）
+#[async_trait]
+impl<T: EventHandler + ?Sized> EventHandler for Arc<T> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    async fn handle(&self, event: &dyn DomainEvent) -> Result<(), RhyonError> {
+        (**self).handle(event).await
+    }
+}
+
+/// 只处理某一具体事件类型的处理器
+#[async_trait]
+pub trait TypedEventHandler<E: DomainEvent>: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn handle(&self, event: &E) -> Result<(), RhyonError>;
+}
+
+/// 将强类型处理器适配为通用的事件处理器
+pub struct Typed<E, H> {
+    handler: H,
+    _event: PhantomData<fn() -> E>,
+}
+
+impl<E, H> Typed<E, H> {
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler,
+            _event: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<E, H> EventHandler for Typed<E, H>
+where
+    E: DomainEvent + 'static,
+    H: TypedEventHandler<E>,
+{
+    fn name(&self) -> &str {
+        self.handler.name()
+    }
+
+    async fn handle(&self, event: &dyn DomainEvent) -> Result<(), RhyonError> {
+        match event.as_any().downcast_ref::<E>() {
+            Some(event) => self.handler.handle(event).await,
+            None => Err(RhyonError::ServerError(format!(
+                "事件类型不匹配: {}",
+                event.event_type()
+            ))),
+        }
+    }
+}
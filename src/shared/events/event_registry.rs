@@ -0,0 +1,69 @@
+use super::{DomainEvent, EventEnvelope};
+use crate::shared::errors::RhyonError;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+type Deserializer = fn(serde_json::Value) -> Result<Box<dyn DomainEvent>, serde_json::Error>;
+
+/// 已注册的事件类型
+struct RegisteredEvent {
+    schema_version: u32,
+    deserializer: Deserializer,
+}
+
+/// 事件注册表：根据`event_type`将信封中的载荷还原为具体的领域事件
+#[derive(Default)]
+pub struct EventRegistry {
+    events: HashMap<String, RegisteredEvent>,
+}
+
+impl EventRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册事件类型及其当前结构版本
+    pub fn register<E>(mut self, event_type: &str, schema_version: u32) -> Self
+    where
+        E: DomainEvent + DeserializeOwned + 'static,
+    {
+        let deserializer: Deserializer = |payload| {
+            serde_json::from_value::<E>(payload)
+                .map(|event| Box::new(event) as Box<dyn DomainEvent>)
+        };
+
+        self.events.insert(
+            event_type.to_string(),
+            RegisteredEvent {
+                schema_version,
+                deserializer,
+            },
+        );
+        self
+    }
+
+    pub fn contains(&self, event_type: &str) -> bool {
+        self.events.contains_key(event_type)
+    }
+
+    /// 还原信封中的领域事件
+    pub fn deserialize(
+        &self,
+        envelope: &EventEnvelope,
+    ) -> Result<Box<dyn DomainEvent>, RhyonError> {
+        let registered = self.events.get(&envelope.event_type).ok_or_else(|| {
+            RhyonError::ServerError(format!("未注册的事件类型: {}", envelope.event_type))
+        })?;
+
+        if envelope.schema_version > registered.schema_version {
+            return Err(RhyonError::ServerError(format!(
+                "不支持的事件结构版本: {} v{}",
+                envelope.event_type, envelope.schema_version
+            )));
+        }
+
+        (registered.deserializer)(envelope.payload.clone()).map_err(|e| {
+            RhyonError::ServerError(format!("事件反序列化失败 ({}): {}", envelope.event_type, e))
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use super::EventEnvelope;
+use crate::shared::errors::RhyonError;
+use async_trait::async_trait;
+
+/// 事件存储中的事件及其全局位置
+#[derive(Debug, Clone)]
+pub struct StoredEvent {
+    /// 全局递增的位置，可用于按顺序读取全部事件
+    pub position: u64,
+    pub envelope: EventEnvelope,
+}
+
+/// 仅追加的事件存储端口
+///
+/// 实现绑定到一个连接：写仓储使用绑定到工作单元事务的实例，使事件与聚合在同一事务中提交
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// 追加事件；同一聚合的序号冲突时返回错误（乐观并发控制）
+    async fn append(&self, envelopes: &[EventEnvelope]) -> Result<(), RhyonError>;
+
+    /// 读取某个聚合从指定序号开始的事件流
+    async fn read_stream(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+        from_sequence: u64,
+    ) -> Result<Vec<StoredEvent>, RhyonError>;
+
+    /// 按全局位置读取事件（不包含`after_position`本身）
+    async fn read_all(
+        &self,
+        after_position: u64,
+        limit: u64,
+    ) -> Result<Vec<StoredEvent>, RhyonError>;
+
+    /// 聚合事件流当前的最大序号，没有事件时为0
+    async fn last_sequence(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+    ) -> Result<u64, RhyonError>;
+
+    /// 已分配的最大全局位置，没有事件时为0
+    async fn head_position(&self) -> Result<u64, RhyonError>;
+}
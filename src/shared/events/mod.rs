@@ -1,4 +1,11 @@
 pub mod domain_event;
-pub mod event_publisher;
+pub mod envelope;
+pub mod event_handler;
+pub mod event_registry;
+pub mod event_store;
 
 pub use domain_event::*;
+pub use envelope::*;
+pub use event_handler::*;
+pub use event_registry::*;
+pub use event_store::*;
@@ -0,0 +1,255 @@
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use rust_embed::Embed;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::str::from_utf8;
+use std::sync::LazyLock;
+
+use super::Locale;
+use crate::shared::errors::ErrorCode;
+
+#[derive(Embed)]
+#[folder = "locales/"]
+struct LocaleAsset;
+
+static CATALOG: LazyLock<MessageCatalog> = LazyLock::new(MessageCatalog::load);
+
+/// 消息目录：每种语言一个Fluent资源（locales/<语言>/main.ftl）
+pub struct MessageCatalog {
+    bundles: Vec<LocaleBundle>,
+}
+
+struct LocaleBundle {
+    locale: Locale,
+    bundle: FluentBundle<FluentResource>,
+    keys: BTreeSet<String>,
+}
+
+impl MessageCatalog {
+    pub fn global() -> &'static Self {
+        &CATALOG
+    }
+
+    fn load() -> Self {
+        let bundles = Locale::ALL
+            .into_iter()
+            .map(|locale| {
+                let filename = format!("{}/main.ftl", locale.tag());
+                let file = LocaleAsset::get(&filename)
+                    .unwrap_or_else(|| panic!("❌ 缺少消息目录: {}", filename));
+                let source = from_utf8(file.data.as_ref())
+                    .expect("消息目录内容不是 UTF-8")
+                    .to_string();
+
+                let resource = FluentResource::try_new(source).unwrap_or_else(|(_, errors)| {
+                    panic!("❌ 无法解析消息目录 {}: {:?}", filename, errors)
+                });
+                let keys = message_keys(&resource);
+
+                let mut bundle = FluentBundle::new_concurrent(vec![locale.language_id()]);
+                // 接口消息不需要双向文本隔离符
+                bundle.set_use_isolating(false);
+                bundle.add_resource(resource).unwrap_or_else(|errors| {
+                    panic!("❌ 消息目录 {} 中有重复的键: {:?}", filename, errors)
+                });
+
+                LocaleBundle {
+                    locale,
+                    bundle,
+                    keys,
+                }
+            })
+            .collect();
+
+        Self { bundles }
+    }
+
+    /// 按语言格式化消息，缺少翻译时依次回退到默认语言和消息键本身
+    pub fn format<'a>(
+        &self,
+        locale: Locale,
+        key: &str,
+        args: impl IntoIterator<Item = (&'a str, &'a Value)>,
+    ) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(name, fluent_value(value));
+        }
+
+        [locale, Locale::default()]
+            .iter()
+            .filter_map(|locale| self.bundle(*locale))
+            .find_map(|bundle| {
+                let pattern = bundle.get_message(key)?.value()?;
+                let mut errors = Vec::new();
+                let text = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+                if !errors.is_empty() {
+                    tracing::warn!("⚠️ 消息格式化失败 {}: {:?}", key, errors);
+                }
+                Some(text.into_owned())
+            })
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    /// 校验每个错误码都有消息，且所有语言定义了相同的消息键
+    pub fn verify(&self) -> Result<(), String> {
+        let mut required: BTreeSet<&str> =
+            ErrorCode::ALL.iter().map(ErrorCode::message_key).collect();
+        for entry in &self.bundles {
+            required.extend(entry.keys.iter().map(String::as_str));
+        }
+
+        let problems: Vec<String> = self
+            .bundles
+            .iter()
+            .filter_map(|entry| {
+                let missing: Vec<&str> = required
+                    .iter()
+                    .copied()
+                    .filter(|key| !entry.keys.contains(*key))
+                    .collect();
+                (!missing.is_empty())
+                    .then(|| format!("{} 缺少 {}", entry.locale.tag(), missing.join(", ")))
+            })
+            .collect();
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("消息目录不完整: {}", problems.join("; ")))
+        }
+    }
+
+    fn bundle(&self, locale: Locale) -> Option<&FluentBundle<FluentResource>> {
+        self.bundles
+            .iter()
+            .find(|entry| entry.locale == locale)
+            .map(|entry| &entry.bundle)
+    }
+}
+
+fn message_keys(resource: &FluentResource) -> BTreeSet<String> {
+    resource
+        .entries()
+        .filter_map(|entry| match entry {
+            fluent_syntax::ast::Entry::Message(message) => Some(message.id.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn fluent_value(value: &Value) -> FluentValue<'static> {
+    match value {
+        Value::Number(number) => number
+            .as_f64()
+            .map_or_else(|| number.to_string().into(), FluentValue::from),
+        Value::String(text) => text.clone().into(),
+        other => other.to_string().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::errors::FieldViolation;
+    use std::fs;
+    use std::path::Path;
+
+    /// 源码中以字符串字面量为参数的调用（如消息与字段违规的构造），返回每次调用开头的字面量参数
+    fn literal_calls(source: &str, call: &str) -> Vec<Vec<String>> {
+        source
+            .match_indices(call)
+            .map(|(start, _)| {
+                let args = &source[start + call.len()..];
+                let args = &args[..args.find(')').unwrap_or(args.len())];
+                args.split(',')
+                    .map(str::trim)
+                    .map_while(|arg| arg.strip_prefix('"')?.strip_suffix('"'))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// 源码中用到的全部消息键：消息、字段违规及错误码
+    fn used_keys() -> BTreeSet<String> {
+        fn visit(dir: &Path, keys: &mut BTreeSet<String>) {
+            for entry in fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    visit(&path, keys);
+                    continue;
+                }
+                let source = fs::read_to_string(&path).unwrap();
+                for args in literal_calls(&source, "Message::new(") {
+                    keys.extend(args.into_iter().take(1));
+                }
+                for args in literal_calls(&source, "FieldViolation::new(") {
+                    if let [field, code] = args.as_slice() {
+                        let code: &'static str = code.clone().leak();
+                        keys.insert(FieldViolation::new(field, code).message_key());
+                    }
+                }
+            }
+        }
+
+        let mut keys: BTreeSet<String> = ErrorCode::ALL
+            .iter()
+            .map(|code| code.message_key().to_string())
+            .collect();
+        visit(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut keys,
+        );
+        keys
+    }
+
+    #[test]
+    fn every_locale_defines_the_same_keys() {
+        MessageCatalog::global().verify().unwrap();
+    }
+
+    #[test]
+    fn every_used_key_has_both_translations() {
+        let catalog = MessageCatalog::global();
+        let keys = used_keys();
+        assert!(keys.contains("violation-title-required"), "{keys:?}");
+        assert!(keys.contains("article-not-published"), "{keys:?}");
+
+        for entry in &catalog.bundles {
+            let missing: Vec<&String> = keys
+                .iter()
+                .filter(|key| !entry.keys.contains(*key))
+                .collect();
+            assert!(
+                missing.is_empty(),
+                "{} 缺少 {:?}",
+                entry.locale.tag(),
+                missing
+            );
+        }
+
+        // 英文目录不能照抄中文
+        for key in &keys {
+            let zh = catalog.format(Locale::ZhCn, key, []);
+            let en = catalog.format(Locale::EnUs, key, []);
+            assert_ne!(zh, en, "{key}");
+        }
+    }
+
+    #[test]
+    fn arguments_are_formatted_into_the_message() {
+        let max = Value::from(200);
+        let message = MessageCatalog::global().format(
+            Locale::EnUs,
+            "violation-title-too-long",
+            [("max", &max)],
+        );
+        assert!(message.contains("200"), "{message}");
+        assert_eq!(
+            MessageCatalog::global().format(Locale::EnUs, "no-such-key", []),
+            "no-such-key"
+        );
+    }
+}
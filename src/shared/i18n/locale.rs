@@ -0,0 +1,109 @@
+use std::future::Future;
+use unic_langid::LanguageIdentifier;
+
+tokio::task_local! {
+    static LOCALE: Locale;
+}
+
+/// 接口消息支持的语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    ZhCn,
+    EnUs,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::ZhCn, Locale::EnUs];
+
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::ZhCn => "zh-CN",
+            Locale::EnUs => "en-US",
+        }
+    }
+
+    pub fn language_id(&self) -> LanguageIdentifier {
+        self.tag().parse().expect("内置语言标签无效")
+    }
+
+    /// 当前请求协商出的语言，未设置时为默认语言
+    pub fn current() -> Self {
+        LOCALE.try_with(|locale| *locale).unwrap_or_default()
+    }
+
+    /// 在给定语言下执行异步操作
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        LOCALE.scope(self, future).await
+    }
+
+    /// 按`Accept-Language`（如 "en-GB,en;q=0.9,zh;q=0.8"）选择最匹配的语言
+    pub fn negotiate(accept_language: &str) -> Self {
+        let mut requested: Vec<(LanguageIdentifier, f32)> = accept_language
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let tag = parts.next()?.trim();
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse().ok())?;
+                let language = if tag == "*" {
+                    Self::default().language_id()
+                } else {
+                    tag.parse().ok()?
+                };
+                (quality > 0.0).then_some((language, quality))
+            })
+            .collect();
+        // 稳定排序，权重相同时保持客户端给出的顺序
+        requested.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let requested: Vec<LanguageIdentifier> = requested
+            .into_iter()
+            .map(|(language, _)| language)
+            .collect();
+        let available: Vec<LanguageIdentifier> =
+            Self::ALL.iter().map(Locale::language_id).collect();
+        let default = Self::default().language_id();
+
+        fluent_langneg::negotiate_languages(
+            &requested,
+            &available,
+            Some(&default),
+            fluent_langneg::NegotiationStrategy::Lookup,
+        )
+        .first()
+        .and_then(|language| {
+            Self::ALL
+                .into_iter()
+                .find(|locale| locale.language_id() == **language)
+        })
+        .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accept_language_selects_the_best_supported_locale() {
+        let cases = [
+            ("en-US", Locale::EnUs),
+            ("zh-CN", Locale::ZhCn),
+            ("en", Locale::EnUs),
+            ("en-GB,en;q=0.9,zh;q=0.8", Locale::EnUs),
+            ("zh;q=0.5, en;q=0.8", Locale::EnUs),
+            ("en;q=0, zh", Locale::ZhCn),
+            ("fr-FR, en;q=0.5", Locale::EnUs),
+            // 不支持的语言、通配符或无法解析时使用默认语言
+            ("fr-FR", Locale::ZhCn),
+            ("*", Locale::ZhCn),
+            ("", Locale::ZhCn),
+            ("en;q=abc", Locale::ZhCn),
+        ];
+        for (header, expected) in cases {
+            assert_eq!(Locale::negotiate(header), expected, "{header:?}");
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use serde_json::Value;
+use std::fmt;
+
+use super::{Locale, MessageCatalog};
+
+/// 可本地化的消息：消息目录中的键及其参数
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    key: &'static str,
+    args: Vec<(&'static str, Value)>,
+}
+
+impl Message {
+    pub fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            args: Vec::new(),
+        }
+    }
+
+    pub fn with_arg(mut self, name: &'static str, value: impl Into<Value>) -> Self {
+        self.args.push((name, value.into()));
+        self
+    }
+
+    pub fn localize(&self, locale: Locale) -> String {
+        MessageCatalog::global().format(
+            locale,
+            self.key,
+            self.args.iter().map(|(name, value)| (*name, value)),
+        )
+    }
+}
+
+/// 日志等非请求场景使用默认语言
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.localize(Locale::default()))
+    }
+}
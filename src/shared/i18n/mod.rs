@@ -0,0 +1,7 @@
+pub mod catalog;
+pub mod locale;
+pub mod message;
+
+pub use catalog::*;
+pub use locale::*;
+pub use message::*;
@@ -1,4 +1,7 @@
+pub mod consistency;
 pub mod errors;
 pub mod events;
+pub mod i18n;
 pub mod pagination;
 pub mod query;
+pub mod tasks;
@@ -0,0 +1,97 @@
+use crate::shared::errors::RhyonError;
+use crate::shared::i18n::Message;
+use crate::shared::query::{FieldAccessor, QueryValue, SortCriteria};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+
+/// 游标翻页方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CursorDirection {
+    /// 向后翻页（取游标之后的数据）
+    Next,
+    /// 向前翻页（取游标之前的数据）
+    Prev,
+}
+
+/// 键集分页游标：记录排序键元组及作为决胜字段的id
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cursor {
+    pub direction: CursorDirection,
+    /// 生成游标时使用的排序字段，用于校验游标与请求的排序一致
+    pub fields: Vec<String>,
+    pub values: Vec<QueryValue>,
+    pub id: String,
+}
+
+impl Cursor {
+    /// 根据实体的排序键生成游标
+    pub fn from_entity<T: FieldAccessor>(
+        direction: CursorDirection,
+        sort: &SortCriteria,
+        entity: &T,
+    ) -> Self {
+        let fields: Vec<String> = sort.fields.iter().map(|f| f.field_name.clone()).collect();
+        let values = fields
+            .iter()
+            .map(|field| entity.field_value(field).unwrap_or(QueryValue::Null))
+            .collect();
+        let id = match entity.field_value("id") {
+            Some(QueryValue::String(id)) => id,
+            _ => String::new(),
+        };
+
+        Self {
+            direction,
+            fields,
+            values,
+            id,
+        }
+    }
+
+    /// 编码为不透明的URL安全字符串
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor should be serializable");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// 从不透明字符串解码
+    pub fn decode(token: &str) -> Result<Self, RhyonError> {
+        URL_SAFE_NO_PAD
+            .decode(token)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| RhyonError::Validation(Message::new("invalid-cursor")))
+    }
+
+    /// 校验游标是否由相同的排序条件生成
+    pub fn ensure_matches(&self, sort: &SortCriteria) -> Result<(), RhyonError> {
+        let matches = self.fields.len() == sort.fields.len()
+            && self.values.len() == self.fields.len()
+            && self
+                .fields
+                .iter()
+                .zip(&sort.fields)
+                .all(|(field, sort_field)| *field == sort_field.field_name);
+
+        if matches {
+            Ok(())
+        } else {
+            Err(RhyonError::Validation(Message::new("cursor-sort-mismatch")))
+        }
+    }
+}
+
+/// 游标记录的排序键可以直接与实体按排序条件比较
+impl FieldAccessor for Cursor {
+    fn field_value(&self, field: &str) -> Option<QueryValue> {
+        if field == "id" {
+            return Some(QueryValue::String(self.id.clone()));
+        }
+
+        self.fields
+            .iter()
+            .position(|name| name == field)
+            .and_then(|index| self.values.get(index).cloned())
+    }
+}
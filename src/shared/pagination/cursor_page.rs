@@ -0,0 +1,80 @@
+use super::cursor::{Cursor, CursorDirection};
+use super::cursor_pagination::CursorPagination;
+use crate::shared::query::{FieldAccessor, SortCriteria};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct CursorPage<T> {
+    pub items: Vec<T>,
+    pub page_size: u64,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+    pub total_items: Option<u64>,
+}
+
+impl<T: FieldAccessor> CursorPage<T> {
+    /// 从仓储取回的数据窗口构建分页结果
+    ///
+    /// `rows`按翻页方向的实际扫描顺序排列（向前翻页时为逆序），
+    /// 且最多比页面大小多一条，用于判断是否还有更多数据
+    pub fn from_window(
+        mut rows: Vec<T>,
+        pagination: &CursorPagination,
+        sort: &SortCriteria,
+        total_items: Option<u64>,
+    ) -> Self {
+        let size = pagination.size() as usize;
+        let has_more = rows.len() > size;
+        rows.truncate(size);
+
+        let direction = pagination.direction();
+        if direction == CursorDirection::Prev {
+            rows.reverse();
+        }
+
+        let encode = |direction, row: Option<&T>| {
+            row.map(|row| Cursor::from_entity(direction, sort, row).encode())
+        };
+
+        let (next_cursor, prev_cursor) = match direction {
+            CursorDirection::Next => (
+                has_more
+                    .then(|| encode(CursorDirection::Next, rows.last()))
+                    .flatten(),
+                pagination
+                    .cursor()
+                    .and_then(|_| encode(CursorDirection::Prev, rows.first())),
+            ),
+            CursorDirection::Prev => (
+                encode(CursorDirection::Next, rows.last()),
+                has_more
+                    .then(|| encode(CursorDirection::Prev, rows.first()))
+                    .flatten(),
+            ),
+        };
+
+        Self {
+            items: rows,
+            page_size: pagination.size(),
+            next_cursor,
+            prev_cursor,
+            total_items,
+        }
+    }
+}
+
+impl<T> CursorPage<T> {
+    /// 转换项目类型
+    pub fn map<U, F>(self, f: F) -> CursorPage<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        CursorPage {
+            items: self.items.into_iter().map(f).collect(),
+            page_size: self.page_size,
+            next_cursor: self.next_cursor,
+            prev_cursor: self.prev_cursor,
+            total_items: self.total_items,
+        }
+    }
+}
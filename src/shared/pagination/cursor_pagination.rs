@@ -0,0 +1,55 @@
+use super::cursor::{Cursor, CursorDirection};
+
+#[derive(Debug, Clone)]
+pub struct CursorPagination {
+    cursor: Option<Cursor>,
+    size: u64,
+    include_total: bool,
+}
+
+impl CursorPagination {
+    pub fn new(cursor: Option<Cursor>, size: u64) -> Self {
+        let size = size.clamp(1, 100); // 业务规则：限制页面大小
+        Self {
+            cursor,
+            size,
+            include_total: false,
+        }
+    }
+
+    /// 是否额外统计总数（深分页场景下建议关闭）
+    pub fn with_total(mut self, include_total: bool) -> Self {
+        self.include_total = include_total;
+        self
+    }
+
+    pub fn cursor(&self) -> Option<&Cursor> {
+        self.cursor.as_ref()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn include_total(&self) -> bool {
+        self.include_total
+    }
+
+    /// 当前翻页方向（首页视为向后翻页）
+    pub fn direction(&self) -> CursorDirection {
+        self.cursor
+            .as_ref()
+            .map_or(CursorDirection::Next, |cursor| cursor.direction)
+    }
+
+    /// 仓储层需要多取一条数据用于判断是否还有更多
+    pub fn fetch_limit(&self) -> u64 {
+        self.size + 1
+    }
+}
+
+impl Default for CursorPagination {
+    fn default() -> Self {
+        Self::new(None, 10)
+    }
+}
@@ -1,6 +1,12 @@
+pub mod cursor;
+pub mod cursor_page;
+pub mod cursor_pagination;
 pub mod query_page;
 pub mod query_pagination;
 pub mod repository_pagination;
 
+pub use cursor::*;
+pub use cursor_page::*;
+pub use cursor_pagination::*;
 pub use query_page::*;
 pub use query_pagination::*;
@@ -26,10 +26,6 @@ impl<T> QueryPage<T> {
         }
     }
 
-    pub fn empty(current_page: u64, page_size: u64) -> Self {
-        Self::new(Vec::new(), current_page, page_size, 0)
-    }
-
     /// 转换项目类型
     pub fn map<U, F>(self, f: F) -> QueryPage<U>
     where
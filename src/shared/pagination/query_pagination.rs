@@ -13,6 +13,14 @@ impl QueryPagination {
         Self { page, size }
     }
 
+    /// 不限制页面大小的分页，仅用于站点地图等服务端批量读取，不可直接使用客户端参数
+    pub fn batch(page: u64, size: u64) -> Self {
+        Self {
+            page: page.max(1),
+            size: size.max(1),
+        }
+    }
+
     pub fn page(&self) -> u64 {
         self.page
     }
@@ -1,32 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
 /// 通用查询条件
 #[derive(Debug, Clone, PartialEq)]
 pub enum QueryCriteria {
     Equals(String, QueryValue),
-    NotEquals(String, QueryValue),
-    Contains(String, String),
-    StartsWith(String, String),
-    EndsWith(String, String),
-    IsNull(String),
-    IsNotNull(String),
-    GreaterThan(String, QueryValue),
-    GreaterThanOrEqual(String, QueryValue),
-    LessThan(String, QueryValue),
-    LessThanOrEqual(String, QueryValue),
-    In(String, Vec<QueryValue>),
-    NotIn(String, Vec<QueryValue>),
-    Between(String, QueryValue, QueryValue),
+    /// 数组字段包含指定元素
+    ArrayContains(String, QueryValue),
     And(Box<QueryCriteria>, Box<QueryCriteria>),
-    Or(Box<QueryCriteria>, Box<QueryCriteria>),
-    Not(Box<QueryCriteria>),
 }
 
 /// 查询值类型
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum QueryValue {
     String(String),
     Integer(i64),
     Float(f64),
     Boolean(bool),
+    DateTime(DateTime<Utc>),
     Null,
 }
 
@@ -36,75 +27,9 @@ impl QueryCriteria {
         QueryCriteria::Equals(field.into(), value.into())
     }
 
-    // 不等条件
-    pub fn ne(field: impl Into<String>, value: impl Into<QueryValue>) -> Self {
-        QueryCriteria::NotEquals(field.into(), value.into())
-    }
-
-    // 包含条件
-    pub fn contains(field: impl Into<String>, value: impl Into<String>) -> Self {
-        QueryCriteria::Contains(field.into(), value.into())
-    }
-
-    // 开始于条件
-    pub fn starts_with(field: impl Into<String>, value: impl Into<String>) -> Self {
-        QueryCriteria::StartsWith(field.into(), value.into())
-    }
-
-    // 结束于条件
-    pub fn ends_with(field: impl Into<String>, value: impl Into<String>) -> Self {
-        QueryCriteria::EndsWith(field.into(), value.into())
-    }
-
-    // 为空条件
-    pub fn is_null(field: impl Into<String>) -> Self {
-        QueryCriteria::IsNull(field.into())
-    }
-
-    // 非空条件
-    pub fn is_not_null(field: impl Into<String>) -> Self {
-        QueryCriteria::IsNotNull(field.into())
-    }
-
-    // 大于条件
-    pub fn gt(field: impl Into<String>, value: impl Into<QueryValue>) -> Self {
-        QueryCriteria::GreaterThan(field.into(), value.into())
-    }
-
-    // 大于等于条件
-    pub fn gte(field: impl Into<String>, value: impl Into<QueryValue>) -> Self {
-        QueryCriteria::GreaterThanOrEqual(field.into(), value.into())
-    }
-
-    // 小于条件
-    pub fn lt(field: impl Into<String>, value: impl Into<QueryValue>) -> Self {
-        QueryCriteria::LessThan(field.into(), value.into())
-    }
-
-    // 小于等于条件
-    pub fn lte(field: impl Into<String>, value: impl Into<QueryValue>) -> Self {
-        QueryCriteria::LessThanOrEqual(field.into(), value.into())
-    }
-
-    // 在范围内条件
-    pub fn in_values(field: impl Into<String>, values: Vec<impl Into<QueryValue>>) -> Self {
-        let values = values.into_iter().map(|v| v.into()).collect();
-        QueryCriteria::In(field.into(), values)
-    }
-
-    // 不在范围内条件
-    pub fn not_in(field: impl Into<String>, values: Vec<impl Into<QueryValue>>) -> Self {
-        let values = values.into_iter().map(|v| v.into()).collect();
-        QueryCriteria::NotIn(field.into(), values)
-    }
-
-    // 区间条件
-    pub fn between(
-        field: impl Into<String>,
-        start: impl Into<QueryValue>,
-        end: impl Into<QueryValue>,
-    ) -> Self {
-        QueryCriteria::Between(field.into(), start.into(), end.into())
+    // 数组包含元素条件
+    pub fn array_contains(field: impl Into<String>, value: impl Into<QueryValue>) -> Self {
+        QueryCriteria::ArrayContains(field.into(), value.into())
     }
 
     // AND组合
@@ -112,14 +37,18 @@ impl QueryCriteria {
         QueryCriteria::And(Box::new(self), Box::new(other))
     }
 
-    // OR组合
-    pub fn or(self, other: QueryCriteria) -> Self {
-        QueryCriteria::Or(Box::new(self), Box::new(other))
-    }
-
-    // NOT操作
-    pub fn not(self) -> Self {
-        QueryCriteria::Not(Box::new(self))
+    /// 条件树中引用的全部字段名
+    pub fn fields(&self) -> Vec<&str> {
+        match self {
+            QueryCriteria::Equals(field, _) | QueryCriteria::ArrayContains(field, _) => {
+                vec![field.as_str()]
+            }
+            QueryCriteria::And(left, right) => {
+                let mut fields = left.fields();
+                fields.extend(right.fields());
+                fields
+            }
+        }
     }
 }
 
@@ -165,3 +94,9 @@ impl From<bool> for QueryValue {
         QueryValue::Boolean(value)
     }
 }
+
+impl From<DateTime<Utc>> for QueryValue {
+    fn from(value: DateTime<Utc>) -> Self {
+        QueryValue::DateTime(value)
+    }
+}
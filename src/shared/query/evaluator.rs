@@ -0,0 +1,143 @@
+use super::criteria::{QueryCriteria, QueryValue};
+use super::sort::{SortCriteria, SortDirection};
+use super::specification::Specification;
+use crate::shared::errors::RhyonError;
+use crate::shared::i18n::Message;
+use std::cmp::Ordering;
+
+/// 字段访问trait，为内存中的条件求值提供字段值
+pub trait FieldAccessor {
+    /// 按字段名读取值（空值返回`QueryValue::Null`，未知字段返回None）
+    fn field_value(&self, field: &str) -> Option<QueryValue>;
+
+    /// 按字段名读取数组字段的元素（空值返回`Some(None)`，未知字段或非数组字段返回None）
+    fn field_elements(&self, _field: &str) -> Option<Option<Vec<QueryValue>>> {
+        None
+    }
+}
+
+impl QueryCriteria {
+    /// 在内存中对实体求值，语义与SQL翻译保持一致
+    ///
+    /// 采用SQL的三值逻辑：任何与NULL的比较结果都是“未知”（None），
+    /// 最终只有求值结果为`Some(true)`时才视为满足条件。
+    /// 引用实体不存在的字段时返回错误，与SQL翻译拒绝未知列一致
+    pub fn evaluate<T: FieldAccessor + ?Sized>(
+        &self,
+        entity: &T,
+    ) -> Result<Option<bool>, RhyonError> {
+        let field_of = |field: &str| {
+            entity
+                .field_value(field)
+                .ok_or_else(|| unknown_field(field))
+        };
+
+        let result = match self {
+            QueryCriteria::Equals(field, value) => {
+                compare(&field_of(field)?, value).map(|o| o == Ordering::Equal)
+            }
+            QueryCriteria::ArrayContains(field, value) => entity
+                .field_elements(field)
+                .ok_or_else(|| unknown_field(field))?
+                .map(|elements| {
+                    elements
+                        .iter()
+                        .any(|element| compare(element, value) == Some(Ordering::Equal))
+                }),
+            QueryCriteria::And(left, right) => and(left.evaluate(entity)?, right.evaluate(entity)?),
+        };
+
+        Ok(result)
+    }
+
+    /// 校验条件引用的字段都是已知字段，在没有实体可供求值时也能拒绝未知字段
+    pub fn ensure_fields(&self, is_known: impl Fn(&str) -> bool) -> Result<(), RhyonError> {
+        match self.fields().into_iter().find(|field| !is_known(field)) {
+            Some(field) => Err(unknown_field(field)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 任何实现了字段访问的类型都可以直接使用查询条件作为规约（引用未知字段时不满足）
+impl<T: FieldAccessor> Specification<T> for QueryCriteria {
+    fn is_satisfied_by(&self, entity: &T) -> bool {
+        matches!(self.evaluate(entity), Ok(Some(true)))
+    }
+}
+
+impl SortCriteria {
+    /// 校验排序字段都是已知字段
+    pub fn ensure_fields(&self, is_known: impl Fn(&str) -> bool) -> Result<(), RhyonError> {
+        match self
+            .fields
+            .iter()
+            .find(|field| !is_known(&field.field_name))
+        {
+            Some(field) => Err(unknown_field(&field.field_name)),
+            None => Ok(()),
+        }
+    }
+
+    /// 在内存中按排序条件比较两个实体
+    ///
+    /// NULL视为最大值（升序在后、降序在前），与SQL翻译的NULL位置保持一致
+    pub fn compare<L, R>(&self, left: &L, right: &R) -> Ordering
+    where
+        L: FieldAccessor + ?Sized,
+        R: FieldAccessor + ?Sized,
+    {
+        for field in &self.fields {
+            let left = left
+                .field_value(&field.field_name)
+                .unwrap_or(QueryValue::Null);
+            let right = right
+                .field_value(&field.field_name)
+                .unwrap_or(QueryValue::Null);
+
+            let ordering = match (&left, &right) {
+                (QueryValue::Null, QueryValue::Null) => Ordering::Equal,
+                (QueryValue::Null, _) => Ordering::Greater,
+                (_, QueryValue::Null) => Ordering::Less,
+                _ => compare(&left, &right).unwrap_or(Ordering::Equal),
+            };
+            let ordering = match field.direction {
+                SortDirection::Asc => ordering,
+                SortDirection::Desc => ordering.reverse(),
+            };
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+/// 条件或排序引用了不支持的字段
+fn unknown_field(field: &str) -> RhyonError {
+    RhyonError::Validation(Message::new("unsupported-field").with_arg("name", field))
+}
+
+/// 比较两个值，NULL或类型不兼容时返回None
+fn compare(left: &QueryValue, right: &QueryValue) -> Option<Ordering> {
+    match (left, right) {
+        (QueryValue::String(l), QueryValue::String(r)) => Some(l.cmp(r)),
+        (QueryValue::Integer(l), QueryValue::Integer(r)) => Some(l.cmp(r)),
+        (QueryValue::Float(l), QueryValue::Float(r)) => l.partial_cmp(r),
+        (QueryValue::Integer(l), QueryValue::Float(r)) => (*l as f64).partial_cmp(r),
+        (QueryValue::Float(l), QueryValue::Integer(r)) => l.partial_cmp(&(*r as f64)),
+        (QueryValue::Boolean(l), QueryValue::Boolean(r)) => Some(l.cmp(r)),
+        (QueryValue::DateTime(l), QueryValue::DateTime(r)) => Some(l.cmp(r)),
+        _ => None,
+    }
+}
+
+fn and(left: Option<bool>, right: Option<bool>) -> Option<bool> {
+    match (left, right) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
@@ -1,7 +1,9 @@
 pub mod criteria;
+pub mod evaluator;
 pub mod sort;
 pub mod specification;
 
 pub use criteria::*;
+pub use evaluator::*;
 pub use sort::*;
 pub use specification::*;
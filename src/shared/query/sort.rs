@@ -7,6 +7,16 @@ pub enum SortDirection {
     Desc,
 }
 
+impl SortDirection {
+    /// 反转排序方向
+    pub fn reversed(&self) -> Self {
+        match self {
+            SortDirection::Asc => SortDirection::Desc,
+            SortDirection::Desc => SortDirection::Asc,
+        }
+    }
+}
+
 impl From<&str> for SortDirection {
     fn from(s: &str) -> Self {
         match s.to_lowercase().as_str() {
@@ -46,18 +56,6 @@ impl SortField {
             direction,
         }
     }
-
-    pub fn asc(field_name: impl Into<String>) -> Self {
-        Self::new(field_name, SortDirection::Asc)
-    }
-
-    pub fn desc(field_name: impl Into<String>) -> Self {
-        Self::new(field_name, SortDirection::Desc)
-    }
-
-    pub fn to_sql_order(&self) -> (String, String) {
-        (self.field_name.clone(), self.direction.to_string())
-    }
 }
 
 /// 多字段排序组合
@@ -71,11 +69,6 @@ impl SortCriteria {
         Self { fields: Vec::new() }
     }
 
-    pub fn add_field(mut self, field: SortField) -> Self {
-        self.fields.push(field);
-        self
-    }
-
     pub fn add(mut self, field_name: impl Into<String>, direction: SortDirection) -> Self {
         self.fields.push(SortField::new(field_name, direction));
         self
@@ -89,11 +82,15 @@ impl SortCriteria {
         self.add(field_name, SortDirection::Desc)
     }
 
-    pub fn to_sql_order(&self) -> Vec<(String, String)> {
-        self.fields
-            .iter()
-            .map(|field| field.to_sql_order())
-            .collect()
+    /// 反转所有字段的排序方向（用于键集分页向前翻页）
+    pub fn reversed(&self) -> Self {
+        Self {
+            fields: self
+                .fields
+                .iter()
+                .map(|field| SortField::new(field.field_name.clone(), field.direction.reversed()))
+                .collect(),
+        }
     }
 }
 
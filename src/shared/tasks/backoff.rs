@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+/// 指数退避：第n次失败后等待 base * 2^(n-1)，不超过上限
+#[derive(Debug, Clone, Copy)]
+pub struct Backoff {
+    base_ms: u64,
+    max_ms: u64,
+}
+
+impl Backoff {
+    pub fn new(base_ms: u64, max_ms: u64) -> Self {
+        Self { base_ms, max_ms }
+    }
+
+    /// 第`attempts`次失败后的等待时长（attempts从1开始）
+    pub fn delay(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(20);
+        let millis = self
+            .base_ms
+            .saturating_mul(2u64.pow(exponent))
+            .min(self.max_ms);
+        Duration::from_millis(millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doubles_until_capped() {
+        let backoff = Backoff::new(100, 1_000);
+        let delays: Vec<u128> = (0..7).map(|n| backoff.delay(n).as_millis()).collect();
+        assert_eq!(delays, [100, 100, 200, 400, 800, 1_000, 1_000]);
+        assert_eq!(backoff.delay(u32::MAX).as_millis(), 1_000);
+    }
+}
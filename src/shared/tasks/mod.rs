@@ -0,0 +1,5 @@
+pub mod backoff;
+pub mod poll_loop;
+
+pub use backoff::*;
+pub use poll_loop::*;
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::shared::errors::RhyonError;
+
+/// 按批次处理积压工作的后台任务（发件箱中继、投影、Webhook投递等）
+#[async_trait]
+pub trait BatchJob: Send + Sync + 'static {
+    /// 用于日志的任务名称
+    fn name(&self) -> &'static str;
+
+    /// 进入轮询前执行一次
+    async fn start(&self) {}
+
+    /// 处理一批工作，返回处理的条数
+    async fn run_batch(&self) -> Result<usize, RhyonError>;
+}
+
+/// 在后台任务中持续轮询：批次已满时立即继续以尽快清空积压，否则等待轮询间隔
+pub fn spawn_poll_loop<J: BatchJob>(job: J, interval: Duration, batch_size: u64) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        job.start().await;
+
+        loop {
+            match job.run_batch().await {
+                Ok(count) if count as u64 >= batch_size => continue,
+                Ok(_) => {}
+                Err(e) => tracing::error!("❌ {}失败: {}", job.name(), e),
+            }
+            tokio::time::sleep(interval).await;
+        }
+    })
+}